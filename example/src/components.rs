@@ -1,1317 +1,9048 @@
 use leptos::prelude::*;
 
-pub fn size_24_outline() -> Vec<impl IntoView> {
-    use leptos_heroicons::size_24::outline::*;
+use crate::GalleryIcon;
+
+pub fn size_16_solid() -> Vec<GalleryIcon> {
+    use leptos_heroicons::size_16::solid::*;
 
     vec![
-        view! { <BoltSlash /> }.into_any(),
-        view! { <DocumentMagnifyingGlass /> }.into_any(),
-        view! { <Plus /> }.into_any(),
-        view! { <CubeTransparent /> }.into_any(),
-        view! { <RocketLaunch /> }.into_any(),
-        view! { <DocumentMinus /> }.into_any(),
-        view! { <CloudArrowUp /> }.into_any(),
-        view! { <ShieldCheck /> }.into_any(),
-        view! { <ArrowUturnRight /> }.into_any(),
-        view! { <EnvelopeOpen /> }.into_any(),
-        view! { <BuildingOffice2 /> }.into_any(),
-        view! { <PlayCircle /> }.into_any(),
-        view! { <Ticket /> }.into_any(),
-        view! { <Battery50 /> }.into_any(),
-        view! { <CommandLine /> }.into_any(),
-        view! { <Hashtag /> }.into_any(),
-        view! { <HandThumbDown /> }.into_any(),
-        view! { <ChartBarSquare /> }.into_any(),
-        view! { <BarsArrowDown /> }.into_any(),
-        view! { <HandThumbUp /> }.into_any(),
-        view! { <GlobeAmericas /> }.into_any(),
-        view! { <SignalSlash /> }.into_any(),
-        view! { <PresentationChartLine /> }.into_any(),
-        view! { <ExclamationCircle /> }.into_any(),
-        view! { <ChartPie /> }.into_any(),
-        view! { <MagnifyingGlassPlus /> }.into_any(),
-        view! { <Heart /> }.into_any(),
-        view! { <ArrowTurnUpLeft /> }.into_any(),
-        view! { <PercentBadge /> }.into_any(),
-        view! { <FolderArrowDown /> }.into_any(),
-        view! { <PhoneArrowUpRight /> }.into_any(),
-        view! { <LightBulb /> }.into_any(),
-        view! { <ArrowTurnRightUp /> }.into_any(),
-        view! { <ChatBubbleLeftRight /> }.into_any(),
-        view! { <ArrowsPointingOut /> }.into_any(),
-        view! { <ChatBubbleBottomCenter /> }.into_any(),
-        view! { <PuzzlePiece /> }.into_any(),
-        view! { <Cake /> }.into_any(),
-        view! { <Rss /> }.into_any(),
-        view! { <AdjustmentsVertical /> }.into_any(),
-        view! { <Language /> }.into_any(),
-        view! { <Folder /> }.into_any(),
-        view! { <H3 /> }.into_any(),
-        view! { <Signal /> }.into_any(),
-        view! { <ViewfinderCircle /> }.into_any(),
-        view! { <CodeBracket /> }.into_any(),
-        view! { <CurrencyYen /> }.into_any(),
-        view! { <DeviceTablet /> }.into_any(),
-        view! { <Fire /> }.into_any(),
-        view! { <Users /> }.into_any(),
-        view! { <FolderPlus /> }.into_any(),
-        view! { <FolderOpen /> }.into_any(),
-        view! { <ArrowTurnUpRight /> }.into_any(),
-        view! { <MagnifyingGlassMinus /> }.into_any(),
-        view! { <ArrowTrendingDown /> }.into_any(),
-        view! { <MusicalNote /> }.into_any(),
-        view! { <DocumentChartBar /> }.into_any(),
-        view! { <ArrowSmallRight /> }.into_any(),
-        view! { <Tag /> }.into_any(),
-        view! { <CurrencyRupee /> }.into_any(),
-        view! { <BookmarkSquare /> }.into_any(),
-        view! { <NumberedList /> }.into_any(),
-        view! { <Wallet /> }.into_any(),
-        view! { <ViewColumns /> }.into_any(),
-        view! { <ShieldExclamation /> }.into_any(),
-        view! { <ArrowLongRight /> }.into_any(),
-        view! { <ArrowSmallUp /> }.into_any(),
-        view! { <Moon /> }.into_any(),
-        view! { <Camera /> }.into_any(),
-        view! { <GlobeEuropeAfrica /> }.into_any(),
-        view! { <ArrowDownCircle /> }.into_any(),
-        view! { <Megaphone /> }.into_any(),
-        view! { <GlobeAsiaAustralia /> }.into_any(),
-        view! { <UserMinus /> }.into_any(),
-        view! { <Gift /> }.into_any(),
-        view! { <HomeModern /> }.into_any(),
-        view! { <PresentationChartBar /> }.into_any(),
-        view! { <ArrowSmallDown /> }.into_any(),
-        view! { <InformationCircle /> }.into_any(),
-        view! { <InboxArrowDown /> }.into_any(),
-        view! { <Equals /> }.into_any(),
-        view! { <ChartBar /> }.into_any(),
-        view! { <Share /> }.into_any(),
-        view! { <Eye /> }.into_any(),
-        view! { <ArrowLeftOnRectangle /> }.into_any(),
-        view! { <PlusSmall /> }.into_any(),
-        view! { <Power /> }.into_any(),
-        view! { <Newspaper /> }.into_any(),
-        view! { <CursorArrowRipple /> }.into_any(),
-        view! { <ArrowDownOnSquareStack /> }.into_any(),
-        view! { <ArrowRightEndOnRectangle /> }.into_any(),
-        view! { <InboxStack /> }.into_any(),
-        view! { <BookOpen /> }.into_any(),
-        view! { <ClipboardDocumentCheck /> }.into_any(),
-        view! { <EyeSlash /> }.into_any(),
-        view! { <Bars3CenterLeft /> }.into_any(),
-        view! { <EllipsisHorizontalCircle /> }.into_any(),
-        view! { <DevicePhoneMobile /> }.into_any(),
-        view! { <ArrowTurnDownRight /> }.into_any(),
-        view! { <Squares2X2 /> }.into_any(),
-        view! { <QrCode /> }.into_any(),
-        view! { <QueueList /> }.into_any(),
-        view! { <ArrowUturnUp /> }.into_any(),
-        view! { <ExclamationTriangle /> }.into_any(),
-        view! { <MinusSmall /> }.into_any(),
-        view! { <Wrench /> }.into_any(),
-        view! { <BellSlash /> }.into_any(),
-        view! { <ArrowRight /> }.into_any(),
-        view! { <Square2Stack /> }.into_any(),
-        view! { <Beaker /> }.into_any(),
-        view! { <DocumentDuplicate /> }.into_any(),
-        view! { <ArchiveBoxXMark /> }.into_any(),
-        view! { <ChatBubbleOvalLeftEllipsis /> }.into_any(),
-        view! { <Variable /> }.into_any(),
-        view! { <Bold /> }.into_any(),
-        view! { <ChatBubbleOvalLeft /> }.into_any(),
-        view! { <ArrowRightStartOnRectangle /> }.into_any(),
-        view! { <ArrowSmallLeft /> }.into_any(),
-        view! { <ArrowPathRoundedSquare /> }.into_any(),
-        view! { <ChevronRight /> }.into_any(),
-        view! { <ArrowTurnRightDown /> }.into_any(),
-        view! { <DocumentCurrencyRupee /> }.into_any(),
-        view! { <User /> }.into_any(),
-        view! { <Banknotes /> }.into_any(),
-        view! { <Calculator /> }.into_any(),
-        view! { <PhoneArrowDownLeft /> }.into_any(),
-        view! { <ArrowDownOnSquare /> }.into_any(),
-        view! { <BellAlert /> }.into_any(),
-        view! { <BuildingLibrary /> }.into_any(),
-        view! { <DocumentCurrencyYen /> }.into_any(),
-        view! { <PaintBrush /> }.into_any(),
-        view! { <ReceiptRefund /> }.into_any(),
-        view! { <Key /> }.into_any(),
-        view! { <PaperClip /> }.into_any(),
-        view! { <RectangleGroup /> }.into_any(),
-        view! { <ArrowRightOnRectangle /> }.into_any(),
-        view! { <PlusCircle /> }.into_any(),
-        view! { <CloudArrowDown /> }.into_any(),
-        view! { <DocumentArrowDown /> }.into_any(),
-        view! { <DocumentPlus /> }.into_any(),
-        view! { <H2 /> }.into_any(),
-        view! { <ArrowRightCircle /> }.into_any(),
-        view! { <Minus /> }.into_any(),
-        view! { <DocumentCurrencyBangladeshi /> }.into_any(),
-        view! { <Bookmark /> }.into_any(),
-        view! { <Trash /> }.into_any(),
-        view! { <FingerPrint /> }.into_any(),
-        view! { <ArrowLeftCircle /> }.into_any(),
-        view! { <Clock /> }.into_any(),
-        view! { <AtSymbol /> }.into_any(),
-        view! { <CursorArrowRays /> }.into_any(),
-        view! { <Cube /> }.into_any(),
-        view! { <ChevronDoubleRight /> }.into_any(),
-        view! { <SpeakerWave /> }.into_any(),
-        view! { <AdjustmentsHorizontal /> }.into_any(),
-        view! { <SquaresPlus /> }.into_any(),
-        view! { <NoSymbol /> }.into_any(),
-        view! { <EllipsisHorizontal /> }.into_any(),
-        view! { <Scale /> }.into_any(),
-        view! { <ComputerDesktop /> }.into_any(),
-        view! { <Scissors /> }.into_any(),
-        view! { <UserGroup /> }.into_any(),
-        view! { <DocumentCheck /> }.into_any(),
-        view! { <FaceSmile /> }.into_any(),
-        view! { <AcademicCap /> }.into_any(),
-        view! { <Document /> }.into_any(),
-        view! { <TableCells /> }.into_any(),
-        view! { <Bars3BottomLeft /> }.into_any(),
-        view! { <PaperAirplane /> }.into_any(),
-        view! { <CalendarDateRange /> }.into_any(),
-        view! { <Slash /> }.into_any(),
-        view! { <CurrencyBangladeshi /> }.into_any(),
-        view! { <ArrowLeftEndOnRectangle /> }.into_any(),
-        view! { <Bars3BottomRight /> }.into_any(),
-        view! { <H1 /> }.into_any(),
-        view! { <UserPlus /> }.into_any(),
-        view! { <Cog6Tooth /> }.into_any(),
-        view! { <ArrowLeftStartOnRectangle /> }.into_any(),
-        view! { <Window /> }.into_any(),
-        view! { <Sun /> }.into_any(),
-        view! { <ArrowTurnDownLeft /> }.into_any(),
-        view! { <Photo /> }.into_any(),
-        view! { <ArrowUpRight /> }.into_any(),
-        view! { <CurrencyPound /> }.into_any(),
-        view! { <FolderMinus /> }.into_any(),
-        view! { <DocumentCurrencyEuro /> }.into_any(),
-        view! { <ArchiveBox /> }.into_any(),
-        view! { <ArrowPath /> }.into_any(),
-        view! { <LinkSlash /> }.into_any(),
-        view! { <CalendarDays /> }.into_any(),
-        view! { <Pencil /> }.into_any(),
-        view! { <ChevronLeft /> }.into_any(),
-        view! { <ArrowTrendingUp /> }.into_any(),
-        view! { <WrenchScrewdriver /> }.into_any(),
-        view! { <VideoCameraSlash /> }.into_any(),
-        view! { <ArrowUpCircle /> }.into_any(),
-        view! { <Map /> }.into_any(),
-        view! { <BuildingOffice /> }.into_any(),
-        view! { <ArrowTurnLeftDown /> }.into_any(),
-        view! { <ArrowDownRight /> }.into_any(),
-        view! { <CreditCard /> }.into_any(),
-        view! { <ArrowsUpDown /> }.into_any(),
-        view! { <ChevronUpDown /> }.into_any(),
-        view! { <Cloud /> }.into_any(),
-        view! { <Wifi /> }.into_any(),
-        view! { <Underline /> }.into_any(),
-        view! { <Play /> }.into_any(),
-        view! { <CurrencyEuro /> }.into_any(),
-        view! { <BugAnt /> }.into_any(),
-        view! { <Funnel /> }.into_any(),
-        view! { <CheckCircle /> }.into_any(),
-        view! { <GlobeAlt /> }.into_any(),
-        view! { <DocumentArrowUp /> }.into_any(),
-        view! { <EyeDropper /> }.into_any(),
-        view! { <ChevronDoubleUp /> }.into_any(),
-        view! { <PencilSquare /> }.into_any(),
-        view! { <ArrowUp /> }.into_any(),
-        view! { <ChevronUp /> }.into_any(),
-        view! { <Backward /> }.into_any(),
-        view! { <CodeBracketSquare /> }.into_any(),
-        view! { <StopCircle /> }.into_any(),
-        view! { <Trophy /> }.into_any(),
-        view! { <ClipboardDocument /> }.into_any(),
-        view! { <ReceiptPercent /> }.into_any(),
-        view! { <Microphone /> }.into_any(),
-        view! { <ChevronDown /> }.into_any(),
-        view! { <ArrowsPointingIn /> }.into_any(),
-        view! { <Divide /> }.into_any(),
-        view! { <Battery100 /> }.into_any(),
-        view! { <ChatBubbleLeftEllipsis /> }.into_any(),
-        view! { <ArrowUpOnSquareStack /> }.into_any(),
-        view! { <Bars3 /> }.into_any(),
-        view! { <MagnifyingGlass /> }.into_any(),
-        view! { <ArchiveBoxArrowDown /> }.into_any(),
-        view! { <Lifebuoy /> }.into_any(),
-        view! { <MapPin /> }.into_any(),
-        view! { <Stop /> }.into_any(),
-        view! { <Clipboard /> }.into_any(),
-        view! { <CheckBadge /> }.into_any(),
-        view! { <Server /> }.into_any(),
-        view! { <Bars4 /> }.into_any(),
-        view! { <Cog /> }.into_any(),
-        view! { <Gif /> }.into_any(),
-        view! { <CurrencyDollar /> }.into_any(),
-        view! { <GiftTop /> }.into_any(),
-        view! { <ArrowUpLeft /> }.into_any(),
-        view! { <UserCircle /> }.into_any(),
-        view! { <Phone /> }.into_any(),
-        view! { <ServerStack /> }.into_any(),
-        view! { <RectangleStack /> }.into_any(),
-        view! { <FaceFrown /> }.into_any(),
-        view! { <Identification /> }.into_any(),
-        view! { <Square3Stack3D /> }.into_any(),
-        view! { <BarsArrowUp /> }.into_any(),
-        view! { <DocumentText /> }.into_any(),
-        view! { <DocumentCurrencyPound /> }.into_any(),
-        view! { <ArrowTopRightOnSquare /> }.into_any(),
-        view! { <ClipboardDocumentList /> }.into_any(),
-        view! { <CpuChip /> }.into_any(),
-        view! { <Bell /> }.into_any(),
-        view! { <Battery0 /> }.into_any(),
-        view! { <ArrowDown /> }.into_any(),
-        view! { <ChatBubbleLeft /> }.into_any(),
-        view! { <BellSnooze /> }.into_any(),
-        view! { <PlayPause /> }.into_any(),
-        view! { <Inbox /> }.into_any(),
-        view! { <Italic /> }.into_any(),
-        view! { <ArrowDownLeft /> }.into_any(),
-        view! { <Flag /> }.into_any(),
-        view! { <ArrowLongLeft /> }.into_any(),
-        view! { <Pause /> }.into_any(),
-        view! { <ChevronDoubleLeft /> }.into_any(),
-        view! { <Printer /> }.into_any(),
-        view! { <Calendar /> }.into_any(),
-        view! { <Briefcase /> }.into_any(),
-        view! { <Film /> }.into_any(),
-        view! { <ArrowDownTray /> }.into_any(),
-        view! { <HandRaised /> }.into_any(),
-        view! { <LockClosed /> }.into_any(),
-        view! { <Home /> }.into_any(),
-        view! { <ArrowUpTray /> }.into_any(),
-        view! { <VideoCamera /> }.into_any(),
-        view! { <ArrowUturnLeft /> }.into_any(),
-        view! { <PhoneXMark /> }.into_any(),
-        view! { <Truck /> }.into_any(),
-        view! { <ListBullet /> }.into_any(),
-        view! { <ArrowLongDown /> }.into_any(),
-        view! { <ChatBubbleBottomCenterText /> }.into_any(),
-        view! { <SpeakerXMark /> }.into_any(),
-        view! { <QuestionMarkCircle /> }.into_any(),
-        view! { <Bars2 /> }.into_any(),
-        view! { <ArrowLeft /> }.into_any(),
-        view! { <XMark /> }.into_any(),
-        view! { <Forward /> }.into_any(),
-        view! { <MagnifyingGlassCircle /> }.into_any(),
-        view! { <LockOpen /> }.into_any(),
-        view! { <Strikethrough /> }.into_any(),
-        view! { <Cog8Tooth /> }.into_any(),
-        view! { <DocumentCurrencyDollar /> }.into_any(),
-        view! { <ArrowUpOnSquare /> }.into_any(),
-        view! { <BuildingStorefront /> }.into_any(),
-        view! { <ArrowLongUp /> }.into_any(),
-        view! { <ArrowTurnLeftUp /> }.into_any(),
-        view! { <Bolt /> }.into_any(),
-        view! { <Check /> }.into_any(),
-        view! { <ArrowUturnDown /> }.into_any(),
-        view! { <Radio /> }.into_any(),
-        view! { <PauseCircle /> }.into_any(),
-        view! { <Link /> }.into_any(),
-        view! { <ShoppingBag /> }.into_any(),
-        view! { <Backspace /> }.into_any(),
-        view! { <EllipsisVertical /> }.into_any(),
-        view! { <MinusCircle /> }.into_any(),
-        view! { <Envelope /> }.into_any(),
-        view! { <ArrowsRightLeft /> }.into_any(),
-        view! { <Sparkles /> }.into_any(),
-        view! { <Star /> }.into_any(),
-        view! { <Swatch /> }.into_any(),
-        view! { <BookmarkSlash /> }.into_any(),
-        view! { <CircleStack /> }.into_any(),
-        view! { <ShoppingCart /> }.into_any(),
-        view! { <Tv /> }.into_any(),
-        view! { <XCircle /> }.into_any(),
-        view! { <ChevronDoubleDown /> }.into_any(),
+        GalleryIcon {
+            name: "academic-cap",
+            component: "AcademicCap",
+            module: "size_16::solid",
+            feature: "16-solid-academic-cap",
+            view: || view! { <AcademicCap /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "adjustments-horizontal",
+            component: "AdjustmentsHorizontal",
+            module: "size_16::solid",
+            feature: "16-solid-adjustments-horizontal",
+            view: || view! { <AdjustmentsHorizontal /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "adjustments-vertical",
+            component: "AdjustmentsVertical",
+            module: "size_16::solid",
+            feature: "16-solid-adjustments-vertical",
+            view: || view! { <AdjustmentsVertical /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "archive-box-arrow-down",
+            component: "ArchiveBoxArrowDown",
+            module: "size_16::solid",
+            feature: "16-solid-archive-box-arrow-down",
+            view: || view! { <ArchiveBoxArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "archive-box-x-mark",
+            component: "ArchiveBoxXMark",
+            module: "size_16::solid",
+            feature: "16-solid-archive-box-x-mark",
+            view: || view! { <ArchiveBoxXMark /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "archive-box",
+            component: "ArchiveBox",
+            module: "size_16::solid",
+            feature: "16-solid-archive-box",
+            view: || view! { <ArchiveBox /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-circle",
+            component: "ArrowDownCircle",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-down-circle",
+            view: || view! { <ArrowDownCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-left",
+            component: "ArrowDownLeft",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-down-left",
+            view: || view! { <ArrowDownLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-on-square-stack",
+            component: "ArrowDownOnSquareStack",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-down-on-square-stack",
+            view: || view! { <ArrowDownOnSquareStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-on-square",
+            component: "ArrowDownOnSquare",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-down-on-square",
+            view: || view! { <ArrowDownOnSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-right",
+            component: "ArrowDownRight",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-down-right",
+            view: || view! { <ArrowDownRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-tray",
+            component: "ArrowDownTray",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-down-tray",
+            view: || view! { <ArrowDownTray /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down",
+            component: "ArrowDown",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-down",
+            view: || view! { <ArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left-circle",
+            component: "ArrowLeftCircle",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-left-circle",
+            view: || view! { <ArrowLeftCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left-end-on-rectangle",
+            component: "ArrowLeftEndOnRectangle",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-left-end-on-rectangle",
+            view: || view! { <ArrowLeftEndOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left-start-on-rectangle",
+            component: "ArrowLeftStartOnRectangle",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-left-start-on-rectangle",
+            view: || view! { <ArrowLeftStartOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left",
+            component: "ArrowLeft",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-left",
+            view: || view! { <ArrowLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-long-down",
+            component: "ArrowLongDown",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-long-down",
+            view: || view! { <ArrowLongDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-long-left",
+            component: "ArrowLongLeft",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-long-left",
+            view: || view! { <ArrowLongLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-long-right",
+            component: "ArrowLongRight",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-long-right",
+            view: || view! { <ArrowLongRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-long-up",
+            component: "ArrowLongUp",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-long-up",
+            view: || view! { <ArrowLongUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-path-rounded-square",
+            component: "ArrowPathRoundedSquare",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-path-rounded-square",
+            view: || view! { <ArrowPathRoundedSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-path",
+            component: "ArrowPath",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-path",
+            view: || view! { <ArrowPath /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right-circle",
+            component: "ArrowRightCircle",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-right-circle",
+            view: || view! { <ArrowRightCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right-end-on-rectangle",
+            component: "ArrowRightEndOnRectangle",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-right-end-on-rectangle",
+            view: || view! { <ArrowRightEndOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right-start-on-rectangle",
+            component: "ArrowRightStartOnRectangle",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-right-start-on-rectangle",
+            view: || view! { <ArrowRightStartOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right",
+            component: "ArrowRight",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-right",
+            view: || view! { <ArrowRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-top-right-on-square",
+            component: "ArrowTopRightOnSquare",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-top-right-on-square",
+            view: || view! { <ArrowTopRightOnSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-trending-down",
+            component: "ArrowTrendingDown",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-trending-down",
+            view: || view! { <ArrowTrendingDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-trending-up",
+            component: "ArrowTrendingUp",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-trending-up",
+            view: || view! { <ArrowTrendingUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-down-left",
+            component: "ArrowTurnDownLeft",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-turn-down-left",
+            view: || view! { <ArrowTurnDownLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-down-right",
+            component: "ArrowTurnDownRight",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-turn-down-right",
+            view: || view! { <ArrowTurnDownRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-left-down",
+            component: "ArrowTurnLeftDown",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-turn-left-down",
+            view: || view! { <ArrowTurnLeftDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-left-up",
+            component: "ArrowTurnLeftUp",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-turn-left-up",
+            view: || view! { <ArrowTurnLeftUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-right-down",
+            component: "ArrowTurnRightDown",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-turn-right-down",
+            view: || view! { <ArrowTurnRightDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-right-up",
+            component: "ArrowTurnRightUp",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-turn-right-up",
+            view: || view! { <ArrowTurnRightUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-up-left",
+            component: "ArrowTurnUpLeft",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-turn-up-left",
+            view: || view! { <ArrowTurnUpLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-up-right",
+            component: "ArrowTurnUpRight",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-turn-up-right",
+            view: || view! { <ArrowTurnUpRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-circle",
+            component: "ArrowUpCircle",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-up-circle",
+            view: || view! { <ArrowUpCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-left",
+            component: "ArrowUpLeft",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-up-left",
+            view: || view! { <ArrowUpLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-on-square-stack",
+            component: "ArrowUpOnSquareStack",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-up-on-square-stack",
+            view: || view! { <ArrowUpOnSquareStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-on-square",
+            component: "ArrowUpOnSquare",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-up-on-square",
+            view: || view! { <ArrowUpOnSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-right",
+            component: "ArrowUpRight",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-up-right",
+            view: || view! { <ArrowUpRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-tray",
+            component: "ArrowUpTray",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-up-tray",
+            view: || view! { <ArrowUpTray /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up",
+            component: "ArrowUp",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-up",
+            view: || view! { <ArrowUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-uturn-down",
+            component: "ArrowUturnDown",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-uturn-down",
+            view: || view! { <ArrowUturnDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-uturn-left",
+            component: "ArrowUturnLeft",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-uturn-left",
+            view: || view! { <ArrowUturnLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-uturn-right",
+            component: "ArrowUturnRight",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-uturn-right",
+            view: || view! { <ArrowUturnRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-uturn-up",
+            component: "ArrowUturnUp",
+            module: "size_16::solid",
+            feature: "16-solid-arrow-uturn-up",
+            view: || view! { <ArrowUturnUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrows-pointing-in",
+            component: "ArrowsPointingIn",
+            module: "size_16::solid",
+            feature: "16-solid-arrows-pointing-in",
+            view: || view! { <ArrowsPointingIn /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrows-pointing-out",
+            component: "ArrowsPointingOut",
+            module: "size_16::solid",
+            feature: "16-solid-arrows-pointing-out",
+            view: || view! { <ArrowsPointingOut /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrows-right-left",
+            component: "ArrowsRightLeft",
+            module: "size_16::solid",
+            feature: "16-solid-arrows-right-left",
+            view: || view! { <ArrowsRightLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrows-up-down",
+            component: "ArrowsUpDown",
+            module: "size_16::solid",
+            feature: "16-solid-arrows-up-down",
+            view: || view! { <ArrowsUpDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "at-symbol",
+            component: "AtSymbol",
+            module: "size_16::solid",
+            feature: "16-solid-at-symbol",
+            view: || view! { <AtSymbol /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "backspace",
+            component: "Backspace",
+            module: "size_16::solid",
+            feature: "16-solid-backspace",
+            view: || view! { <Backspace /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "backward",
+            component: "Backward",
+            module: "size_16::solid",
+            feature: "16-solid-backward",
+            view: || view! { <Backward /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "banknotes",
+            component: "Banknotes",
+            module: "size_16::solid",
+            feature: "16-solid-banknotes",
+            view: || view! { <Banknotes /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-2",
+            component: "Bars2",
+            module: "size_16::solid",
+            feature: "16-solid-bars-2",
+            view: || view! { <Bars2 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-3-bottom-left",
+            component: "Bars3BottomLeft",
+            module: "size_16::solid",
+            feature: "16-solid-bars-3-bottom-left",
+            view: || view! { <Bars3BottomLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-3-bottom-right",
+            component: "Bars3BottomRight",
+            module: "size_16::solid",
+            feature: "16-solid-bars-3-bottom-right",
+            view: || view! { <Bars3BottomRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-3-center-left",
+            component: "Bars3CenterLeft",
+            module: "size_16::solid",
+            feature: "16-solid-bars-3-center-left",
+            view: || view! { <Bars3CenterLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-3",
+            component: "Bars3",
+            module: "size_16::solid",
+            feature: "16-solid-bars-3",
+            view: || view! { <Bars3 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-4",
+            component: "Bars4",
+            module: "size_16::solid",
+            feature: "16-solid-bars-4",
+            view: || view! { <Bars4 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-arrow-down",
+            component: "BarsArrowDown",
+            module: "size_16::solid",
+            feature: "16-solid-bars-arrow-down",
+            view: || view! { <BarsArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-arrow-up",
+            component: "BarsArrowUp",
+            module: "size_16::solid",
+            feature: "16-solid-bars-arrow-up",
+            view: || view! { <BarsArrowUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "battery-0",
+            component: "Battery0",
+            module: "size_16::solid",
+            feature: "16-solid-battery-0",
+            view: || view! { <Battery0 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "battery-100",
+            component: "Battery100",
+            module: "size_16::solid",
+            feature: "16-solid-battery-100",
+            view: || view! { <Battery100 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "battery-50",
+            component: "Battery50",
+            module: "size_16::solid",
+            feature: "16-solid-battery-50",
+            view: || view! { <Battery50 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "beaker",
+            component: "Beaker",
+            module: "size_16::solid",
+            feature: "16-solid-beaker",
+            view: || view! { <Beaker /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bell-alert",
+            component: "BellAlert",
+            module: "size_16::solid",
+            feature: "16-solid-bell-alert",
+            view: || view! { <BellAlert /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bell-slash",
+            component: "BellSlash",
+            module: "size_16::solid",
+            feature: "16-solid-bell-slash",
+            view: || view! { <BellSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bell-snooze",
+            component: "BellSnooze",
+            module: "size_16::solid",
+            feature: "16-solid-bell-snooze",
+            view: || view! { <BellSnooze /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bell",
+            component: "Bell",
+            module: "size_16::solid",
+            feature: "16-solid-bell",
+            view: || view! { <Bell /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bold",
+            component: "Bold",
+            module: "size_16::solid",
+            feature: "16-solid-bold",
+            view: || view! { <Bold /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bolt-slash",
+            component: "BoltSlash",
+            module: "size_16::solid",
+            feature: "16-solid-bolt-slash",
+            view: || view! { <BoltSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bolt",
+            component: "Bolt",
+            module: "size_16::solid",
+            feature: "16-solid-bolt",
+            view: || view! { <Bolt /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "book-open",
+            component: "BookOpen",
+            module: "size_16::solid",
+            feature: "16-solid-book-open",
+            view: || view! { <BookOpen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bookmark-slash",
+            component: "BookmarkSlash",
+            module: "size_16::solid",
+            feature: "16-solid-bookmark-slash",
+            view: || view! { <BookmarkSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bookmark-square",
+            component: "BookmarkSquare",
+            module: "size_16::solid",
+            feature: "16-solid-bookmark-square",
+            view: || view! { <BookmarkSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bookmark",
+            component: "Bookmark",
+            module: "size_16::solid",
+            feature: "16-solid-bookmark",
+            view: || view! { <Bookmark /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "briefcase",
+            component: "Briefcase",
+            module: "size_16::solid",
+            feature: "16-solid-briefcase",
+            view: || view! { <Briefcase /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bug-ant",
+            component: "BugAnt",
+            module: "size_16::solid",
+            feature: "16-solid-bug-ant",
+            view: || view! { <BugAnt /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "building-library",
+            component: "BuildingLibrary",
+            module: "size_16::solid",
+            feature: "16-solid-building-library",
+            view: || view! { <BuildingLibrary /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "building-office-2",
+            component: "BuildingOffice2",
+            module: "size_16::solid",
+            feature: "16-solid-building-office-2",
+            view: || view! { <BuildingOffice2 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "building-office",
+            component: "BuildingOffice",
+            module: "size_16::solid",
+            feature: "16-solid-building-office",
+            view: || view! { <BuildingOffice /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "building-storefront",
+            component: "BuildingStorefront",
+            module: "size_16::solid",
+            feature: "16-solid-building-storefront",
+            view: || view! { <BuildingStorefront /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cake",
+            component: "Cake",
+            module: "size_16::solid",
+            feature: "16-solid-cake",
+            view: || view! { <Cake /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "calculator",
+            component: "Calculator",
+            module: "size_16::solid",
+            feature: "16-solid-calculator",
+            view: || view! { <Calculator /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "calendar-date-range",
+            component: "CalendarDateRange",
+            module: "size_16::solid",
+            feature: "16-solid-calendar-date-range",
+            view: || view! { <CalendarDateRange /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "calendar-days",
+            component: "CalendarDays",
+            module: "size_16::solid",
+            feature: "16-solid-calendar-days",
+            view: || view! { <CalendarDays /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "calendar",
+            component: "Calendar",
+            module: "size_16::solid",
+            feature: "16-solid-calendar",
+            view: || view! { <Calendar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "camera",
+            component: "Camera",
+            module: "size_16::solid",
+            feature: "16-solid-camera",
+            view: || view! { <Camera /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chart-bar-square",
+            component: "ChartBarSquare",
+            module: "size_16::solid",
+            feature: "16-solid-chart-bar-square",
+            view: || view! { <ChartBarSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chart-bar",
+            component: "ChartBar",
+            module: "size_16::solid",
+            feature: "16-solid-chart-bar",
+            view: || view! { <ChartBar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chart-pie",
+            component: "ChartPie",
+            module: "size_16::solid",
+            feature: "16-solid-chart-pie",
+            view: || view! { <ChartPie /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-bottom-center-text",
+            component: "ChatBubbleBottomCenterText",
+            module: "size_16::solid",
+            feature: "16-solid-chat-bubble-bottom-center-text",
+            view: || view! { <ChatBubbleBottomCenterText /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-bottom-center",
+            component: "ChatBubbleBottomCenter",
+            module: "size_16::solid",
+            feature: "16-solid-chat-bubble-bottom-center",
+            view: || view! { <ChatBubbleBottomCenter /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-left-ellipsis",
+            component: "ChatBubbleLeftEllipsis",
+            module: "size_16::solid",
+            feature: "16-solid-chat-bubble-left-ellipsis",
+            view: || view! { <ChatBubbleLeftEllipsis /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-left-right",
+            component: "ChatBubbleLeftRight",
+            module: "size_16::solid",
+            feature: "16-solid-chat-bubble-left-right",
+            view: || view! { <ChatBubbleLeftRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-left",
+            component: "ChatBubbleLeft",
+            module: "size_16::solid",
+            feature: "16-solid-chat-bubble-left",
+            view: || view! { <ChatBubbleLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-oval-left-ellipsis",
+            component: "ChatBubbleOvalLeftEllipsis",
+            module: "size_16::solid",
+            feature: "16-solid-chat-bubble-oval-left-ellipsis",
+            view: || view! { <ChatBubbleOvalLeftEllipsis /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-oval-left",
+            component: "ChatBubbleOvalLeft",
+            module: "size_16::solid",
+            feature: "16-solid-chat-bubble-oval-left",
+            view: || view! { <ChatBubbleOvalLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "check-badge",
+            component: "CheckBadge",
+            module: "size_16::solid",
+            feature: "16-solid-check-badge",
+            view: || view! { <CheckBadge /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "check-circle",
+            component: "CheckCircle",
+            module: "size_16::solid",
+            feature: "16-solid-check-circle",
+            view: || view! { <CheckCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "check",
+            component: "Check",
+            module: "size_16::solid",
+            feature: "16-solid-check",
+            view: || view! { <Check /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-double-down",
+            component: "ChevronDoubleDown",
+            module: "size_16::solid",
+            feature: "16-solid-chevron-double-down",
+            view: || view! { <ChevronDoubleDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-double-left",
+            component: "ChevronDoubleLeft",
+            module: "size_16::solid",
+            feature: "16-solid-chevron-double-left",
+            view: || view! { <ChevronDoubleLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-double-right",
+            component: "ChevronDoubleRight",
+            module: "size_16::solid",
+            feature: "16-solid-chevron-double-right",
+            view: || view! { <ChevronDoubleRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-double-up",
+            component: "ChevronDoubleUp",
+            module: "size_16::solid",
+            feature: "16-solid-chevron-double-up",
+            view: || view! { <ChevronDoubleUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-down",
+            component: "ChevronDown",
+            module: "size_16::solid",
+            feature: "16-solid-chevron-down",
+            view: || view! { <ChevronDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-left",
+            component: "ChevronLeft",
+            module: "size_16::solid",
+            feature: "16-solid-chevron-left",
+            view: || view! { <ChevronLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-right",
+            component: "ChevronRight",
+            module: "size_16::solid",
+            feature: "16-solid-chevron-right",
+            view: || view! { <ChevronRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-up-down",
+            component: "ChevronUpDown",
+            module: "size_16::solid",
+            feature: "16-solid-chevron-up-down",
+            view: || view! { <ChevronUpDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-up",
+            component: "ChevronUp",
+            module: "size_16::solid",
+            feature: "16-solid-chevron-up",
+            view: || view! { <ChevronUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "circle-stack",
+            component: "CircleStack",
+            module: "size_16::solid",
+            feature: "16-solid-circle-stack",
+            view: || view! { <CircleStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clipboard-document-check",
+            component: "ClipboardDocumentCheck",
+            module: "size_16::solid",
+            feature: "16-solid-clipboard-document-check",
+            view: || view! { <ClipboardDocumentCheck /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clipboard-document-list",
+            component: "ClipboardDocumentList",
+            module: "size_16::solid",
+            feature: "16-solid-clipboard-document-list",
+            view: || view! { <ClipboardDocumentList /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clipboard-document",
+            component: "ClipboardDocument",
+            module: "size_16::solid",
+            feature: "16-solid-clipboard-document",
+            view: || view! { <ClipboardDocument /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clipboard",
+            component: "Clipboard",
+            module: "size_16::solid",
+            feature: "16-solid-clipboard",
+            view: || view! { <Clipboard /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clock",
+            component: "Clock",
+            module: "size_16::solid",
+            feature: "16-solid-clock",
+            view: || view! { <Clock /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cloud-arrow-down",
+            component: "CloudArrowDown",
+            module: "size_16::solid",
+            feature: "16-solid-cloud-arrow-down",
+            view: || view! { <CloudArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cloud-arrow-up",
+            component: "CloudArrowUp",
+            module: "size_16::solid",
+            feature: "16-solid-cloud-arrow-up",
+            view: || view! { <CloudArrowUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cloud",
+            component: "Cloud",
+            module: "size_16::solid",
+            feature: "16-solid-cloud",
+            view: || view! { <Cloud /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "code-bracket-square",
+            component: "CodeBracketSquare",
+            module: "size_16::solid",
+            feature: "16-solid-code-bracket-square",
+            view: || view! { <CodeBracketSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "code-bracket",
+            component: "CodeBracket",
+            module: "size_16::solid",
+            feature: "16-solid-code-bracket",
+            view: || view! { <CodeBracket /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cog-6-tooth",
+            component: "Cog6Tooth",
+            module: "size_16::solid",
+            feature: "16-solid-cog-6-tooth",
+            view: || view! { <Cog6Tooth /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cog-8-tooth",
+            component: "Cog8Tooth",
+            module: "size_16::solid",
+            feature: "16-solid-cog-8-tooth",
+            view: || view! { <Cog8Tooth /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cog",
+            component: "Cog",
+            module: "size_16::solid",
+            feature: "16-solid-cog",
+            view: || view! { <Cog /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "command-line",
+            component: "CommandLine",
+            module: "size_16::solid",
+            feature: "16-solid-command-line",
+            view: || view! { <CommandLine /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "computer-desktop",
+            component: "ComputerDesktop",
+            module: "size_16::solid",
+            feature: "16-solid-computer-desktop",
+            view: || view! { <ComputerDesktop /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cpu-chip",
+            component: "CpuChip",
+            module: "size_16::solid",
+            feature: "16-solid-cpu-chip",
+            view: || view! { <CpuChip /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "credit-card",
+            component: "CreditCard",
+            module: "size_16::solid",
+            feature: "16-solid-credit-card",
+            view: || view! { <CreditCard /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cube-transparent",
+            component: "CubeTransparent",
+            module: "size_16::solid",
+            feature: "16-solid-cube-transparent",
+            view: || view! { <CubeTransparent /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cube",
+            component: "Cube",
+            module: "size_16::solid",
+            feature: "16-solid-cube",
+            view: || view! { <Cube /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-bangladeshi",
+            component: "CurrencyBangladeshi",
+            module: "size_16::solid",
+            feature: "16-solid-currency-bangladeshi",
+            view: || view! { <CurrencyBangladeshi /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-dollar",
+            component: "CurrencyDollar",
+            module: "size_16::solid",
+            feature: "16-solid-currency-dollar",
+            view: || view! { <CurrencyDollar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-euro",
+            component: "CurrencyEuro",
+            module: "size_16::solid",
+            feature: "16-solid-currency-euro",
+            view: || view! { <CurrencyEuro /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-pound",
+            component: "CurrencyPound",
+            module: "size_16::solid",
+            feature: "16-solid-currency-pound",
+            view: || view! { <CurrencyPound /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-rupee",
+            component: "CurrencyRupee",
+            module: "size_16::solid",
+            feature: "16-solid-currency-rupee",
+            view: || view! { <CurrencyRupee /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-yen",
+            component: "CurrencyYen",
+            module: "size_16::solid",
+            feature: "16-solid-currency-yen",
+            view: || view! { <CurrencyYen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cursor-arrow-rays",
+            component: "CursorArrowRays",
+            module: "size_16::solid",
+            feature: "16-solid-cursor-arrow-rays",
+            view: || view! { <CursorArrowRays /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cursor-arrow-ripple",
+            component: "CursorArrowRipple",
+            module: "size_16::solid",
+            feature: "16-solid-cursor-arrow-ripple",
+            view: || view! { <CursorArrowRipple /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "device-phone-mobile",
+            component: "DevicePhoneMobile",
+            module: "size_16::solid",
+            feature: "16-solid-device-phone-mobile",
+            view: || view! { <DevicePhoneMobile /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "device-tablet",
+            component: "DeviceTablet",
+            module: "size_16::solid",
+            feature: "16-solid-device-tablet",
+            view: || view! { <DeviceTablet /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "divide",
+            component: "Divide",
+            module: "size_16::solid",
+            feature: "16-solid-divide",
+            view: || view! { <Divide /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-arrow-down",
+            component: "DocumentArrowDown",
+            module: "size_16::solid",
+            feature: "16-solid-document-arrow-down",
+            view: || view! { <DocumentArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-arrow-up",
+            component: "DocumentArrowUp",
+            module: "size_16::solid",
+            feature: "16-solid-document-arrow-up",
+            view: || view! { <DocumentArrowUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-chart-bar",
+            component: "DocumentChartBar",
+            module: "size_16::solid",
+            feature: "16-solid-document-chart-bar",
+            view: || view! { <DocumentChartBar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-check",
+            component: "DocumentCheck",
+            module: "size_16::solid",
+            feature: "16-solid-document-check",
+            view: || view! { <DocumentCheck /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-bangladeshi",
+            component: "DocumentCurrencyBangladeshi",
+            module: "size_16::solid",
+            feature: "16-solid-document-currency-bangladeshi",
+            view: || view! { <DocumentCurrencyBangladeshi /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-dollar",
+            component: "DocumentCurrencyDollar",
+            module: "size_16::solid",
+            feature: "16-solid-document-currency-dollar",
+            view: || view! { <DocumentCurrencyDollar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-euro",
+            component: "DocumentCurrencyEuro",
+            module: "size_16::solid",
+            feature: "16-solid-document-currency-euro",
+            view: || view! { <DocumentCurrencyEuro /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-pound",
+            component: "DocumentCurrencyPound",
+            module: "size_16::solid",
+            feature: "16-solid-document-currency-pound",
+            view: || view! { <DocumentCurrencyPound /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-rupee",
+            component: "DocumentCurrencyRupee",
+            module: "size_16::solid",
+            feature: "16-solid-document-currency-rupee",
+            view: || view! { <DocumentCurrencyRupee /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-yen",
+            component: "DocumentCurrencyYen",
+            module: "size_16::solid",
+            feature: "16-solid-document-currency-yen",
+            view: || view! { <DocumentCurrencyYen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-duplicate",
+            component: "DocumentDuplicate",
+            module: "size_16::solid",
+            feature: "16-solid-document-duplicate",
+            view: || view! { <DocumentDuplicate /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-magnifying-glass",
+            component: "DocumentMagnifyingGlass",
+            module: "size_16::solid",
+            feature: "16-solid-document-magnifying-glass",
+            view: || view! { <DocumentMagnifyingGlass /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-minus",
+            component: "DocumentMinus",
+            module: "size_16::solid",
+            feature: "16-solid-document-minus",
+            view: || view! { <DocumentMinus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-plus",
+            component: "DocumentPlus",
+            module: "size_16::solid",
+            feature: "16-solid-document-plus",
+            view: || view! { <DocumentPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-text",
+            component: "DocumentText",
+            module: "size_16::solid",
+            feature: "16-solid-document-text",
+            view: || view! { <DocumentText /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document",
+            component: "Document",
+            module: "size_16::solid",
+            feature: "16-solid-document",
+            view: || view! { <Document /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "ellipsis-horizontal-circle",
+            component: "EllipsisHorizontalCircle",
+            module: "size_16::solid",
+            feature: "16-solid-ellipsis-horizontal-circle",
+            view: || view! { <EllipsisHorizontalCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "ellipsis-horizontal",
+            component: "EllipsisHorizontal",
+            module: "size_16::solid",
+            feature: "16-solid-ellipsis-horizontal",
+            view: || view! { <EllipsisHorizontal /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "ellipsis-vertical",
+            component: "EllipsisVertical",
+            module: "size_16::solid",
+            feature: "16-solid-ellipsis-vertical",
+            view: || view! { <EllipsisVertical /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "envelope-open",
+            component: "EnvelopeOpen",
+            module: "size_16::solid",
+            feature: "16-solid-envelope-open",
+            view: || view! { <EnvelopeOpen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "envelope",
+            component: "Envelope",
+            module: "size_16::solid",
+            feature: "16-solid-envelope",
+            view: || view! { <Envelope /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "equals",
+            component: "Equals",
+            module: "size_16::solid",
+            feature: "16-solid-equals",
+            view: || view! { <Equals /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "exclamation-circle",
+            component: "ExclamationCircle",
+            module: "size_16::solid",
+            feature: "16-solid-exclamation-circle",
+            view: || view! { <ExclamationCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "exclamation-triangle",
+            component: "ExclamationTriangle",
+            module: "size_16::solid",
+            feature: "16-solid-exclamation-triangle",
+            view: || view! { <ExclamationTriangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "eye-dropper",
+            component: "EyeDropper",
+            module: "size_16::solid",
+            feature: "16-solid-eye-dropper",
+            view: || view! { <EyeDropper /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "eye-slash",
+            component: "EyeSlash",
+            module: "size_16::solid",
+            feature: "16-solid-eye-slash",
+            view: || view! { <EyeSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "eye",
+            component: "Eye",
+            module: "size_16::solid",
+            feature: "16-solid-eye",
+            view: || view! { <Eye /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "face-frown",
+            component: "FaceFrown",
+            module: "size_16::solid",
+            feature: "16-solid-face-frown",
+            view: || view! { <FaceFrown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "face-smile",
+            component: "FaceSmile",
+            module: "size_16::solid",
+            feature: "16-solid-face-smile",
+            view: || view! { <FaceSmile /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "film",
+            component: "Film",
+            module: "size_16::solid",
+            feature: "16-solid-film",
+            view: || view! { <Film /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "finger-print",
+            component: "FingerPrint",
+            module: "size_16::solid",
+            feature: "16-solid-finger-print",
+            view: || view! { <FingerPrint /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "fire",
+            component: "Fire",
+            module: "size_16::solid",
+            feature: "16-solid-fire",
+            view: || view! { <Fire /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "flag",
+            component: "Flag",
+            module: "size_16::solid",
+            feature: "16-solid-flag",
+            view: || view! { <Flag /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder-arrow-down",
+            component: "FolderArrowDown",
+            module: "size_16::solid",
+            feature: "16-solid-folder-arrow-down",
+            view: || view! { <FolderArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder-minus",
+            component: "FolderMinus",
+            module: "size_16::solid",
+            feature: "16-solid-folder-minus",
+            view: || view! { <FolderMinus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder-open",
+            component: "FolderOpen",
+            module: "size_16::solid",
+            feature: "16-solid-folder-open",
+            view: || view! { <FolderOpen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder-plus",
+            component: "FolderPlus",
+            module: "size_16::solid",
+            feature: "16-solid-folder-plus",
+            view: || view! { <FolderPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder",
+            component: "Folder",
+            module: "size_16::solid",
+            feature: "16-solid-folder",
+            view: || view! { <Folder /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "forward",
+            component: "Forward",
+            module: "size_16::solid",
+            feature: "16-solid-forward",
+            view: || view! { <Forward /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "funnel",
+            component: "Funnel",
+            module: "size_16::solid",
+            feature: "16-solid-funnel",
+            view: || view! { <Funnel /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "gif",
+            component: "Gif",
+            module: "size_16::solid",
+            feature: "16-solid-gif",
+            view: || view! { <Gif /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "gift-top",
+            component: "GiftTop",
+            module: "size_16::solid",
+            feature: "16-solid-gift-top",
+            view: || view! { <GiftTop /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "gift",
+            component: "Gift",
+            module: "size_16::solid",
+            feature: "16-solid-gift",
+            view: || view! { <Gift /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "globe-alt",
+            component: "GlobeAlt",
+            module: "size_16::solid",
+            feature: "16-solid-globe-alt",
+            view: || view! { <GlobeAlt /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "globe-americas",
+            component: "GlobeAmericas",
+            module: "size_16::solid",
+            feature: "16-solid-globe-americas",
+            view: || view! { <GlobeAmericas /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "globe-asia-australia",
+            component: "GlobeAsiaAustralia",
+            module: "size_16::solid",
+            feature: "16-solid-globe-asia-australia",
+            view: || view! { <GlobeAsiaAustralia /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "globe-europe-africa",
+            component: "GlobeEuropeAfrica",
+            module: "size_16::solid",
+            feature: "16-solid-globe-europe-africa",
+            view: || view! { <GlobeEuropeAfrica /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "h1",
+            component: "H1",
+            module: "size_16::solid",
+            feature: "16-solid-h1",
+            view: || view! { <H1 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "h2",
+            component: "H2",
+            module: "size_16::solid",
+            feature: "16-solid-h2",
+            view: || view! { <H2 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "h3",
+            component: "H3",
+            module: "size_16::solid",
+            feature: "16-solid-h3",
+            view: || view! { <H3 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "hand-raised",
+            component: "HandRaised",
+            module: "size_16::solid",
+            feature: "16-solid-hand-raised",
+            view: || view! { <HandRaised /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "hand-thumb-down",
+            component: "HandThumbDown",
+            module: "size_16::solid",
+            feature: "16-solid-hand-thumb-down",
+            view: || view! { <HandThumbDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "hand-thumb-up",
+            component: "HandThumbUp",
+            module: "size_16::solid",
+            feature: "16-solid-hand-thumb-up",
+            view: || view! { <HandThumbUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "hashtag",
+            component: "Hashtag",
+            module: "size_16::solid",
+            feature: "16-solid-hashtag",
+            view: || view! { <Hashtag /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "heart",
+            component: "Heart",
+            module: "size_16::solid",
+            feature: "16-solid-heart",
+            view: || view! { <Heart /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "home-modern",
+            component: "HomeModern",
+            module: "size_16::solid",
+            feature: "16-solid-home-modern",
+            view: || view! { <HomeModern /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "home",
+            component: "Home",
+            module: "size_16::solid",
+            feature: "16-solid-home",
+            view: || view! { <Home /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "identification",
+            component: "Identification",
+            module: "size_16::solid",
+            feature: "16-solid-identification",
+            view: || view! { <Identification /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "inbox-arrow-down",
+            component: "InboxArrowDown",
+            module: "size_16::solid",
+            feature: "16-solid-inbox-arrow-down",
+            view: || view! { <InboxArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "inbox-stack",
+            component: "InboxStack",
+            module: "size_16::solid",
+            feature: "16-solid-inbox-stack",
+            view: || view! { <InboxStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "inbox",
+            component: "Inbox",
+            module: "size_16::solid",
+            feature: "16-solid-inbox",
+            view: || view! { <Inbox /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "information-circle",
+            component: "InformationCircle",
+            module: "size_16::solid",
+            feature: "16-solid-information-circle",
+            view: || view! { <InformationCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "italic",
+            component: "Italic",
+            module: "size_16::solid",
+            feature: "16-solid-italic",
+            view: || view! { <Italic /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "key",
+            component: "Key",
+            module: "size_16::solid",
+            feature: "16-solid-key",
+            view: || view! { <Key /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "language",
+            component: "Language",
+            module: "size_16::solid",
+            feature: "16-solid-language",
+            view: || view! { <Language /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "lifebuoy",
+            component: "Lifebuoy",
+            module: "size_16::solid",
+            feature: "16-solid-lifebuoy",
+            view: || view! { <Lifebuoy /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "light-bulb",
+            component: "LightBulb",
+            module: "size_16::solid",
+            feature: "16-solid-light-bulb",
+            view: || view! { <LightBulb /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "link-slash",
+            component: "LinkSlash",
+            module: "size_16::solid",
+            feature: "16-solid-link-slash",
+            view: || view! { <LinkSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "link",
+            component: "Link",
+            module: "size_16::solid",
+            feature: "16-solid-link",
+            view: || view! { <Link /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "list-bullet",
+            component: "ListBullet",
+            module: "size_16::solid",
+            feature: "16-solid-list-bullet",
+            view: || view! { <ListBullet /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "lock-closed",
+            component: "LockClosed",
+            module: "size_16::solid",
+            feature: "16-solid-lock-closed",
+            view: || view! { <LockClosed /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "lock-open",
+            component: "LockOpen",
+            module: "size_16::solid",
+            feature: "16-solid-lock-open",
+            view: || view! { <LockOpen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "magnifying-glass-circle",
+            component: "MagnifyingGlassCircle",
+            module: "size_16::solid",
+            feature: "16-solid-magnifying-glass-circle",
+            view: || view! { <MagnifyingGlassCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "magnifying-glass-minus",
+            component: "MagnifyingGlassMinus",
+            module: "size_16::solid",
+            feature: "16-solid-magnifying-glass-minus",
+            view: || view! { <MagnifyingGlassMinus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "magnifying-glass-plus",
+            component: "MagnifyingGlassPlus",
+            module: "size_16::solid",
+            feature: "16-solid-magnifying-glass-plus",
+            view: || view! { <MagnifyingGlassPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "magnifying-glass",
+            component: "MagnifyingGlass",
+            module: "size_16::solid",
+            feature: "16-solid-magnifying-glass",
+            view: || view! { <MagnifyingGlass /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "map-pin",
+            component: "MapPin",
+            module: "size_16::solid",
+            feature: "16-solid-map-pin",
+            view: || view! { <MapPin /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "map",
+            component: "Map",
+            module: "size_16::solid",
+            feature: "16-solid-map",
+            view: || view! { <Map /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "megaphone",
+            component: "Megaphone",
+            module: "size_16::solid",
+            feature: "16-solid-megaphone",
+            view: || view! { <Megaphone /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "microphone",
+            component: "Microphone",
+            module: "size_16::solid",
+            feature: "16-solid-microphone",
+            view: || view! { <Microphone /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "minus-circle",
+            component: "MinusCircle",
+            module: "size_16::solid",
+            feature: "16-solid-minus-circle",
+            view: || view! { <MinusCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "minus",
+            component: "Minus",
+            module: "size_16::solid",
+            feature: "16-solid-minus",
+            view: || view! { <Minus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "moon",
+            component: "Moon",
+            module: "size_16::solid",
+            feature: "16-solid-moon",
+            view: || view! { <Moon /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "musical-note",
+            component: "MusicalNote",
+            module: "size_16::solid",
+            feature: "16-solid-musical-note",
+            view: || view! { <MusicalNote /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "newspaper",
+            component: "Newspaper",
+            module: "size_16::solid",
+            feature: "16-solid-newspaper",
+            view: || view! { <Newspaper /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "no-symbol",
+            component: "NoSymbol",
+            module: "size_16::solid",
+            feature: "16-solid-no-symbol",
+            view: || view! { <NoSymbol /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "numbered-list",
+            component: "NumberedList",
+            module: "size_16::solid",
+            feature: "16-solid-numbered-list",
+            view: || view! { <NumberedList /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "paint-brush",
+            component: "PaintBrush",
+            module: "size_16::solid",
+            feature: "16-solid-paint-brush",
+            view: || view! { <PaintBrush /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "paper-airplane",
+            component: "PaperAirplane",
+            module: "size_16::solid",
+            feature: "16-solid-paper-airplane",
+            view: || view! { <PaperAirplane /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "paper-clip",
+            component: "PaperClip",
+            module: "size_16::solid",
+            feature: "16-solid-paper-clip",
+            view: || view! { <PaperClip /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "pause-circle",
+            component: "PauseCircle",
+            module: "size_16::solid",
+            feature: "16-solid-pause-circle",
+            view: || view! { <PauseCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "pause",
+            component: "Pause",
+            module: "size_16::solid",
+            feature: "16-solid-pause",
+            view: || view! { <Pause /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "pencil-square",
+            component: "PencilSquare",
+            module: "size_16::solid",
+            feature: "16-solid-pencil-square",
+            view: || view! { <PencilSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "pencil",
+            component: "Pencil",
+            module: "size_16::solid",
+            feature: "16-solid-pencil",
+            view: || view! { <Pencil /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "percent-badge",
+            component: "PercentBadge",
+            module: "size_16::solid",
+            feature: "16-solid-percent-badge",
+            view: || view! { <PercentBadge /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "phone-arrow-down-left",
+            component: "PhoneArrowDownLeft",
+            module: "size_16::solid",
+            feature: "16-solid-phone-arrow-down-left",
+            view: || view! { <PhoneArrowDownLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "phone-arrow-up-right",
+            component: "PhoneArrowUpRight",
+            module: "size_16::solid",
+            feature: "16-solid-phone-arrow-up-right",
+            view: || view! { <PhoneArrowUpRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "phone-x-mark",
+            component: "PhoneXMark",
+            module: "size_16::solid",
+            feature: "16-solid-phone-x-mark",
+            view: || view! { <PhoneXMark /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "phone",
+            component: "Phone",
+            module: "size_16::solid",
+            feature: "16-solid-phone",
+            view: || view! { <Phone /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "photo",
+            component: "Photo",
+            module: "size_16::solid",
+            feature: "16-solid-photo",
+            view: || view! { <Photo /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "play-circle",
+            component: "PlayCircle",
+            module: "size_16::solid",
+            feature: "16-solid-play-circle",
+            view: || view! { <PlayCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "play-pause",
+            component: "PlayPause",
+            module: "size_16::solid",
+            feature: "16-solid-play-pause",
+            view: || view! { <PlayPause /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "play",
+            component: "Play",
+            module: "size_16::solid",
+            feature: "16-solid-play",
+            view: || view! { <Play /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "plus-circle",
+            component: "PlusCircle",
+            module: "size_16::solid",
+            feature: "16-solid-plus-circle",
+            view: || view! { <PlusCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "plus",
+            component: "Plus",
+            module: "size_16::solid",
+            feature: "16-solid-plus",
+            view: || view! { <Plus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "power",
+            component: "Power",
+            module: "size_16::solid",
+            feature: "16-solid-power",
+            view: || view! { <Power /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "presentation-chart-bar",
+            component: "PresentationChartBar",
+            module: "size_16::solid",
+            feature: "16-solid-presentation-chart-bar",
+            view: || view! { <PresentationChartBar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "presentation-chart-line",
+            component: "PresentationChartLine",
+            module: "size_16::solid",
+            feature: "16-solid-presentation-chart-line",
+            view: || view! { <PresentationChartLine /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "printer",
+            component: "Printer",
+            module: "size_16::solid",
+            feature: "16-solid-printer",
+            view: || view! { <Printer /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "puzzle-piece",
+            component: "PuzzlePiece",
+            module: "size_16::solid",
+            feature: "16-solid-puzzle-piece",
+            view: || view! { <PuzzlePiece /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "qr-code",
+            component: "QrCode",
+            module: "size_16::solid",
+            feature: "16-solid-qr-code",
+            view: || view! { <QrCode /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "question-mark-circle",
+            component: "QuestionMarkCircle",
+            module: "size_16::solid",
+            feature: "16-solid-question-mark-circle",
+            view: || view! { <QuestionMarkCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "queue-list",
+            component: "QueueList",
+            module: "size_16::solid",
+            feature: "16-solid-queue-list",
+            view: || view! { <QueueList /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "radio",
+            component: "Radio",
+            module: "size_16::solid",
+            feature: "16-solid-radio",
+            view: || view! { <Radio /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "receipt-percent",
+            component: "ReceiptPercent",
+            module: "size_16::solid",
+            feature: "16-solid-receipt-percent",
+            view: || view! { <ReceiptPercent /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "receipt-refund",
+            component: "ReceiptRefund",
+            module: "size_16::solid",
+            feature: "16-solid-receipt-refund",
+            view: || view! { <ReceiptRefund /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "rectangle-group",
+            component: "RectangleGroup",
+            module: "size_16::solid",
+            feature: "16-solid-rectangle-group",
+            view: || view! { <RectangleGroup /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "rectangle-stack",
+            component: "RectangleStack",
+            module: "size_16::solid",
+            feature: "16-solid-rectangle-stack",
+            view: || view! { <RectangleStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "rocket-launch",
+            component: "RocketLaunch",
+            module: "size_16::solid",
+            feature: "16-solid-rocket-launch",
+            view: || view! { <RocketLaunch /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "rss",
+            component: "Rss",
+            module: "size_16::solid",
+            feature: "16-solid-rss",
+            view: || view! { <Rss /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "scale",
+            component: "Scale",
+            module: "size_16::solid",
+            feature: "16-solid-scale",
+            view: || view! { <Scale /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "scissors",
+            component: "Scissors",
+            module: "size_16::solid",
+            feature: "16-solid-scissors",
+            view: || view! { <Scissors /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "server-stack",
+            component: "ServerStack",
+            module: "size_16::solid",
+            feature: "16-solid-server-stack",
+            view: || view! { <ServerStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "server",
+            component: "Server",
+            module: "size_16::solid",
+            feature: "16-solid-server",
+            view: || view! { <Server /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "share",
+            component: "Share",
+            module: "size_16::solid",
+            feature: "16-solid-share",
+            view: || view! { <Share /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "shield-check",
+            component: "ShieldCheck",
+            module: "size_16::solid",
+            feature: "16-solid-shield-check",
+            view: || view! { <ShieldCheck /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "shield-exclamation",
+            component: "ShieldExclamation",
+            module: "size_16::solid",
+            feature: "16-solid-shield-exclamation",
+            view: || view! { <ShieldExclamation /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "shopping-bag",
+            component: "ShoppingBag",
+            module: "size_16::solid",
+            feature: "16-solid-shopping-bag",
+            view: || view! { <ShoppingBag /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "shopping-cart",
+            component: "ShoppingCart",
+            module: "size_16::solid",
+            feature: "16-solid-shopping-cart",
+            view: || view! { <ShoppingCart /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "signal-slash",
+            component: "SignalSlash",
+            module: "size_16::solid",
+            feature: "16-solid-signal-slash",
+            view: || view! { <SignalSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "signal",
+            component: "Signal",
+            module: "size_16::solid",
+            feature: "16-solid-signal",
+            view: || view! { <Signal /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "slash",
+            component: "Slash",
+            module: "size_16::solid",
+            feature: "16-solid-slash",
+            view: || view! { <Slash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "sparkles",
+            component: "Sparkles",
+            module: "size_16::solid",
+            feature: "16-solid-sparkles",
+            view: || view! { <Sparkles /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "speaker-wave",
+            component: "SpeakerWave",
+            module: "size_16::solid",
+            feature: "16-solid-speaker-wave",
+            view: || view! { <SpeakerWave /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "speaker-x-mark",
+            component: "SpeakerXMark",
+            module: "size_16::solid",
+            feature: "16-solid-speaker-x-mark",
+            view: || view! { <SpeakerXMark /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "square-2-stack",
+            component: "Square2Stack",
+            module: "size_16::solid",
+            feature: "16-solid-square-2-stack",
+            view: || view! { <Square2Stack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "square-3-stack-3d",
+            component: "Square3Stack3D",
+            module: "size_16::solid",
+            feature: "16-solid-square-3-stack-3d",
+            view: || view! { <Square3Stack3D /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "squares-2x2",
+            component: "Squares2X2",
+            module: "size_16::solid",
+            feature: "16-solid-squares-2x2",
+            view: || view! { <Squares2X2 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "squares-plus",
+            component: "SquaresPlus",
+            module: "size_16::solid",
+            feature: "16-solid-squares-plus",
+            view: || view! { <SquaresPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "star",
+            component: "Star",
+            module: "size_16::solid",
+            feature: "16-solid-star",
+            view: || view! { <Star /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "stop-circle",
+            component: "StopCircle",
+            module: "size_16::solid",
+            feature: "16-solid-stop-circle",
+            view: || view! { <StopCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "stop",
+            component: "Stop",
+            module: "size_16::solid",
+            feature: "16-solid-stop",
+            view: || view! { <Stop /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "strikethrough",
+            component: "Strikethrough",
+            module: "size_16::solid",
+            feature: "16-solid-strikethrough",
+            view: || view! { <Strikethrough /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "sun",
+            component: "Sun",
+            module: "size_16::solid",
+            feature: "16-solid-sun",
+            view: || view! { <Sun /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "swatch",
+            component: "Swatch",
+            module: "size_16::solid",
+            feature: "16-solid-swatch",
+            view: || view! { <Swatch /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "table-cells",
+            component: "TableCells",
+            module: "size_16::solid",
+            feature: "16-solid-table-cells",
+            view: || view! { <TableCells /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "tag",
+            component: "Tag",
+            module: "size_16::solid",
+            feature: "16-solid-tag",
+            view: || view! { <Tag /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "ticket",
+            component: "Ticket",
+            module: "size_16::solid",
+            feature: "16-solid-ticket",
+            view: || view! { <Ticket /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "trash",
+            component: "Trash",
+            module: "size_16::solid",
+            feature: "16-solid-trash",
+            view: || view! { <Trash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "trophy",
+            component: "Trophy",
+            module: "size_16::solid",
+            feature: "16-solid-trophy",
+            view: || view! { <Trophy /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "truck",
+            component: "Truck",
+            module: "size_16::solid",
+            feature: "16-solid-truck",
+            view: || view! { <Truck /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "tv",
+            component: "Tv",
+            module: "size_16::solid",
+            feature: "16-solid-tv",
+            view: || view! { <Tv /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "underline",
+            component: "Underline",
+            module: "size_16::solid",
+            feature: "16-solid-underline",
+            view: || view! { <Underline /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user-circle",
+            component: "UserCircle",
+            module: "size_16::solid",
+            feature: "16-solid-user-circle",
+            view: || view! { <UserCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user-group",
+            component: "UserGroup",
+            module: "size_16::solid",
+            feature: "16-solid-user-group",
+            view: || view! { <UserGroup /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user-minus",
+            component: "UserMinus",
+            module: "size_16::solid",
+            feature: "16-solid-user-minus",
+            view: || view! { <UserMinus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user-plus",
+            component: "UserPlus",
+            module: "size_16::solid",
+            feature: "16-solid-user-plus",
+            view: || view! { <UserPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user",
+            component: "User",
+            module: "size_16::solid",
+            feature: "16-solid-user",
+            view: || view! { <User /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "users",
+            component: "Users",
+            module: "size_16::solid",
+            feature: "16-solid-users",
+            view: || view! { <Users /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "variable",
+            component: "Variable",
+            module: "size_16::solid",
+            feature: "16-solid-variable",
+            view: || view! { <Variable /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "video-camera-slash",
+            component: "VideoCameraSlash",
+            module: "size_16::solid",
+            feature: "16-solid-video-camera-slash",
+            view: || view! { <VideoCameraSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "video-camera",
+            component: "VideoCamera",
+            module: "size_16::solid",
+            feature: "16-solid-video-camera",
+            view: || view! { <VideoCamera /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "view-columns",
+            component: "ViewColumns",
+            module: "size_16::solid",
+            feature: "16-solid-view-columns",
+            view: || view! { <ViewColumns /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "viewfinder-circle",
+            component: "ViewfinderCircle",
+            module: "size_16::solid",
+            feature: "16-solid-viewfinder-circle",
+            view: || view! { <ViewfinderCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "wallet",
+            component: "Wallet",
+            module: "size_16::solid",
+            feature: "16-solid-wallet",
+            view: || view! { <Wallet /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "wifi",
+            component: "Wifi",
+            module: "size_16::solid",
+            feature: "16-solid-wifi",
+            view: || view! { <Wifi /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "window",
+            component: "Window",
+            module: "size_16::solid",
+            feature: "16-solid-window",
+            view: || view! { <Window /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "wrench-screwdriver",
+            component: "WrenchScrewdriver",
+            module: "size_16::solid",
+            feature: "16-solid-wrench-screwdriver",
+            view: || view! { <WrenchScrewdriver /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "wrench",
+            component: "Wrench",
+            module: "size_16::solid",
+            feature: "16-solid-wrench",
+            view: || view! { <Wrench /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "x-circle",
+            component: "XCircle",
+            module: "size_16::solid",
+            feature: "16-solid-x-circle",
+            view: || view! { <XCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "x-mark",
+            component: "XMark",
+            module: "size_16::solid",
+            feature: "16-solid-x-mark",
+            view: || view! { <XMark /> }.into_any(),
+        },
     ]
 }
 
-pub fn size_20_solid() -> Vec<impl IntoView> {
+pub fn size_20_solid() -> Vec<GalleryIcon> {
     use leptos_heroicons::size_20::solid::*;
 
     vec![
-        view! { <BookOpen /> }.into_any(),
-        view! { <EyeSlash /> }.into_any(),
-        view! { <RectangleGroup /> }.into_any(),
-        view! { <HomeModern /> }.into_any(),
-        view! { <FaceFrown /> }.into_any(),
-        view! { <ArrowRightStartOnRectangle /> }.into_any(),
-        view! { <Document /> }.into_any(),
-        view! { <SpeakerWave /> }.into_any(),
-        view! { <DocumentCurrencyEuro /> }.into_any(),
-        view! { <Bold /> }.into_any(),
-        view! { <SquaresPlus /> }.into_any(),
-        view! { <ChevronRight /> }.into_any(),
-        view! { <BuildingStorefront /> }.into_any(),
-        view! { <PlayPause /> }.into_any(),
-        view! { <ExclamationTriangle /> }.into_any(),
-        view! { <Scissors /> }.into_any(),
-        view! { <ArrowDown /> }.into_any(),
-        view! { <ReceiptRefund /> }.into_any(),
-        view! { <AcademicCap /> }.into_any(),
-        view! { <ArrowUturnDown /> }.into_any(),
-        view! { <MinusSmall /> }.into_any(),
-        view! { <Megaphone /> }.into_any(),
-        view! { <FolderMinus /> }.into_any(),
-        view! { <InboxStack /> }.into_any(),
-        view! { <Squares2X2 /> }.into_any(),
-        view! { <Ticket /> }.into_any(),
-        view! { <Heart /> }.into_any(),
-        view! { <ClipboardDocument /> }.into_any(),
-        view! { <ChevronDoubleDown /> }.into_any(),
-        view! { <ChartBar /> }.into_any(),
-        view! { <DocumentPlus /> }.into_any(),
-        view! { <Square3Stack3D /> }.into_any(),
-        view! { <Gift /> }.into_any(),
-        view! { <DocumentCurrencyRupee /> }.into_any(),
-        view! { <GlobeAsiaAustralia /> }.into_any(),
-        view! { <Newspaper /> }.into_any(),
-        view! { <ArrowRight /> }.into_any(),
-        view! { <ArrowsPointingIn /> }.into_any(),
-        view! { <AdjustmentsHorizontal /> }.into_any(),
-        view! { <ChevronLeft /> }.into_any(),
-        view! { <ChevronDoubleRight /> }.into_any(),
-        view! { <Bars3BottomLeft /> }.into_any(),
-        view! { <ArrowLeft /> }.into_any(),
-        view! { <Camera /> }.into_any(),
-        view! { <ArrowsRightLeft /> }.into_any(),
-        view! { <Square2Stack /> }.into_any(),
-        view! { <Wallet /> }.into_any(),
-        view! { <ArchiveBoxArrowDown /> }.into_any(),
-        view! { <Cube /> }.into_any(),
-        view! { <ChatBubbleLeft /> }.into_any(),
-        view! { <ArrowUturnUp /> }.into_any(),
-        view! { <CheckCircle /> }.into_any(),
-        view! { <EllipsisVertical /> }.into_any(),
-        view! { <DocumentCurrencyDollar /> }.into_any(),
-        view! { <AtSymbol /> }.into_any(),
-        view! { <VideoCameraSlash /> }.into_any(),
-        view! { <ArrowDownOnSquare /> }.into_any(),
-        view! { <Users /> }.into_any(),
-        view! { <ChevronDown /> }.into_any(),
-        view! { <WrenchScrewdriver /> }.into_any(),
-        view! { <PlusCircle /> }.into_any(),
-        view! { <User /> }.into_any(),
-        view! { <Folder /> }.into_any(),
-        view! { <PaintBrush /> }.into_any(),
-        view! { <Signal /> }.into_any(),
-        view! { <Hashtag /> }.into_any(),
-        view! { <ArrowLeftOnRectangle /> }.into_any(),
-        view! { <Lifebuoy /> }.into_any(),
-        view! { <ChevronDoubleLeft /> }.into_any(),
-        view! { <ArrowLeftEndOnRectangle /> }.into_any(),
-        view! { <ArrowTopRightOnSquare /> }.into_any(),
-        view! { <ChatBubbleOvalLeftEllipsis /> }.into_any(),
-        view! { <H1 /> }.into_any(),
-        view! { <Banknotes /> }.into_any(),
-        view! { <ArrowUp /> }.into_any(),
-        view! { <Backward /> }.into_any(),
-        view! { <ArrowUturnLeft /> }.into_any(),
-        view! { <UserGroup /> }.into_any(),
-        view! { <ArrowDownRight /> }.into_any(),
-        view! { <ServerStack /> }.into_any(),
-        view! { <ArrowSmallUp /> }.into_any(),
-        view! { <DeviceTablet /> }.into_any(),
-        view! { <CpuChip /> }.into_any(),
-        view! { <ChartPie /> }.into_any(),
-        view! { <Beaker /> }.into_any(),
-        view! { <ArrowUpOnSquare /> }.into_any(),
-        view! { <PhoneArrowUpRight /> }.into_any(),
-        view! { <BookmarkSquare /> }.into_any(),
-        view! { <ChatBubbleLeftEllipsis /> }.into_any(),
-        view! { <ArrowTrendingUp /> }.into_any(),
-        view! { <Map /> }.into_any(),
-        view! { <CurrencyRupee /> }.into_any(),
-        view! { <Wrench /> }.into_any(),
-        view! { <Share /> }.into_any(),
-        view! { <GiftTop /> }.into_any(),
-        view! { <Power /> }.into_any(),
-        view! { <FaceSmile /> }.into_any(),
-        view! { <PlayCircle /> }.into_any(),
-        view! { <Tv /> }.into_any(),
-        view! { <CodeBracketSquare /> }.into_any(),
-        view! { <Equals /> }.into_any(),
-        view! { <Gif /> }.into_any(),
-        view! { <Cog /> }.into_any(),
-        view! { <NumberedList /> }.into_any(),
-        view! { <Calendar /> }.into_any(),
-        view! { <Plus /> }.into_any(),
-        view! { <ArrowTurnLeftUp /> }.into_any(),
-        view! { <PhoneXMark /> }.into_any(),
-        view! { <MagnifyingGlass /> }.into_any(),
-        view! { <LinkSlash /> }.into_any(),
-        view! { <VideoCamera /> }.into_any(),
-        view! { <ArrowPath /> }.into_any(),
-        view! { <ArrowLeftStartOnRectangle /> }.into_any(),
-        view! { <BarsArrowDown /> }.into_any(),
-        view! { <EllipsisHorizontal /> }.into_any(),
-        view! { <ComputerDesktop /> }.into_any(),
-        view! { <EnvelopeOpen /> }.into_any(),
-        view! { <Moon /> }.into_any(),
-        view! { <CalendarDateRange /> }.into_any(),
-        view! { <RocketLaunch /> }.into_any(),
-        view! { <ChevronDoubleUp /> }.into_any(),
-        view! { <PresentationChartLine /> }.into_any(),
-        view! { <GlobeAmericas /> }.into_any(),
-        view! { <QrCode /> }.into_any(),
-        view! { <DocumentArrowUp /> }.into_any(),
-        view! { <PauseCircle /> }.into_any(),
-        view! { <MinusCircle /> }.into_any(),
-        view! { <XCircle /> }.into_any(),
-        view! { <ShoppingBag /> }.into_any(),
-        view! { <Envelope /> }.into_any(),
-        view! { <FolderPlus /> }.into_any(),
-        view! { <PercentBadge /> }.into_any(),
-        view! { <Microphone /> }.into_any(),
-        view! { <Sparkles /> }.into_any(),
-        view! { <Fire /> }.into_any(),
-        view! { <Bars3BottomRight /> }.into_any(),
-        view! { <FolderOpen /> }.into_any(),
-        view! { <ChevronUp /> }.into_any(),
-        view! { <Minus /> }.into_any(),
-        view! { <CloudArrowUp /> }.into_any(),
-        view! { <AdjustmentsVertical /> }.into_any(),
-        view! { <CircleStack /> }.into_any(),
-        view! { <ArrowUpOnSquareStack /> }.into_any(),
-        view! { <Trash /> }.into_any(),
-        view! { <Clock /> }.into_any(),
-        view! { <HandThumbDown /> }.into_any(),
-        view! { <Sun /> }.into_any(),
-        view! { <HandThumbUp /> }.into_any(),
-        view! { <Cake /> }.into_any(),
-        view! { <DocumentMinus /> }.into_any(),
-        view! { <MapPin /> }.into_any(),
-        view! { <Home /> }.into_any(),
-        view! { <H3 /> }.into_any(),
-        view! { <ShieldCheck /> }.into_any(),
-        view! { <ChatBubbleBottomCenterText /> }.into_any(),
-        view! { <Briefcase /> }.into_any(),
-        view! { <UserCircle /> }.into_any(),
-        view! { <Scale /> }.into_any(),
-        view! { <ArrowSmallLeft /> }.into_any(),
-        view! { <UserMinus /> }.into_any(),
-        view! { <Pencil /> }.into_any(),
-        view! { <DocumentCurrencyYen /> }.into_any(),
-        view! { <Strikethrough /> }.into_any(),
-        view! { <BookmarkSlash /> }.into_any(),
-        view! { <BellSnooze /> }.into_any(),
-        view! { <PlusSmall /> }.into_any(),
-        view! { <DocumentCurrencyBangladeshi /> }.into_any(),
-        view! { <BuildingLibrary /> }.into_any(),
-        view! { <Film /> }.into_any(),
-        view! { <Slash /> }.into_any(),
-        view! { <CommandLine /> }.into_any(),
-        view! { <Window /> }.into_any(),
-        view! { <Bell /> }.into_any(),
-        view! { <ArrowTurnRightDown /> }.into_any(),
-        view! { <ShieldExclamation /> }.into_any(),
-        view! { <ArrowLongDown /> }.into_any(),
-        view! { <SignalSlash /> }.into_any(),
-        view! { <BugAnt /> }.into_any(),
-        view! { <HandRaised /> }.into_any(),
-        view! { <QueueList /> }.into_any(),
-        view! { <DocumentArrowDown /> }.into_any(),
-        view! { <ClipboardDocumentCheck /> }.into_any(),
-        view! { <ShoppingCart /> }.into_any(),
-        view! { <Photo /> }.into_any(),
-        view! { <Flag /> }.into_any(),
-        view! { <ChatBubbleOvalLeft /> }.into_any(),
-        view! { <ArrowPathRoundedSquare /> }.into_any(),
-        view! { <CloudArrowDown /> }.into_any(),
-        view! { <Key /> }.into_any(),
-        view! { <Battery0 /> }.into_any(),
-        view! { <PhoneArrowDownLeft /> }.into_any(),
-        view! { <ListBullet /> }.into_any(),
-        view! { <LockClosed /> }.into_any(),
-        view! { <ArrowDownCircle /> }.into_any(),
-        view! { <MagnifyingGlassCircle /> }.into_any(),
-        view! { <BellSlash /> }.into_any(),
-        view! { <DocumentChartBar /> }.into_any(),
-        view! { <SpeakerXMark /> }.into_any(),
-        view! { <ArrowLongUp /> }.into_any(),
-        view! { <ArrowLeftCircle /> }.into_any(),
-        view! { <PuzzlePiece /> }.into_any(),
-        view! { <Server /> }.into_any(),
-        view! { <Star /> }.into_any(),
-        view! { <Bars4 /> }.into_any(),
-        view! { <CursorArrowRays /> }.into_any(),
-        view! { <Bars3 /> }.into_any(),
-        view! { <Language /> }.into_any(),
-        view! { <ArrowTurnDownRight /> }.into_any(),
-        view! { <Eye /> }.into_any(),
-        view! { <ArrowDownTray /> }.into_any(),
-        view! { <Backspace /> }.into_any(),
-        view! { <BarsArrowUp /> }.into_any(),
-        view! { <Cloud /> }.into_any(),
-        view! { <CursorArrowRipple /> }.into_any(),
-        view! { <CurrencyYen /> }.into_any(),
-        view! { <MagnifyingGlassMinus /> }.into_any(),
-        view! { <CodeBracket /> }.into_any(),
-        view! { <Divide /> }.into_any(),
-        view! { <DocumentText /> }.into_any(),
-        view! { <BellAlert /> }.into_any(),
-        view! { <InboxArrowDown /> }.into_any(),
-        view! { <Bolt /> }.into_any(),
-        view! { <Calculator /> }.into_any(),
-        view! { <ArrowSmallDown /> }.into_any(),
-        view! { <Link /> }.into_any(),
-        view! { <Trophy /> }.into_any(),
-        view! { <ArrowUpLeft /> }.into_any(),
-        view! { <Cog8Tooth /> }.into_any(),
-        view! { <Funnel /> }.into_any(),
-        view! { <MusicalNote /> }.into_any(),
-        view! { <ArrowRightOnRectangle /> }.into_any(),
-        view! { <ArrowTurnUpLeft /> }.into_any(),
-        view! { <ClipboardDocumentList /> }.into_any(),
-        view! { <DevicePhoneMobile /> }.into_any(),
-        view! { <PaperClip /> }.into_any(),
-        view! { <ArrowUpTray /> }.into_any(),
-        view! { <RectangleStack /> }.into_any(),
-        view! { <LockOpen /> }.into_any(),
-        view! { <Identification /> }.into_any(),
-        view! { <QuestionMarkCircle /> }.into_any(),
-        view! { <ArrowDownLeft /> }.into_any(),
-        view! { <DocumentCurrencyPound /> }.into_any(),
-        view! { <ChatBubbleLeftRight /> }.into_any(),
-        view! { <ViewColumns /> }.into_any(),
-        view! { <ArrowLongLeft /> }.into_any(),
-        view! { <ExclamationCircle /> }.into_any(),
-        view! { <UserPlus /> }.into_any(),
-        view! { <Phone /> }.into_any(),
-        view! { <ReceiptPercent /> }.into_any(),
-        view! { <CurrencyEuro /> }.into_any(),
-        view! { <ArrowsPointingOut /> }.into_any(),
-        view! { <BoltSlash /> }.into_any(),
-        view! { <FolderArrowDown /> }.into_any(),
-        view! { <ArrowTurnDownLeft /> }.into_any(),
-        view! { <Rss /> }.into_any(),
-        view! { <Inbox /> }.into_any(),
-        view! { <Battery50 /> }.into_any(),
-        view! { <CurrencyDollar /> }.into_any(),
-        view! { <TableCells /> }.into_any(),
-        view! { <Pause /> }.into_any(),
-        view! { <Variable /> }.into_any(),
-        view! { <BuildingOffice /> }.into_any(),
-        view! { <ArrowUpCircle /> }.into_any(),
-        view! { <Italic /> }.into_any(),
-        view! { <CurrencyBangladeshi /> }.into_any(),
-        view! { <ArrowTurnUpRight /> }.into_any(),
-        view! { <ChatBubbleBottomCenter /> }.into_any(),
-        view! { <ArrowTrendingDown /> }.into_any(),
-        view! { <NoSymbol /> }.into_any(),
-        view! { <Cog6Tooth /> }.into_any(),
-        view! { <BuildingOffice2 /> }.into_any(),
-        view! { <ChevronUpDown /> }.into_any(),
-        view! { <GlobeEuropeAfrica /> }.into_any(),
-        view! { <LightBulb /> }.into_any(),
-        view! { <ArrowsUpDown /> }.into_any(),
-        view! { <PresentationChartBar /> }.into_any(),
-        view! { <ArrowUturnRight /> }.into_any(),
-        view! { <Tag /> }.into_any(),
-        view! { <EllipsisHorizontalCircle /> }.into_any(),
-        view! { <ArchiveBox /> }.into_any(),
-        view! { <ArrowRightCircle /> }.into_any(),
-        view! { <ArrowLongRight /> }.into_any(),
-        view! { <ArrowDownOnSquareStack /> }.into_any(),
-        view! { <ArrowRightEndOnRectangle /> }.into_any(),
-        view! { <PencilSquare /> }.into_any(),
-        view! { <Truck /> }.into_any(),
-        view! { <Forward /> }.into_any(),
-        view! { <H2 /> }.into_any(),
-        view! { <CreditCard /> }.into_any(),
-        view! { <MagnifyingGlassPlus /> }.into_any(),
-        view! { <CubeTransparent /> }.into_any(),
-        view! { <InformationCircle /> }.into_any(),
-        view! { <Stop /> }.into_any(),
-        view! { <Swatch /> }.into_any(),
-        view! { <ArrowSmallRight /> }.into_any(),
-        view! { <EyeDropper /> }.into_any(),
-        view! { <Printer /> }.into_any(),
-        view! { <ArrowUpRight /> }.into_any(),
-        view! { <CurrencyPound /> }.into_any(),
-        view! { <ChartBarSquare /> }.into_any(),
-        view! { <Clipboard /> }.into_any(),
-        view! { <Wifi /> }.into_any(),
-        view! { <Battery100 /> }.into_any(),
-        view! { <ViewfinderCircle /> }.into_any(),
-        view! { <Play /> }.into_any(),
-        view! { <DocumentMagnifyingGlass /> }.into_any(),
-        view! { <ArchiveBoxXMark /> }.into_any(),
-        view! { <DocumentDuplicate /> }.into_any(),
-        view! { <Check /> }.into_any(),
-        view! { <DocumentCheck /> }.into_any(),
-        view! { <Radio /> }.into_any(),
-        view! { <CalendarDays /> }.into_any(),
-        view! { <Bookmark /> }.into_any(),
-        view! { <PaperAirplane /> }.into_any(),
-        view! { <StopCircle /> }.into_any(),
-        view! { <Bars2 /> }.into_any(),
-        view! { <XMark /> }.into_any(),
-        view! { <Underline /> }.into_any(),
-        view! { <CheckBadge /> }.into_any(),
-        view! { <FingerPrint /> }.into_any(),
-        view! { <Bars3CenterLeft /> }.into_any(),
-        view! { <ArrowTurnRightUp /> }.into_any(),
-        view! { <GlobeAlt /> }.into_any(),
-        view! { <ArrowTurnLeftDown /> }.into_any(),
+        GalleryIcon {
+            name: "academic-cap",
+            component: "AcademicCap",
+            module: "size_20::solid",
+            feature: "20-solid-academic-cap",
+            view: || view! { <AcademicCap /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "adjustments-horizontal",
+            component: "AdjustmentsHorizontal",
+            module: "size_20::solid",
+            feature: "20-solid-adjustments-horizontal",
+            view: || view! { <AdjustmentsHorizontal /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "adjustments-vertical",
+            component: "AdjustmentsVertical",
+            module: "size_20::solid",
+            feature: "20-solid-adjustments-vertical",
+            view: || view! { <AdjustmentsVertical /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "archive-box-arrow-down",
+            component: "ArchiveBoxArrowDown",
+            module: "size_20::solid",
+            feature: "20-solid-archive-box-arrow-down",
+            view: || view! { <ArchiveBoxArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "archive-box-x-mark",
+            component: "ArchiveBoxXMark",
+            module: "size_20::solid",
+            feature: "20-solid-archive-box-x-mark",
+            view: || view! { <ArchiveBoxXMark /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "archive-box",
+            component: "ArchiveBox",
+            module: "size_20::solid",
+            feature: "20-solid-archive-box",
+            view: || view! { <ArchiveBox /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-circle",
+            component: "ArrowDownCircle",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-down-circle",
+            view: || view! { <ArrowDownCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-left",
+            component: "ArrowDownLeft",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-down-left",
+            view: || view! { <ArrowDownLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-on-square-stack",
+            component: "ArrowDownOnSquareStack",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-down-on-square-stack",
+            view: || view! { <ArrowDownOnSquareStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-on-square",
+            component: "ArrowDownOnSquare",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-down-on-square",
+            view: || view! { <ArrowDownOnSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-right",
+            component: "ArrowDownRight",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-down-right",
+            view: || view! { <ArrowDownRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-tray",
+            component: "ArrowDownTray",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-down-tray",
+            view: || view! { <ArrowDownTray /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down",
+            component: "ArrowDown",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-down",
+            view: || view! { <ArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left-circle",
+            component: "ArrowLeftCircle",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-left-circle",
+            view: || view! { <ArrowLeftCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left-end-on-rectangle",
+            component: "ArrowLeftEndOnRectangle",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-left-end-on-rectangle",
+            view: || view! { <ArrowLeftEndOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left-on-rectangle",
+            component: "ArrowLeftOnRectangle",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-left-on-rectangle",
+            view: || view! { <ArrowLeftOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left-start-on-rectangle",
+            component: "ArrowLeftStartOnRectangle",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-left-start-on-rectangle",
+            view: || view! { <ArrowLeftStartOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left",
+            component: "ArrowLeft",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-left",
+            view: || view! { <ArrowLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-long-down",
+            component: "ArrowLongDown",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-long-down",
+            view: || view! { <ArrowLongDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-long-left",
+            component: "ArrowLongLeft",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-long-left",
+            view: || view! { <ArrowLongLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-long-right",
+            component: "ArrowLongRight",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-long-right",
+            view: || view! { <ArrowLongRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-long-up",
+            component: "ArrowLongUp",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-long-up",
+            view: || view! { <ArrowLongUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-path-rounded-square",
+            component: "ArrowPathRoundedSquare",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-path-rounded-square",
+            view: || view! { <ArrowPathRoundedSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-path",
+            component: "ArrowPath",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-path",
+            view: || view! { <ArrowPath /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right-circle",
+            component: "ArrowRightCircle",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-right-circle",
+            view: || view! { <ArrowRightCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right-end-on-rectangle",
+            component: "ArrowRightEndOnRectangle",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-right-end-on-rectangle",
+            view: || view! { <ArrowRightEndOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right-on-rectangle",
+            component: "ArrowRightOnRectangle",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-right-on-rectangle",
+            view: || view! { <ArrowRightOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right-start-on-rectangle",
+            component: "ArrowRightStartOnRectangle",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-right-start-on-rectangle",
+            view: || view! { <ArrowRightStartOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right",
+            component: "ArrowRight",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-right",
+            view: || view! { <ArrowRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-small-down",
+            component: "ArrowSmallDown",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-small-down",
+            view: || view! { <ArrowSmallDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-small-left",
+            component: "ArrowSmallLeft",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-small-left",
+            view: || view! { <ArrowSmallLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-small-right",
+            component: "ArrowSmallRight",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-small-right",
+            view: || view! { <ArrowSmallRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-small-up",
+            component: "ArrowSmallUp",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-small-up",
+            view: || view! { <ArrowSmallUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-top-right-on-square",
+            component: "ArrowTopRightOnSquare",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-top-right-on-square",
+            view: || view! { <ArrowTopRightOnSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-trending-down",
+            component: "ArrowTrendingDown",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-trending-down",
+            view: || view! { <ArrowTrendingDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-trending-up",
+            component: "ArrowTrendingUp",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-trending-up",
+            view: || view! { <ArrowTrendingUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-down-left",
+            component: "ArrowTurnDownLeft",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-turn-down-left",
+            view: || view! { <ArrowTurnDownLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-down-right",
+            component: "ArrowTurnDownRight",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-turn-down-right",
+            view: || view! { <ArrowTurnDownRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-left-down",
+            component: "ArrowTurnLeftDown",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-turn-left-down",
+            view: || view! { <ArrowTurnLeftDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-left-up",
+            component: "ArrowTurnLeftUp",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-turn-left-up",
+            view: || view! { <ArrowTurnLeftUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-right-down",
+            component: "ArrowTurnRightDown",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-turn-right-down",
+            view: || view! { <ArrowTurnRightDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-right-up",
+            component: "ArrowTurnRightUp",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-turn-right-up",
+            view: || view! { <ArrowTurnRightUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-up-left",
+            component: "ArrowTurnUpLeft",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-turn-up-left",
+            view: || view! { <ArrowTurnUpLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-up-right",
+            component: "ArrowTurnUpRight",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-turn-up-right",
+            view: || view! { <ArrowTurnUpRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-circle",
+            component: "ArrowUpCircle",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-up-circle",
+            view: || view! { <ArrowUpCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-left",
+            component: "ArrowUpLeft",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-up-left",
+            view: || view! { <ArrowUpLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-on-square-stack",
+            component: "ArrowUpOnSquareStack",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-up-on-square-stack",
+            view: || view! { <ArrowUpOnSquareStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-on-square",
+            component: "ArrowUpOnSquare",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-up-on-square",
+            view: || view! { <ArrowUpOnSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-right",
+            component: "ArrowUpRight",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-up-right",
+            view: || view! { <ArrowUpRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-tray",
+            component: "ArrowUpTray",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-up-tray",
+            view: || view! { <ArrowUpTray /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up",
+            component: "ArrowUp",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-up",
+            view: || view! { <ArrowUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-uturn-down",
+            component: "ArrowUturnDown",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-uturn-down",
+            view: || view! { <ArrowUturnDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-uturn-left",
+            component: "ArrowUturnLeft",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-uturn-left",
+            view: || view! { <ArrowUturnLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-uturn-right",
+            component: "ArrowUturnRight",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-uturn-right",
+            view: || view! { <ArrowUturnRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-uturn-up",
+            component: "ArrowUturnUp",
+            module: "size_20::solid",
+            feature: "20-solid-arrow-uturn-up",
+            view: || view! { <ArrowUturnUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrows-pointing-in",
+            component: "ArrowsPointingIn",
+            module: "size_20::solid",
+            feature: "20-solid-arrows-pointing-in",
+            view: || view! { <ArrowsPointingIn /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrows-pointing-out",
+            component: "ArrowsPointingOut",
+            module: "size_20::solid",
+            feature: "20-solid-arrows-pointing-out",
+            view: || view! { <ArrowsPointingOut /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrows-right-left",
+            component: "ArrowsRightLeft",
+            module: "size_20::solid",
+            feature: "20-solid-arrows-right-left",
+            view: || view! { <ArrowsRightLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrows-up-down",
+            component: "ArrowsUpDown",
+            module: "size_20::solid",
+            feature: "20-solid-arrows-up-down",
+            view: || view! { <ArrowsUpDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "at-symbol",
+            component: "AtSymbol",
+            module: "size_20::solid",
+            feature: "20-solid-at-symbol",
+            view: || view! { <AtSymbol /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "backspace",
+            component: "Backspace",
+            module: "size_20::solid",
+            feature: "20-solid-backspace",
+            view: || view! { <Backspace /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "backward",
+            component: "Backward",
+            module: "size_20::solid",
+            feature: "20-solid-backward",
+            view: || view! { <Backward /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "banknotes",
+            component: "Banknotes",
+            module: "size_20::solid",
+            feature: "20-solid-banknotes",
+            view: || view! { <Banknotes /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-2",
+            component: "Bars2",
+            module: "size_20::solid",
+            feature: "20-solid-bars-2",
+            view: || view! { <Bars2 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-3-bottom-left",
+            component: "Bars3BottomLeft",
+            module: "size_20::solid",
+            feature: "20-solid-bars-3-bottom-left",
+            view: || view! { <Bars3BottomLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-3-bottom-right",
+            component: "Bars3BottomRight",
+            module: "size_20::solid",
+            feature: "20-solid-bars-3-bottom-right",
+            view: || view! { <Bars3BottomRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-3-center-left",
+            component: "Bars3CenterLeft",
+            module: "size_20::solid",
+            feature: "20-solid-bars-3-center-left",
+            view: || view! { <Bars3CenterLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-3",
+            component: "Bars3",
+            module: "size_20::solid",
+            feature: "20-solid-bars-3",
+            view: || view! { <Bars3 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-4",
+            component: "Bars4",
+            module: "size_20::solid",
+            feature: "20-solid-bars-4",
+            view: || view! { <Bars4 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-arrow-down",
+            component: "BarsArrowDown",
+            module: "size_20::solid",
+            feature: "20-solid-bars-arrow-down",
+            view: || view! { <BarsArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-arrow-up",
+            component: "BarsArrowUp",
+            module: "size_20::solid",
+            feature: "20-solid-bars-arrow-up",
+            view: || view! { <BarsArrowUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "battery-0",
+            component: "Battery0",
+            module: "size_20::solid",
+            feature: "20-solid-battery-0",
+            view: || view! { <Battery0 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "battery-100",
+            component: "Battery100",
+            module: "size_20::solid",
+            feature: "20-solid-battery-100",
+            view: || view! { <Battery100 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "battery-50",
+            component: "Battery50",
+            module: "size_20::solid",
+            feature: "20-solid-battery-50",
+            view: || view! { <Battery50 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "beaker",
+            component: "Beaker",
+            module: "size_20::solid",
+            feature: "20-solid-beaker",
+            view: || view! { <Beaker /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bell-alert",
+            component: "BellAlert",
+            module: "size_20::solid",
+            feature: "20-solid-bell-alert",
+            view: || view! { <BellAlert /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bell-slash",
+            component: "BellSlash",
+            module: "size_20::solid",
+            feature: "20-solid-bell-slash",
+            view: || view! { <BellSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bell-snooze",
+            component: "BellSnooze",
+            module: "size_20::solid",
+            feature: "20-solid-bell-snooze",
+            view: || view! { <BellSnooze /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bell",
+            component: "Bell",
+            module: "size_20::solid",
+            feature: "20-solid-bell",
+            view: || view! { <Bell /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bold",
+            component: "Bold",
+            module: "size_20::solid",
+            feature: "20-solid-bold",
+            view: || view! { <Bold /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bolt-slash",
+            component: "BoltSlash",
+            module: "size_20::solid",
+            feature: "20-solid-bolt-slash",
+            view: || view! { <BoltSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bolt",
+            component: "Bolt",
+            module: "size_20::solid",
+            feature: "20-solid-bolt",
+            view: || view! { <Bolt /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "book-open",
+            component: "BookOpen",
+            module: "size_20::solid",
+            feature: "20-solid-book-open",
+            view: || view! { <BookOpen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bookmark-slash",
+            component: "BookmarkSlash",
+            module: "size_20::solid",
+            feature: "20-solid-bookmark-slash",
+            view: || view! { <BookmarkSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bookmark-square",
+            component: "BookmarkSquare",
+            module: "size_20::solid",
+            feature: "20-solid-bookmark-square",
+            view: || view! { <BookmarkSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bookmark",
+            component: "Bookmark",
+            module: "size_20::solid",
+            feature: "20-solid-bookmark",
+            view: || view! { <Bookmark /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "briefcase",
+            component: "Briefcase",
+            module: "size_20::solid",
+            feature: "20-solid-briefcase",
+            view: || view! { <Briefcase /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bug-ant",
+            component: "BugAnt",
+            module: "size_20::solid",
+            feature: "20-solid-bug-ant",
+            view: || view! { <BugAnt /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "building-library",
+            component: "BuildingLibrary",
+            module: "size_20::solid",
+            feature: "20-solid-building-library",
+            view: || view! { <BuildingLibrary /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "building-office-2",
+            component: "BuildingOffice2",
+            module: "size_20::solid",
+            feature: "20-solid-building-office-2",
+            view: || view! { <BuildingOffice2 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "building-office",
+            component: "BuildingOffice",
+            module: "size_20::solid",
+            feature: "20-solid-building-office",
+            view: || view! { <BuildingOffice /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "building-storefront",
+            component: "BuildingStorefront",
+            module: "size_20::solid",
+            feature: "20-solid-building-storefront",
+            view: || view! { <BuildingStorefront /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cake",
+            component: "Cake",
+            module: "size_20::solid",
+            feature: "20-solid-cake",
+            view: || view! { <Cake /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "calculator",
+            component: "Calculator",
+            module: "size_20::solid",
+            feature: "20-solid-calculator",
+            view: || view! { <Calculator /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "calendar-date-range",
+            component: "CalendarDateRange",
+            module: "size_20::solid",
+            feature: "20-solid-calendar-date-range",
+            view: || view! { <CalendarDateRange /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "calendar-days",
+            component: "CalendarDays",
+            module: "size_20::solid",
+            feature: "20-solid-calendar-days",
+            view: || view! { <CalendarDays /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "calendar",
+            component: "Calendar",
+            module: "size_20::solid",
+            feature: "20-solid-calendar",
+            view: || view! { <Calendar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "camera",
+            component: "Camera",
+            module: "size_20::solid",
+            feature: "20-solid-camera",
+            view: || view! { <Camera /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chart-bar-square",
+            component: "ChartBarSquare",
+            module: "size_20::solid",
+            feature: "20-solid-chart-bar-square",
+            view: || view! { <ChartBarSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chart-bar",
+            component: "ChartBar",
+            module: "size_20::solid",
+            feature: "20-solid-chart-bar",
+            view: || view! { <ChartBar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chart-pie",
+            component: "ChartPie",
+            module: "size_20::solid",
+            feature: "20-solid-chart-pie",
+            view: || view! { <ChartPie /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-bottom-center-text",
+            component: "ChatBubbleBottomCenterText",
+            module: "size_20::solid",
+            feature: "20-solid-chat-bubble-bottom-center-text",
+            view: || view! { <ChatBubbleBottomCenterText /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-bottom-center",
+            component: "ChatBubbleBottomCenter",
+            module: "size_20::solid",
+            feature: "20-solid-chat-bubble-bottom-center",
+            view: || view! { <ChatBubbleBottomCenter /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-left-ellipsis",
+            component: "ChatBubbleLeftEllipsis",
+            module: "size_20::solid",
+            feature: "20-solid-chat-bubble-left-ellipsis",
+            view: || view! { <ChatBubbleLeftEllipsis /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-left-right",
+            component: "ChatBubbleLeftRight",
+            module: "size_20::solid",
+            feature: "20-solid-chat-bubble-left-right",
+            view: || view! { <ChatBubbleLeftRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-left",
+            component: "ChatBubbleLeft",
+            module: "size_20::solid",
+            feature: "20-solid-chat-bubble-left",
+            view: || view! { <ChatBubbleLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-oval-left-ellipsis",
+            component: "ChatBubbleOvalLeftEllipsis",
+            module: "size_20::solid",
+            feature: "20-solid-chat-bubble-oval-left-ellipsis",
+            view: || view! { <ChatBubbleOvalLeftEllipsis /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-oval-left",
+            component: "ChatBubbleOvalLeft",
+            module: "size_20::solid",
+            feature: "20-solid-chat-bubble-oval-left",
+            view: || view! { <ChatBubbleOvalLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "check-badge",
+            component: "CheckBadge",
+            module: "size_20::solid",
+            feature: "20-solid-check-badge",
+            view: || view! { <CheckBadge /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "check-circle",
+            component: "CheckCircle",
+            module: "size_20::solid",
+            feature: "20-solid-check-circle",
+            view: || view! { <CheckCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "check",
+            component: "Check",
+            module: "size_20::solid",
+            feature: "20-solid-check",
+            view: || view! { <Check /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-double-down",
+            component: "ChevronDoubleDown",
+            module: "size_20::solid",
+            feature: "20-solid-chevron-double-down",
+            view: || view! { <ChevronDoubleDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-double-left",
+            component: "ChevronDoubleLeft",
+            module: "size_20::solid",
+            feature: "20-solid-chevron-double-left",
+            view: || view! { <ChevronDoubleLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-double-right",
+            component: "ChevronDoubleRight",
+            module: "size_20::solid",
+            feature: "20-solid-chevron-double-right",
+            view: || view! { <ChevronDoubleRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-double-up",
+            component: "ChevronDoubleUp",
+            module: "size_20::solid",
+            feature: "20-solid-chevron-double-up",
+            view: || view! { <ChevronDoubleUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-down",
+            component: "ChevronDown",
+            module: "size_20::solid",
+            feature: "20-solid-chevron-down",
+            view: || view! { <ChevronDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-left",
+            component: "ChevronLeft",
+            module: "size_20::solid",
+            feature: "20-solid-chevron-left",
+            view: || view! { <ChevronLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-right",
+            component: "ChevronRight",
+            module: "size_20::solid",
+            feature: "20-solid-chevron-right",
+            view: || view! { <ChevronRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-up-down",
+            component: "ChevronUpDown",
+            module: "size_20::solid",
+            feature: "20-solid-chevron-up-down",
+            view: || view! { <ChevronUpDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-up",
+            component: "ChevronUp",
+            module: "size_20::solid",
+            feature: "20-solid-chevron-up",
+            view: || view! { <ChevronUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "circle-stack",
+            component: "CircleStack",
+            module: "size_20::solid",
+            feature: "20-solid-circle-stack",
+            view: || view! { <CircleStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clipboard-document-check",
+            component: "ClipboardDocumentCheck",
+            module: "size_20::solid",
+            feature: "20-solid-clipboard-document-check",
+            view: || view! { <ClipboardDocumentCheck /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clipboard-document-list",
+            component: "ClipboardDocumentList",
+            module: "size_20::solid",
+            feature: "20-solid-clipboard-document-list",
+            view: || view! { <ClipboardDocumentList /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clipboard-document",
+            component: "ClipboardDocument",
+            module: "size_20::solid",
+            feature: "20-solid-clipboard-document",
+            view: || view! { <ClipboardDocument /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clipboard",
+            component: "Clipboard",
+            module: "size_20::solid",
+            feature: "20-solid-clipboard",
+            view: || view! { <Clipboard /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clock",
+            component: "Clock",
+            module: "size_20::solid",
+            feature: "20-solid-clock",
+            view: || view! { <Clock /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cloud-arrow-down",
+            component: "CloudArrowDown",
+            module: "size_20::solid",
+            feature: "20-solid-cloud-arrow-down",
+            view: || view! { <CloudArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cloud-arrow-up",
+            component: "CloudArrowUp",
+            module: "size_20::solid",
+            feature: "20-solid-cloud-arrow-up",
+            view: || view! { <CloudArrowUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cloud",
+            component: "Cloud",
+            module: "size_20::solid",
+            feature: "20-solid-cloud",
+            view: || view! { <Cloud /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "code-bracket-square",
+            component: "CodeBracketSquare",
+            module: "size_20::solid",
+            feature: "20-solid-code-bracket-square",
+            view: || view! { <CodeBracketSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "code-bracket",
+            component: "CodeBracket",
+            module: "size_20::solid",
+            feature: "20-solid-code-bracket",
+            view: || view! { <CodeBracket /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cog-6-tooth",
+            component: "Cog6Tooth",
+            module: "size_20::solid",
+            feature: "20-solid-cog-6-tooth",
+            view: || view! { <Cog6Tooth /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cog-8-tooth",
+            component: "Cog8Tooth",
+            module: "size_20::solid",
+            feature: "20-solid-cog-8-tooth",
+            view: || view! { <Cog8Tooth /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cog",
+            component: "Cog",
+            module: "size_20::solid",
+            feature: "20-solid-cog",
+            view: || view! { <Cog /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "command-line",
+            component: "CommandLine",
+            module: "size_20::solid",
+            feature: "20-solid-command-line",
+            view: || view! { <CommandLine /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "computer-desktop",
+            component: "ComputerDesktop",
+            module: "size_20::solid",
+            feature: "20-solid-computer-desktop",
+            view: || view! { <ComputerDesktop /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cpu-chip",
+            component: "CpuChip",
+            module: "size_20::solid",
+            feature: "20-solid-cpu-chip",
+            view: || view! { <CpuChip /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "credit-card",
+            component: "CreditCard",
+            module: "size_20::solid",
+            feature: "20-solid-credit-card",
+            view: || view! { <CreditCard /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cube-transparent",
+            component: "CubeTransparent",
+            module: "size_20::solid",
+            feature: "20-solid-cube-transparent",
+            view: || view! { <CubeTransparent /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cube",
+            component: "Cube",
+            module: "size_20::solid",
+            feature: "20-solid-cube",
+            view: || view! { <Cube /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-bangladeshi",
+            component: "CurrencyBangladeshi",
+            module: "size_20::solid",
+            feature: "20-solid-currency-bangladeshi",
+            view: || view! { <CurrencyBangladeshi /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-dollar",
+            component: "CurrencyDollar",
+            module: "size_20::solid",
+            feature: "20-solid-currency-dollar",
+            view: || view! { <CurrencyDollar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-euro",
+            component: "CurrencyEuro",
+            module: "size_20::solid",
+            feature: "20-solid-currency-euro",
+            view: || view! { <CurrencyEuro /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-pound",
+            component: "CurrencyPound",
+            module: "size_20::solid",
+            feature: "20-solid-currency-pound",
+            view: || view! { <CurrencyPound /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-rupee",
+            component: "CurrencyRupee",
+            module: "size_20::solid",
+            feature: "20-solid-currency-rupee",
+            view: || view! { <CurrencyRupee /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-yen",
+            component: "CurrencyYen",
+            module: "size_20::solid",
+            feature: "20-solid-currency-yen",
+            view: || view! { <CurrencyYen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cursor-arrow-rays",
+            component: "CursorArrowRays",
+            module: "size_20::solid",
+            feature: "20-solid-cursor-arrow-rays",
+            view: || view! { <CursorArrowRays /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cursor-arrow-ripple",
+            component: "CursorArrowRipple",
+            module: "size_20::solid",
+            feature: "20-solid-cursor-arrow-ripple",
+            view: || view! { <CursorArrowRipple /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "device-phone-mobile",
+            component: "DevicePhoneMobile",
+            module: "size_20::solid",
+            feature: "20-solid-device-phone-mobile",
+            view: || view! { <DevicePhoneMobile /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "device-tablet",
+            component: "DeviceTablet",
+            module: "size_20::solid",
+            feature: "20-solid-device-tablet",
+            view: || view! { <DeviceTablet /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "divide",
+            component: "Divide",
+            module: "size_20::solid",
+            feature: "20-solid-divide",
+            view: || view! { <Divide /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-arrow-down",
+            component: "DocumentArrowDown",
+            module: "size_20::solid",
+            feature: "20-solid-document-arrow-down",
+            view: || view! { <DocumentArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-arrow-up",
+            component: "DocumentArrowUp",
+            module: "size_20::solid",
+            feature: "20-solid-document-arrow-up",
+            view: || view! { <DocumentArrowUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-chart-bar",
+            component: "DocumentChartBar",
+            module: "size_20::solid",
+            feature: "20-solid-document-chart-bar",
+            view: || view! { <DocumentChartBar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-check",
+            component: "DocumentCheck",
+            module: "size_20::solid",
+            feature: "20-solid-document-check",
+            view: || view! { <DocumentCheck /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-bangladeshi",
+            component: "DocumentCurrencyBangladeshi",
+            module: "size_20::solid",
+            feature: "20-solid-document-currency-bangladeshi",
+            view: || view! { <DocumentCurrencyBangladeshi /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-dollar",
+            component: "DocumentCurrencyDollar",
+            module: "size_20::solid",
+            feature: "20-solid-document-currency-dollar",
+            view: || view! { <DocumentCurrencyDollar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-euro",
+            component: "DocumentCurrencyEuro",
+            module: "size_20::solid",
+            feature: "20-solid-document-currency-euro",
+            view: || view! { <DocumentCurrencyEuro /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-pound",
+            component: "DocumentCurrencyPound",
+            module: "size_20::solid",
+            feature: "20-solid-document-currency-pound",
+            view: || view! { <DocumentCurrencyPound /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-rupee",
+            component: "DocumentCurrencyRupee",
+            module: "size_20::solid",
+            feature: "20-solid-document-currency-rupee",
+            view: || view! { <DocumentCurrencyRupee /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-yen",
+            component: "DocumentCurrencyYen",
+            module: "size_20::solid",
+            feature: "20-solid-document-currency-yen",
+            view: || view! { <DocumentCurrencyYen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-duplicate",
+            component: "DocumentDuplicate",
+            module: "size_20::solid",
+            feature: "20-solid-document-duplicate",
+            view: || view! { <DocumentDuplicate /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-magnifying-glass",
+            component: "DocumentMagnifyingGlass",
+            module: "size_20::solid",
+            feature: "20-solid-document-magnifying-glass",
+            view: || view! { <DocumentMagnifyingGlass /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-minus",
+            component: "DocumentMinus",
+            module: "size_20::solid",
+            feature: "20-solid-document-minus",
+            view: || view! { <DocumentMinus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-plus",
+            component: "DocumentPlus",
+            module: "size_20::solid",
+            feature: "20-solid-document-plus",
+            view: || view! { <DocumentPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-text",
+            component: "DocumentText",
+            module: "size_20::solid",
+            feature: "20-solid-document-text",
+            view: || view! { <DocumentText /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document",
+            component: "Document",
+            module: "size_20::solid",
+            feature: "20-solid-document",
+            view: || view! { <Document /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "ellipsis-horizontal-circle",
+            component: "EllipsisHorizontalCircle",
+            module: "size_20::solid",
+            feature: "20-solid-ellipsis-horizontal-circle",
+            view: || view! { <EllipsisHorizontalCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "ellipsis-horizontal",
+            component: "EllipsisHorizontal",
+            module: "size_20::solid",
+            feature: "20-solid-ellipsis-horizontal",
+            view: || view! { <EllipsisHorizontal /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "ellipsis-vertical",
+            component: "EllipsisVertical",
+            module: "size_20::solid",
+            feature: "20-solid-ellipsis-vertical",
+            view: || view! { <EllipsisVertical /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "envelope-open",
+            component: "EnvelopeOpen",
+            module: "size_20::solid",
+            feature: "20-solid-envelope-open",
+            view: || view! { <EnvelopeOpen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "envelope",
+            component: "Envelope",
+            module: "size_20::solid",
+            feature: "20-solid-envelope",
+            view: || view! { <Envelope /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "equals",
+            component: "Equals",
+            module: "size_20::solid",
+            feature: "20-solid-equals",
+            view: || view! { <Equals /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "exclamation-circle",
+            component: "ExclamationCircle",
+            module: "size_20::solid",
+            feature: "20-solid-exclamation-circle",
+            view: || view! { <ExclamationCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "exclamation-triangle",
+            component: "ExclamationTriangle",
+            module: "size_20::solid",
+            feature: "20-solid-exclamation-triangle",
+            view: || view! { <ExclamationTriangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "eye-dropper",
+            component: "EyeDropper",
+            module: "size_20::solid",
+            feature: "20-solid-eye-dropper",
+            view: || view! { <EyeDropper /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "eye-slash",
+            component: "EyeSlash",
+            module: "size_20::solid",
+            feature: "20-solid-eye-slash",
+            view: || view! { <EyeSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "eye",
+            component: "Eye",
+            module: "size_20::solid",
+            feature: "20-solid-eye",
+            view: || view! { <Eye /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "face-frown",
+            component: "FaceFrown",
+            module: "size_20::solid",
+            feature: "20-solid-face-frown",
+            view: || view! { <FaceFrown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "face-smile",
+            component: "FaceSmile",
+            module: "size_20::solid",
+            feature: "20-solid-face-smile",
+            view: || view! { <FaceSmile /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "film",
+            component: "Film",
+            module: "size_20::solid",
+            feature: "20-solid-film",
+            view: || view! { <Film /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "finger-print",
+            component: "FingerPrint",
+            module: "size_20::solid",
+            feature: "20-solid-finger-print",
+            view: || view! { <FingerPrint /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "fire",
+            component: "Fire",
+            module: "size_20::solid",
+            feature: "20-solid-fire",
+            view: || view! { <Fire /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "flag",
+            component: "Flag",
+            module: "size_20::solid",
+            feature: "20-solid-flag",
+            view: || view! { <Flag /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder-arrow-down",
+            component: "FolderArrowDown",
+            module: "size_20::solid",
+            feature: "20-solid-folder-arrow-down",
+            view: || view! { <FolderArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder-minus",
+            component: "FolderMinus",
+            module: "size_20::solid",
+            feature: "20-solid-folder-minus",
+            view: || view! { <FolderMinus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder-open",
+            component: "FolderOpen",
+            module: "size_20::solid",
+            feature: "20-solid-folder-open",
+            view: || view! { <FolderOpen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder-plus",
+            component: "FolderPlus",
+            module: "size_20::solid",
+            feature: "20-solid-folder-plus",
+            view: || view! { <FolderPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder",
+            component: "Folder",
+            module: "size_20::solid",
+            feature: "20-solid-folder",
+            view: || view! { <Folder /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "forward",
+            component: "Forward",
+            module: "size_20::solid",
+            feature: "20-solid-forward",
+            view: || view! { <Forward /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "funnel",
+            component: "Funnel",
+            module: "size_20::solid",
+            feature: "20-solid-funnel",
+            view: || view! { <Funnel /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "gif",
+            component: "Gif",
+            module: "size_20::solid",
+            feature: "20-solid-gif",
+            view: || view! { <Gif /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "gift-top",
+            component: "GiftTop",
+            module: "size_20::solid",
+            feature: "20-solid-gift-top",
+            view: || view! { <GiftTop /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "gift",
+            component: "Gift",
+            module: "size_20::solid",
+            feature: "20-solid-gift",
+            view: || view! { <Gift /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "globe-alt",
+            component: "GlobeAlt",
+            module: "size_20::solid",
+            feature: "20-solid-globe-alt",
+            view: || view! { <GlobeAlt /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "globe-americas",
+            component: "GlobeAmericas",
+            module: "size_20::solid",
+            feature: "20-solid-globe-americas",
+            view: || view! { <GlobeAmericas /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "globe-asia-australia",
+            component: "GlobeAsiaAustralia",
+            module: "size_20::solid",
+            feature: "20-solid-globe-asia-australia",
+            view: || view! { <GlobeAsiaAustralia /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "globe-europe-africa",
+            component: "GlobeEuropeAfrica",
+            module: "size_20::solid",
+            feature: "20-solid-globe-europe-africa",
+            view: || view! { <GlobeEuropeAfrica /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "h1",
+            component: "H1",
+            module: "size_20::solid",
+            feature: "20-solid-h1",
+            view: || view! { <H1 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "h2",
+            component: "H2",
+            module: "size_20::solid",
+            feature: "20-solid-h2",
+            view: || view! { <H2 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "h3",
+            component: "H3",
+            module: "size_20::solid",
+            feature: "20-solid-h3",
+            view: || view! { <H3 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "hand-raised",
+            component: "HandRaised",
+            module: "size_20::solid",
+            feature: "20-solid-hand-raised",
+            view: || view! { <HandRaised /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "hand-thumb-down",
+            component: "HandThumbDown",
+            module: "size_20::solid",
+            feature: "20-solid-hand-thumb-down",
+            view: || view! { <HandThumbDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "hand-thumb-up",
+            component: "HandThumbUp",
+            module: "size_20::solid",
+            feature: "20-solid-hand-thumb-up",
+            view: || view! { <HandThumbUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "hashtag",
+            component: "Hashtag",
+            module: "size_20::solid",
+            feature: "20-solid-hashtag",
+            view: || view! { <Hashtag /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "heart",
+            component: "Heart",
+            module: "size_20::solid",
+            feature: "20-solid-heart",
+            view: || view! { <Heart /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "home-modern",
+            component: "HomeModern",
+            module: "size_20::solid",
+            feature: "20-solid-home-modern",
+            view: || view! { <HomeModern /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "home",
+            component: "Home",
+            module: "size_20::solid",
+            feature: "20-solid-home",
+            view: || view! { <Home /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "identification",
+            component: "Identification",
+            module: "size_20::solid",
+            feature: "20-solid-identification",
+            view: || view! { <Identification /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "inbox-arrow-down",
+            component: "InboxArrowDown",
+            module: "size_20::solid",
+            feature: "20-solid-inbox-arrow-down",
+            view: || view! { <InboxArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "inbox-stack",
+            component: "InboxStack",
+            module: "size_20::solid",
+            feature: "20-solid-inbox-stack",
+            view: || view! { <InboxStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "inbox",
+            component: "Inbox",
+            module: "size_20::solid",
+            feature: "20-solid-inbox",
+            view: || view! { <Inbox /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "information-circle",
+            component: "InformationCircle",
+            module: "size_20::solid",
+            feature: "20-solid-information-circle",
+            view: || view! { <InformationCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "italic",
+            component: "Italic",
+            module: "size_20::solid",
+            feature: "20-solid-italic",
+            view: || view! { <Italic /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "key",
+            component: "Key",
+            module: "size_20::solid",
+            feature: "20-solid-key",
+            view: || view! { <Key /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "language",
+            component: "Language",
+            module: "size_20::solid",
+            feature: "20-solid-language",
+            view: || view! { <Language /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "lifebuoy",
+            component: "Lifebuoy",
+            module: "size_20::solid",
+            feature: "20-solid-lifebuoy",
+            view: || view! { <Lifebuoy /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "light-bulb",
+            component: "LightBulb",
+            module: "size_20::solid",
+            feature: "20-solid-light-bulb",
+            view: || view! { <LightBulb /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "link-slash",
+            component: "LinkSlash",
+            module: "size_20::solid",
+            feature: "20-solid-link-slash",
+            view: || view! { <LinkSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "link",
+            component: "Link",
+            module: "size_20::solid",
+            feature: "20-solid-link",
+            view: || view! { <Link /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "list-bullet",
+            component: "ListBullet",
+            module: "size_20::solid",
+            feature: "20-solid-list-bullet",
+            view: || view! { <ListBullet /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "lock-closed",
+            component: "LockClosed",
+            module: "size_20::solid",
+            feature: "20-solid-lock-closed",
+            view: || view! { <LockClosed /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "lock-open",
+            component: "LockOpen",
+            module: "size_20::solid",
+            feature: "20-solid-lock-open",
+            view: || view! { <LockOpen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "magnifying-glass-circle",
+            component: "MagnifyingGlassCircle",
+            module: "size_20::solid",
+            feature: "20-solid-magnifying-glass-circle",
+            view: || view! { <MagnifyingGlassCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "magnifying-glass-minus",
+            component: "MagnifyingGlassMinus",
+            module: "size_20::solid",
+            feature: "20-solid-magnifying-glass-minus",
+            view: || view! { <MagnifyingGlassMinus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "magnifying-glass-plus",
+            component: "MagnifyingGlassPlus",
+            module: "size_20::solid",
+            feature: "20-solid-magnifying-glass-plus",
+            view: || view! { <MagnifyingGlassPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "magnifying-glass",
+            component: "MagnifyingGlass",
+            module: "size_20::solid",
+            feature: "20-solid-magnifying-glass",
+            view: || view! { <MagnifyingGlass /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "map-pin",
+            component: "MapPin",
+            module: "size_20::solid",
+            feature: "20-solid-map-pin",
+            view: || view! { <MapPin /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "map",
+            component: "Map",
+            module: "size_20::solid",
+            feature: "20-solid-map",
+            view: || view! { <Map /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "megaphone",
+            component: "Megaphone",
+            module: "size_20::solid",
+            feature: "20-solid-megaphone",
+            view: || view! { <Megaphone /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "microphone",
+            component: "Microphone",
+            module: "size_20::solid",
+            feature: "20-solid-microphone",
+            view: || view! { <Microphone /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "minus-circle",
+            component: "MinusCircle",
+            module: "size_20::solid",
+            feature: "20-solid-minus-circle",
+            view: || view! { <MinusCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "minus-small",
+            component: "MinusSmall",
+            module: "size_20::solid",
+            feature: "20-solid-minus-small",
+            view: || view! { <MinusSmall /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "minus",
+            component: "Minus",
+            module: "size_20::solid",
+            feature: "20-solid-minus",
+            view: || view! { <Minus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "moon",
+            component: "Moon",
+            module: "size_20::solid",
+            feature: "20-solid-moon",
+            view: || view! { <Moon /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "musical-note",
+            component: "MusicalNote",
+            module: "size_20::solid",
+            feature: "20-solid-musical-note",
+            view: || view! { <MusicalNote /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "newspaper",
+            component: "Newspaper",
+            module: "size_20::solid",
+            feature: "20-solid-newspaper",
+            view: || view! { <Newspaper /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "no-symbol",
+            component: "NoSymbol",
+            module: "size_20::solid",
+            feature: "20-solid-no-symbol",
+            view: || view! { <NoSymbol /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "numbered-list",
+            component: "NumberedList",
+            module: "size_20::solid",
+            feature: "20-solid-numbered-list",
+            view: || view! { <NumberedList /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "paint-brush",
+            component: "PaintBrush",
+            module: "size_20::solid",
+            feature: "20-solid-paint-brush",
+            view: || view! { <PaintBrush /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "paper-airplane",
+            component: "PaperAirplane",
+            module: "size_20::solid",
+            feature: "20-solid-paper-airplane",
+            view: || view! { <PaperAirplane /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "paper-clip",
+            component: "PaperClip",
+            module: "size_20::solid",
+            feature: "20-solid-paper-clip",
+            view: || view! { <PaperClip /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "pause-circle",
+            component: "PauseCircle",
+            module: "size_20::solid",
+            feature: "20-solid-pause-circle",
+            view: || view! { <PauseCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "pause",
+            component: "Pause",
+            module: "size_20::solid",
+            feature: "20-solid-pause",
+            view: || view! { <Pause /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "pencil-square",
+            component: "PencilSquare",
+            module: "size_20::solid",
+            feature: "20-solid-pencil-square",
+            view: || view! { <PencilSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "pencil",
+            component: "Pencil",
+            module: "size_20::solid",
+            feature: "20-solid-pencil",
+            view: || view! { <Pencil /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "percent-badge",
+            component: "PercentBadge",
+            module: "size_20::solid",
+            feature: "20-solid-percent-badge",
+            view: || view! { <PercentBadge /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "phone-arrow-down-left",
+            component: "PhoneArrowDownLeft",
+            module: "size_20::solid",
+            feature: "20-solid-phone-arrow-down-left",
+            view: || view! { <PhoneArrowDownLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "phone-arrow-up-right",
+            component: "PhoneArrowUpRight",
+            module: "size_20::solid",
+            feature: "20-solid-phone-arrow-up-right",
+            view: || view! { <PhoneArrowUpRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "phone-x-mark",
+            component: "PhoneXMark",
+            module: "size_20::solid",
+            feature: "20-solid-phone-x-mark",
+            view: || view! { <PhoneXMark /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "phone",
+            component: "Phone",
+            module: "size_20::solid",
+            feature: "20-solid-phone",
+            view: || view! { <Phone /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "photo",
+            component: "Photo",
+            module: "size_20::solid",
+            feature: "20-solid-photo",
+            view: || view! { <Photo /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "play-circle",
+            component: "PlayCircle",
+            module: "size_20::solid",
+            feature: "20-solid-play-circle",
+            view: || view! { <PlayCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "play-pause",
+            component: "PlayPause",
+            module: "size_20::solid",
+            feature: "20-solid-play-pause",
+            view: || view! { <PlayPause /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "play",
+            component: "Play",
+            module: "size_20::solid",
+            feature: "20-solid-play",
+            view: || view! { <Play /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "plus-circle",
+            component: "PlusCircle",
+            module: "size_20::solid",
+            feature: "20-solid-plus-circle",
+            view: || view! { <PlusCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "plus-small",
+            component: "PlusSmall",
+            module: "size_20::solid",
+            feature: "20-solid-plus-small",
+            view: || view! { <PlusSmall /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "plus",
+            component: "Plus",
+            module: "size_20::solid",
+            feature: "20-solid-plus",
+            view: || view! { <Plus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "power",
+            component: "Power",
+            module: "size_20::solid",
+            feature: "20-solid-power",
+            view: || view! { <Power /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "presentation-chart-bar",
+            component: "PresentationChartBar",
+            module: "size_20::solid",
+            feature: "20-solid-presentation-chart-bar",
+            view: || view! { <PresentationChartBar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "presentation-chart-line",
+            component: "PresentationChartLine",
+            module: "size_20::solid",
+            feature: "20-solid-presentation-chart-line",
+            view: || view! { <PresentationChartLine /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "printer",
+            component: "Printer",
+            module: "size_20::solid",
+            feature: "20-solid-printer",
+            view: || view! { <Printer /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "puzzle-piece",
+            component: "PuzzlePiece",
+            module: "size_20::solid",
+            feature: "20-solid-puzzle-piece",
+            view: || view! { <PuzzlePiece /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "qr-code",
+            component: "QrCode",
+            module: "size_20::solid",
+            feature: "20-solid-qr-code",
+            view: || view! { <QrCode /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "question-mark-circle",
+            component: "QuestionMarkCircle",
+            module: "size_20::solid",
+            feature: "20-solid-question-mark-circle",
+            view: || view! { <QuestionMarkCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "queue-list",
+            component: "QueueList",
+            module: "size_20::solid",
+            feature: "20-solid-queue-list",
+            view: || view! { <QueueList /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "radio",
+            component: "Radio",
+            module: "size_20::solid",
+            feature: "20-solid-radio",
+            view: || view! { <Radio /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "receipt-percent",
+            component: "ReceiptPercent",
+            module: "size_20::solid",
+            feature: "20-solid-receipt-percent",
+            view: || view! { <ReceiptPercent /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "receipt-refund",
+            component: "ReceiptRefund",
+            module: "size_20::solid",
+            feature: "20-solid-receipt-refund",
+            view: || view! { <ReceiptRefund /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "rectangle-group",
+            component: "RectangleGroup",
+            module: "size_20::solid",
+            feature: "20-solid-rectangle-group",
+            view: || view! { <RectangleGroup /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "rectangle-stack",
+            component: "RectangleStack",
+            module: "size_20::solid",
+            feature: "20-solid-rectangle-stack",
+            view: || view! { <RectangleStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "rocket-launch",
+            component: "RocketLaunch",
+            module: "size_20::solid",
+            feature: "20-solid-rocket-launch",
+            view: || view! { <RocketLaunch /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "rss",
+            component: "Rss",
+            module: "size_20::solid",
+            feature: "20-solid-rss",
+            view: || view! { <Rss /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "scale",
+            component: "Scale",
+            module: "size_20::solid",
+            feature: "20-solid-scale",
+            view: || view! { <Scale /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "scissors",
+            component: "Scissors",
+            module: "size_20::solid",
+            feature: "20-solid-scissors",
+            view: || view! { <Scissors /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "server-stack",
+            component: "ServerStack",
+            module: "size_20::solid",
+            feature: "20-solid-server-stack",
+            view: || view! { <ServerStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "server",
+            component: "Server",
+            module: "size_20::solid",
+            feature: "20-solid-server",
+            view: || view! { <Server /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "share",
+            component: "Share",
+            module: "size_20::solid",
+            feature: "20-solid-share",
+            view: || view! { <Share /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "shield-check",
+            component: "ShieldCheck",
+            module: "size_20::solid",
+            feature: "20-solid-shield-check",
+            view: || view! { <ShieldCheck /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "shield-exclamation",
+            component: "ShieldExclamation",
+            module: "size_20::solid",
+            feature: "20-solid-shield-exclamation",
+            view: || view! { <ShieldExclamation /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "shopping-bag",
+            component: "ShoppingBag",
+            module: "size_20::solid",
+            feature: "20-solid-shopping-bag",
+            view: || view! { <ShoppingBag /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "shopping-cart",
+            component: "ShoppingCart",
+            module: "size_20::solid",
+            feature: "20-solid-shopping-cart",
+            view: || view! { <ShoppingCart /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "signal-slash",
+            component: "SignalSlash",
+            module: "size_20::solid",
+            feature: "20-solid-signal-slash",
+            view: || view! { <SignalSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "signal",
+            component: "Signal",
+            module: "size_20::solid",
+            feature: "20-solid-signal",
+            view: || view! { <Signal /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "slash",
+            component: "Slash",
+            module: "size_20::solid",
+            feature: "20-solid-slash",
+            view: || view! { <Slash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "sparkles",
+            component: "Sparkles",
+            module: "size_20::solid",
+            feature: "20-solid-sparkles",
+            view: || view! { <Sparkles /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "speaker-wave",
+            component: "SpeakerWave",
+            module: "size_20::solid",
+            feature: "20-solid-speaker-wave",
+            view: || view! { <SpeakerWave /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "speaker-x-mark",
+            component: "SpeakerXMark",
+            module: "size_20::solid",
+            feature: "20-solid-speaker-x-mark",
+            view: || view! { <SpeakerXMark /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "square-2-stack",
+            component: "Square2Stack",
+            module: "size_20::solid",
+            feature: "20-solid-square-2-stack",
+            view: || view! { <Square2Stack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "square-3-stack-3d",
+            component: "Square3Stack3D",
+            module: "size_20::solid",
+            feature: "20-solid-square-3-stack-3d",
+            view: || view! { <Square3Stack3D /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "squares-2x2",
+            component: "Squares2X2",
+            module: "size_20::solid",
+            feature: "20-solid-squares-2x2",
+            view: || view! { <Squares2X2 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "squares-plus",
+            component: "SquaresPlus",
+            module: "size_20::solid",
+            feature: "20-solid-squares-plus",
+            view: || view! { <SquaresPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "star",
+            component: "Star",
+            module: "size_20::solid",
+            feature: "20-solid-star",
+            view: || view! { <Star /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "stop-circle",
+            component: "StopCircle",
+            module: "size_20::solid",
+            feature: "20-solid-stop-circle",
+            view: || view! { <StopCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "stop",
+            component: "Stop",
+            module: "size_20::solid",
+            feature: "20-solid-stop",
+            view: || view! { <Stop /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "strikethrough",
+            component: "Strikethrough",
+            module: "size_20::solid",
+            feature: "20-solid-strikethrough",
+            view: || view! { <Strikethrough /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "sun",
+            component: "Sun",
+            module: "size_20::solid",
+            feature: "20-solid-sun",
+            view: || view! { <Sun /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "swatch",
+            component: "Swatch",
+            module: "size_20::solid",
+            feature: "20-solid-swatch",
+            view: || view! { <Swatch /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "table-cells",
+            component: "TableCells",
+            module: "size_20::solid",
+            feature: "20-solid-table-cells",
+            view: || view! { <TableCells /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "tag",
+            component: "Tag",
+            module: "size_20::solid",
+            feature: "20-solid-tag",
+            view: || view! { <Tag /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "ticket",
+            component: "Ticket",
+            module: "size_20::solid",
+            feature: "20-solid-ticket",
+            view: || view! { <Ticket /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "trash",
+            component: "Trash",
+            module: "size_20::solid",
+            feature: "20-solid-trash",
+            view: || view! { <Trash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "trophy",
+            component: "Trophy",
+            module: "size_20::solid",
+            feature: "20-solid-trophy",
+            view: || view! { <Trophy /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "truck",
+            component: "Truck",
+            module: "size_20::solid",
+            feature: "20-solid-truck",
+            view: || view! { <Truck /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "tv",
+            component: "Tv",
+            module: "size_20::solid",
+            feature: "20-solid-tv",
+            view: || view! { <Tv /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "underline",
+            component: "Underline",
+            module: "size_20::solid",
+            feature: "20-solid-underline",
+            view: || view! { <Underline /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user-circle",
+            component: "UserCircle",
+            module: "size_20::solid",
+            feature: "20-solid-user-circle",
+            view: || view! { <UserCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user-group",
+            component: "UserGroup",
+            module: "size_20::solid",
+            feature: "20-solid-user-group",
+            view: || view! { <UserGroup /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user-minus",
+            component: "UserMinus",
+            module: "size_20::solid",
+            feature: "20-solid-user-minus",
+            view: || view! { <UserMinus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user-plus",
+            component: "UserPlus",
+            module: "size_20::solid",
+            feature: "20-solid-user-plus",
+            view: || view! { <UserPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user",
+            component: "User",
+            module: "size_20::solid",
+            feature: "20-solid-user",
+            view: || view! { <User /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "users",
+            component: "Users",
+            module: "size_20::solid",
+            feature: "20-solid-users",
+            view: || view! { <Users /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "variable",
+            component: "Variable",
+            module: "size_20::solid",
+            feature: "20-solid-variable",
+            view: || view! { <Variable /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "video-camera-slash",
+            component: "VideoCameraSlash",
+            module: "size_20::solid",
+            feature: "20-solid-video-camera-slash",
+            view: || view! { <VideoCameraSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "video-camera",
+            component: "VideoCamera",
+            module: "size_20::solid",
+            feature: "20-solid-video-camera",
+            view: || view! { <VideoCamera /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "view-columns",
+            component: "ViewColumns",
+            module: "size_20::solid",
+            feature: "20-solid-view-columns",
+            view: || view! { <ViewColumns /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "viewfinder-circle",
+            component: "ViewfinderCircle",
+            module: "size_20::solid",
+            feature: "20-solid-viewfinder-circle",
+            view: || view! { <ViewfinderCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "wallet",
+            component: "Wallet",
+            module: "size_20::solid",
+            feature: "20-solid-wallet",
+            view: || view! { <Wallet /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "wifi",
+            component: "Wifi",
+            module: "size_20::solid",
+            feature: "20-solid-wifi",
+            view: || view! { <Wifi /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "window",
+            component: "Window",
+            module: "size_20::solid",
+            feature: "20-solid-window",
+            view: || view! { <Window /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "wrench-screwdriver",
+            component: "WrenchScrewdriver",
+            module: "size_20::solid",
+            feature: "20-solid-wrench-screwdriver",
+            view: || view! { <WrenchScrewdriver /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "wrench",
+            component: "Wrench",
+            module: "size_20::solid",
+            feature: "20-solid-wrench",
+            view: || view! { <Wrench /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "x-circle",
+            component: "XCircle",
+            module: "size_20::solid",
+            feature: "20-solid-x-circle",
+            view: || view! { <XCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "x-mark",
+            component: "XMark",
+            module: "size_20::solid",
+            feature: "20-solid-x-mark",
+            view: || view! { <XMark /> }.into_any(),
+        },
     ]
 }
 
-pub fn size_24_solid() -> Vec<impl IntoView> {
-    use leptos_heroicons::size_24::solid::*;
+pub fn size_24_outline() -> Vec<GalleryIcon> {
+    use leptos_heroicons::size_24::outline::*;
 
     vec![
-        view! { <Italic /> }.into_any(),
-        view! { <Home /> }.into_any(),
-        view! { <Variable /> }.into_any(),
-        view! { <MagnifyingGlassPlus /> }.into_any(),
-        view! { <RectangleGroup /> }.into_any(),
-        view! { <PhoneArrowUpRight /> }.into_any(),
-        view! { <Truck /> }.into_any(),
-        view! { <ChevronDoubleDown /> }.into_any(),
-        view! { <HandThumbUp /> }.into_any(),
-        view! { <SignalSlash /> }.into_any(),
-        view! { <BookOpen /> }.into_any(),
-        view! { <Bars3BottomLeft /> }.into_any(),
-        view! { <EyeSlash /> }.into_any(),
-        view! { <Pause /> }.into_any(),
-        view! { <PercentBadge /> }.into_any(),
-        view! { <ArrowLeft /> }.into_any(),
-        view! { <Film /> }.into_any(),
-        view! { <PresentationChartBar /> }.into_any(),
-        view! { <Inbox /> }.into_any(),
-        view! { <Square2Stack /> }.into_any(),
-        view! { <Printer /> }.into_any(),
-        view! { <Bell /> }.into_any(),
-        view! { <BuildingStorefront /> }.into_any(),
-        view! { <ShieldCheck /> }.into_any(),
-        view! { <Flag /> }.into_any(),
-        view! { <ArrowTopRightOnSquare /> }.into_any(),
-        view! { <RocketLaunch /> }.into_any(),
-        view! { <DocumentCurrencyPound /> }.into_any(),
-        view! { <VideoCameraSlash /> }.into_any(),
-        view! { <Megaphone /> }.into_any(),
-        view! { <ArrowUturnDown /> }.into_any(),
-        view! { <WrenchScrewdriver /> }.into_any(),
-        view! { <ChatBubbleBottomCenterText /> }.into_any(),
-        view! { <CommandLine /> }.into_any(),
-        view! { <ClipboardDocumentList /> }.into_any(),
-        view! { <Newspaper /> }.into_any(),
-        view! { <ChartBar /> }.into_any(),
-        view! { <ArrowUturnUp /> }.into_any(),
-        view! { <PlayCircle /> }.into_any(),
-        view! { <ArrowTrendingUp /> }.into_any(),
-        view! { <Phone /> }.into_any(),
-        view! { <EnvelopeOpen /> }.into_any(),
-        view! { <ArrowDown /> }.into_any(),
-        view! { <Star /> }.into_any(),
-        view! { <FolderOpen /> }.into_any(),
-        view! { <Forward /> }.into_any(),
-        view! { <Rss /> }.into_any(),
-        view! { <PlayPause /> }.into_any(),
-        view! { <Bookmark /> }.into_any(),
-        view! { <MusicalNote /> }.into_any(),
-        view! { <FolderPlus /> }.into_any(),
-        view! { <Bolt /> }.into_any(),
-        view! { <Radio /> }.into_any(),
-        view! { <AtSymbol /> }.into_any(),
-        view! { <ViewColumns /> }.into_any(),
-        view! { <Bars2 /> }.into_any(),
-        view! { <XMark /> }.into_any(),
-        view! { <DocumentArrowUp /> }.into_any(),
-        view! { <ChevronDoubleLeft /> }.into_any(),
-        view! { <NumberedList /> }.into_any(),
-        view! { <ChatBubbleLeft /> }.into_any(),
-        view! { <Link /> }.into_any(),
-        view! { <FaceFrown /> }.into_any(),
-        view! { <Check /> }.into_any(),
-        view! { <ChevronDoubleUp /> }.into_any(),
-        view! { <PuzzlePiece /> }.into_any(),
-        view! { <Tag /> }.into_any(),
-        view! { <Swatch /> }.into_any(),
-        view! { <MagnifyingGlassCircle /> }.into_any(),
-        view! { <ArrowsPointingIn /> }.into_any(),
-        view! { <ReceiptRefund /> }.into_any(),
-        view! { <ArrowUturnLeft /> }.into_any(),
-        view! { <DeviceTablet /> }.into_any(),
-        view! { <Banknotes /> }.into_any(),
-        view! { <AdjustmentsVertical /> }.into_any(),
-        view! { <H2 /> }.into_any(),
-        view! { <ClipboardDocument /> }.into_any(),
-        view! { <CurrencyYen /> }.into_any(),
-        view! { <CodeBracket /> }.into_any(),
-        view! { <Square3Stack3D /> }.into_any(),
-        view! { <MinusSmall /> }.into_any(),
-        view! { <ChartPie /> }.into_any(),
-        view! { <ArrowTurnDownRight /> }.into_any(),
-        view! { <Squares2X2 /> }.into_any(),
-        view! { <BookmarkSquare /> }.into_any(),
-        view! { <InboxStack /> }.into_any(),
-        view! { <ArrowDownRight /> }.into_any(),
-        view! { <ClipboardDocumentCheck /> }.into_any(),
-        view! { <Photo /> }.into_any(),
-        view! { <DocumentMinus /> }.into_any(),
-        view! { <CloudArrowUp /> }.into_any(),
-        view! { <ArrowRight /> }.into_any(),
-        view! { <CodeBracketSquare /> }.into_any(),
-        view! { <ExclamationTriangle /> }.into_any(),
-        view! { <Eye /> }.into_any(),
-        view! { <HomeModern /> }.into_any(),
-        view! { <Slash /> }.into_any(),
-        view! { <Pencil /> }.into_any(),
-        view! { <GlobeAsiaAustralia /> }.into_any(),
-        view! { <Microphone /> }.into_any(),
-        view! { <Scale /> }.into_any(),
-        view! { <GlobeAmericas /> }.into_any(),
-        view! { <ChevronUp /> }.into_any(),
-        view! { <MagnifyingGlass /> }.into_any(),
-        view! { <DocumentCurrencyBangladeshi /> }.into_any(),
-        view! { <Window /> }.into_any(),
-        view! { <Funnel /> }.into_any(),
-        view! { <H3 /> }.into_any(),
-        view! { <ComputerDesktop /> }.into_any(),
-        view! { <PlusCircle /> }.into_any(),
-        view! { <MagnifyingGlassMinus /> }.into_any(),
-        view! { <FaceSmile /> }.into_any(),
-        view! { <DocumentPlus /> }.into_any(),
-        view! { <BarsArrowDown /> }.into_any(),
-        view! { <ArrowUpOnSquare /> }.into_any(),
-        view! { <ChevronDoubleRight /> }.into_any(),
-        view! { <ArrowDownOnSquareStack /> }.into_any(),
-        view! { <ArrowRightOnRectangle /> }.into_any(),
-        view! { <PaintBrush /> }.into_any(),
-        view! { <Stop /> }.into_any(),
-        view! { <EllipsisHorizontalCircle /> }.into_any(),
-        view! { <DocumentCurrencyYen /> }.into_any(),
-        view! { <ChatBubbleLeftEllipsis /> }.into_any(),
-        view! { <BugAnt /> }.into_any(),
-        view! { <Bars3BottomRight /> }.into_any(),
-        view! { <UserGroup /> }.into_any(),
-        view! { <FingerPrint /> }.into_any(),
-        view! { <Server /> }.into_any(),
-        view! { <CalendarDateRange /> }.into_any(),
-        view! { <ChevronRight /> }.into_any(),
-        view! { <ArrowTurnRightDown /> }.into_any(),
-        view! { <Wifi /> }.into_any(),
-        view! { <Key /> }.into_any(),
-        view! { <Bars4 /> }.into_any(),
-        view! { <EllipsisVertical /> }.into_any(),
-        view! { <ArrowPath /> }.into_any(),
-        view! { <LinkSlash /> }.into_any(),
-        view! { <Play /> }.into_any(),
-        view! { <Cloud /> }.into_any(),
-        view! { <Language /> }.into_any(),
-        view! { <Bars3 /> }.into_any(),
-        view! { <ArrowDownOnSquare /> }.into_any(),
-        view! { <ChatBubbleLeftRight /> }.into_any(),
-        view! { <ChatBubbleOvalLeft /> }.into_any(),
-        view! { <ArrowSmallUp /> }.into_any(),
-        view! { <Divide /> }.into_any(),
-        view! { <PhoneArrowDownLeft /> }.into_any(),
-        view! { <MapPin /> }.into_any(),
-        view! { <ArrowsRightLeft /> }.into_any(),
-        view! { <HandThumbDown /> }.into_any(),
-        view! { <Trophy /> }.into_any(),
-        view! { <EllipsisHorizontal /> }.into_any(),
-        view! { <CurrencyRupee /> }.into_any(),
-        view! { <ArrowTurnLeftUp /> }.into_any(),
-        view! { <ArrowSmallLeft /> }.into_any(),
-        view! { <ArrowUturnRight /> }.into_any(),
-        view! { <Briefcase /> }.into_any(),
-        view! { <Bold /> }.into_any(),
-        view! { <Cog6Tooth /> }.into_any(),
-        view! { <FolderMinus /> }.into_any(),
-        view! { <BellAlert /> }.into_any(),
-        view! { <Calculator /> }.into_any(),
-        view! { <Equals /> }.into_any(),
-        view! { <ChevronLeft /> }.into_any(),
-        view! { <DocumentArrowDown /> }.into_any(),
-        view! { <Calendar /> }.into_any(),
-        view! { <ArrowRightEndOnRectangle /> }.into_any(),
-        view! { <BuildingLibrary /> }.into_any(),
-        view! { <Identification /> }.into_any(),
-        view! { <CalendarDays /> }.into_any(),
-        view! { <ArchiveBox /> }.into_any(),
-        view! { <BuildingOffice2 /> }.into_any(),
-        view! { <ArrowTurnDownLeft /> }.into_any(),
-        view! { <CloudArrowDown /> }.into_any(),
-        view! { <SquaresPlus /> }.into_any(),
-        view! { <Power /> }.into_any(),
-        view! { <SpeakerWave /> }.into_any(),
-        view! { <ArrowLongDown /> }.into_any(),
-        view! { <Beaker /> }.into_any(),
-        view! { <CpuChip /> }.into_any(),
-        view! { <CurrencyBangladeshi /> }.into_any(),
-        view! { <ArrowTurnUpLeft /> }.into_any(),
-        view! { <QrCode /> }.into_any(),
-        view! { <Share /> }.into_any(),
-        view! { <Sun /> }.into_any(),
-        view! { <PaperClip /> }.into_any(),
-        view! { <Gift /> }.into_any(),
-        view! { <ExclamationCircle /> }.into_any(),
-        view! { <AcademicCap /> }.into_any(),
-        view! { <Wrench /> }.into_any(),
-        view! { <TableCells /> }.into_any(),
-        view! { <GiftTop /> }.into_any(),
-        view! { <FolderArrowDown /> }.into_any(),
-        view! { <ArrowLeftOnRectangle /> }.into_any(),
-        view! { <ArrowRightStartOnRectangle /> }.into_any(),
-        view! { <Battery0 /> }.into_any(),
-        view! { <Backspace /> }.into_any(),
-        view! { <Clock /> }.into_any(),
-        view! { <ChatBubbleOvalLeftEllipsis /> }.into_any(),
-        view! { <XCircle /> }.into_any(),
-        view! { <Map /> }.into_any(),
-        view! { <Battery100 /> }.into_any(),
-        view! { <Minus /> }.into_any(),
-        view! { <Envelope /> }.into_any(),
-        view! { <StopCircle /> }.into_any(),
-        view! { <Cube /> }.into_any(),
-        view! { <Sparkles /> }.into_any(),
-        view! { <ArrowDownTray /> }.into_any(),
-        view! { <ChevronDown /> }.into_any(),
-        view! { <PlusSmall /> }.into_any(),
-        view! { <PencilSquare /> }.into_any(),
-        view! { <Trash /> }.into_any(),
-        view! { <ViewfinderCircle /> }.into_any(),
-        view! { <UserMinus /> }.into_any(),
-        view! { <SpeakerXMark /> }.into_any(),
-        view! { <CheckBadge /> }.into_any(),
-        view! { <ArrowSmallDown /> }.into_any(),
-        view! { <ArrowLeftEndOnRectangle /> }.into_any(),
-        view! { <InboxArrowDown /> }.into_any(),
-        view! { <ArrowUpOnSquareStack /> }.into_any(),
-        view! { <DevicePhoneMobile /> }.into_any(),
-        view! { <User /> }.into_any(),
-        view! { <LockOpen /> }.into_any(),
-        view! { <ArrowSmallRight /> }.into_any(),
-        view! { <BookmarkSlash /> }.into_any(),
-        view! { <BellSlash /> }.into_any(),
-        view! { <CreditCard /> }.into_any(),
-        view! { <CursorArrowRipple /> }.into_any(),
-        view! { <DocumentCurrencyRupee /> }.into_any(),
-        view! { <Tv /> }.into_any(),
-        view! { <RectangleStack /> }.into_any(),
-        view! { <ArrowLeftStartOnRectangle /> }.into_any(),
-        view! { <ShieldExclamation /> }.into_any(),
-        view! { <DocumentChartBar /> }.into_any(),
-        view! { <ArrowTurnLeftDown /> }.into_any(),
-        view! { <ArrowPathRoundedSquare /> }.into_any(),
-        view! { <EyeDropper /> }.into_any(),
-        view! { <ArrowLongLeft /> }.into_any(),
-        view! { <CurrencyEuro /> }.into_any(),
-        view! { <Gif /> }.into_any(),
-        view! { <Cog /> }.into_any(),
-        view! { <CheckCircle /> }.into_any(),
-        view! { <QueueList /> }.into_any(),
-        view! { <ArrowDownLeft /> }.into_any(),
-        view! { <ReceiptPercent /> }.into_any(),
-        view! { <HandRaised /> }.into_any(),
-        view! { <DocumentCheck /> }.into_any(),
-        view! { <PaperAirplane /> }.into_any(),
-        view! { <GlobeEuropeAfrica /> }.into_any(),
-        view! { <ArrowDownCircle /> }.into_any(),
-        view! { <InformationCircle /> }.into_any(),
-        view! { <Ticket /> }.into_any(),
-        view! { <PresentationChartLine /> }.into_any(),
-        view! { <UserPlus /> }.into_any(),
-        view! { <ArrowTurnUpRight /> }.into_any(),
-        view! { <VideoCamera /> }.into_any(),
-        view! { <ListBullet /> }.into_any(),
-        view! { <ArrowTrendingDown /> }.into_any(),
-        view! { <Heart /> }.into_any(),
-        view! { <Hashtag /> }.into_any(),
-        view! { <CurrencyDollar /> }.into_any(),
-        view! { <PhoneXMark /> }.into_any(),
-        view! { <ArrowUpTray /> }.into_any(),
-        view! { <DocumentCurrencyEuro /> }.into_any(),
-        view! { <LockClosed /> }.into_any(),
-        view! { <DocumentText /> }.into_any(),
-        view! { <BarsArrowUp /> }.into_any(),
-        view! { <ArrowUpCircle /> }.into_any(),
-        view! { <BuildingOffice /> }.into_any(),
-        view! { <DocumentDuplicate /> }.into_any(),
-        view! { <AdjustmentsHorizontal /> }.into_any(),
-        view! { <ChevronUpDown /> }.into_any(),
-        view! { <Document /> }.into_any(),
-        view! { <NoSymbol /> }.into_any(),
-        view! { <UserCircle /> }.into_any(),
-        view! { <ServerStack /> }.into_any(),
-        view! { <ArrowUpLeft /> }.into_any(),
-        view! { <ArchiveBoxXMark /> }.into_any(),
-        view! { <H1 /> }.into_any(),
-        view! { <BellSnooze /> }.into_any(),
-        view! { <Scissors /> }.into_any(),
-        view! { <Plus /> }.into_any(),
-        view! { <ArrowLongRight /> }.into_any(),
-        view! { <Bars3CenterLeft /> }.into_any(),
-        view! { <ArrowUpRight /> }.into_any(),
-        view! { <DocumentMagnifyingGlass /> }.into_any(),
-        view! { <ArrowsPointingOut /> }.into_any(),
-        view! { <CurrencyPound /> }.into_any(),
-        view! { <ArrowTurnRightUp /> }.into_any(),
-        view! { <Battery50 /> }.into_any(),
-        view! { <Moon /> }.into_any(),
-        view! { <ShoppingBag /> }.into_any(),
-        view! { <Folder /> }.into_any(),
-        view! { <MinusCircle /> }.into_any(),
-        view! { <CubeTransparent /> }.into_any(),
-        view! { <ArrowLongUp /> }.into_any(),
-        view! { <PauseCircle /> }.into_any(),
-        view! { <Lifebuoy /> }.into_any(),
-        view! { <Signal /> }.into_any(),
-        view! { <BoltSlash /> }.into_any(),
-        view! { <Clipboard /> }.into_any(),
-        view! { <ShoppingCart /> }.into_any(),
-        view! { <ChartBarSquare /> }.into_any(),
-        view! { <ArrowUp /> }.into_any(),
-        view! { <Users /> }.into_any(),
-        view! { <ArchiveBoxArrowDown /> }.into_any(),
-        view! { <Backward /> }.into_any(),
-        view! { <CircleStack /> }.into_any(),
-        view! { <ArrowsUpDown /> }.into_any(),
-        view! { <Camera /> }.into_any(),
-        view! { <Fire /> }.into_any(),
-        view! { <Wallet /> }.into_any(),
-        view! { <LightBulb /> }.into_any(),
-        view! { <ArrowLeftCircle /> }.into_any(),
-        view! { <GlobeAlt /> }.into_any(),
-        view! { <CursorArrowRays /> }.into_any(),
-        view! { <DocumentCurrencyDollar /> }.into_any(),
-        view! { <ArrowRightCircle /> }.into_any(),
-        view! { <Cog8Tooth /> }.into_any(),
-        view! { <Strikethrough /> }.into_any(),
-        view! { <Cake /> }.into_any(),
-        view! { <QuestionMarkCircle /> }.into_any(),
-        view! { <Underline /> }.into_any(),
-        view! { <ChatBubbleBottomCenter /> }.into_any(),
+        GalleryIcon {
+            name: "academic-cap",
+            component: "AcademicCap",
+            module: "size_24::outline",
+            feature: "24-outline-academic-cap",
+            view: || view! { <AcademicCap /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "adjustments-horizontal",
+            component: "AdjustmentsHorizontal",
+            module: "size_24::outline",
+            feature: "24-outline-adjustments-horizontal",
+            view: || view! { <AdjustmentsHorizontal /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "adjustments-vertical",
+            component: "AdjustmentsVertical",
+            module: "size_24::outline",
+            feature: "24-outline-adjustments-vertical",
+            view: || view! { <AdjustmentsVertical /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "archive-box-arrow-down",
+            component: "ArchiveBoxArrowDown",
+            module: "size_24::outline",
+            feature: "24-outline-archive-box-arrow-down",
+            view: || view! { <ArchiveBoxArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "archive-box-x-mark",
+            component: "ArchiveBoxXMark",
+            module: "size_24::outline",
+            feature: "24-outline-archive-box-x-mark",
+            view: || view! { <ArchiveBoxXMark /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "archive-box",
+            component: "ArchiveBox",
+            module: "size_24::outline",
+            feature: "24-outline-archive-box",
+            view: || view! { <ArchiveBox /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-circle",
+            component: "ArrowDownCircle",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-down-circle",
+            view: || view! { <ArrowDownCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-left",
+            component: "ArrowDownLeft",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-down-left",
+            view: || view! { <ArrowDownLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-on-square-stack",
+            component: "ArrowDownOnSquareStack",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-down-on-square-stack",
+            view: || view! { <ArrowDownOnSquareStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-on-square",
+            component: "ArrowDownOnSquare",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-down-on-square",
+            view: || view! { <ArrowDownOnSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-right",
+            component: "ArrowDownRight",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-down-right",
+            view: || view! { <ArrowDownRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-tray",
+            component: "ArrowDownTray",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-down-tray",
+            view: || view! { <ArrowDownTray /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down",
+            component: "ArrowDown",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-down",
+            view: || view! { <ArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left-circle",
+            component: "ArrowLeftCircle",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-left-circle",
+            view: || view! { <ArrowLeftCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left-end-on-rectangle",
+            component: "ArrowLeftEndOnRectangle",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-left-end-on-rectangle",
+            view: || view! { <ArrowLeftEndOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left-on-rectangle",
+            component: "ArrowLeftOnRectangle",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-left-on-rectangle",
+            view: || view! { <ArrowLeftOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left-start-on-rectangle",
+            component: "ArrowLeftStartOnRectangle",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-left-start-on-rectangle",
+            view: || view! { <ArrowLeftStartOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left",
+            component: "ArrowLeft",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-left",
+            view: || view! { <ArrowLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-long-down",
+            component: "ArrowLongDown",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-long-down",
+            view: || view! { <ArrowLongDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-long-left",
+            component: "ArrowLongLeft",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-long-left",
+            view: || view! { <ArrowLongLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-long-right",
+            component: "ArrowLongRight",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-long-right",
+            view: || view! { <ArrowLongRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-long-up",
+            component: "ArrowLongUp",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-long-up",
+            view: || view! { <ArrowLongUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-path-rounded-square",
+            component: "ArrowPathRoundedSquare",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-path-rounded-square",
+            view: || view! { <ArrowPathRoundedSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-path",
+            component: "ArrowPath",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-path",
+            view: || view! { <ArrowPath /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right-circle",
+            component: "ArrowRightCircle",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-right-circle",
+            view: || view! { <ArrowRightCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right-end-on-rectangle",
+            component: "ArrowRightEndOnRectangle",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-right-end-on-rectangle",
+            view: || view! { <ArrowRightEndOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right-on-rectangle",
+            component: "ArrowRightOnRectangle",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-right-on-rectangle",
+            view: || view! { <ArrowRightOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right-start-on-rectangle",
+            component: "ArrowRightStartOnRectangle",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-right-start-on-rectangle",
+            view: || view! { <ArrowRightStartOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right",
+            component: "ArrowRight",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-right",
+            view: || view! { <ArrowRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-small-down",
+            component: "ArrowSmallDown",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-small-down",
+            view: || view! { <ArrowSmallDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-small-left",
+            component: "ArrowSmallLeft",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-small-left",
+            view: || view! { <ArrowSmallLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-small-right",
+            component: "ArrowSmallRight",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-small-right",
+            view: || view! { <ArrowSmallRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-small-up",
+            component: "ArrowSmallUp",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-small-up",
+            view: || view! { <ArrowSmallUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-top-right-on-square",
+            component: "ArrowTopRightOnSquare",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-top-right-on-square",
+            view: || view! { <ArrowTopRightOnSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-trending-down",
+            component: "ArrowTrendingDown",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-trending-down",
+            view: || view! { <ArrowTrendingDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-trending-up",
+            component: "ArrowTrendingUp",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-trending-up",
+            view: || view! { <ArrowTrendingUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-down-left",
+            component: "ArrowTurnDownLeft",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-turn-down-left",
+            view: || view! { <ArrowTurnDownLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-down-right",
+            component: "ArrowTurnDownRight",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-turn-down-right",
+            view: || view! { <ArrowTurnDownRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-left-down",
+            component: "ArrowTurnLeftDown",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-turn-left-down",
+            view: || view! { <ArrowTurnLeftDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-left-up",
+            component: "ArrowTurnLeftUp",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-turn-left-up",
+            view: || view! { <ArrowTurnLeftUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-right-down",
+            component: "ArrowTurnRightDown",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-turn-right-down",
+            view: || view! { <ArrowTurnRightDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-right-up",
+            component: "ArrowTurnRightUp",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-turn-right-up",
+            view: || view! { <ArrowTurnRightUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-up-left",
+            component: "ArrowTurnUpLeft",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-turn-up-left",
+            view: || view! { <ArrowTurnUpLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-up-right",
+            component: "ArrowTurnUpRight",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-turn-up-right",
+            view: || view! { <ArrowTurnUpRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-circle",
+            component: "ArrowUpCircle",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-up-circle",
+            view: || view! { <ArrowUpCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-left",
+            component: "ArrowUpLeft",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-up-left",
+            view: || view! { <ArrowUpLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-on-square-stack",
+            component: "ArrowUpOnSquareStack",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-up-on-square-stack",
+            view: || view! { <ArrowUpOnSquareStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-on-square",
+            component: "ArrowUpOnSquare",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-up-on-square",
+            view: || view! { <ArrowUpOnSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-right",
+            component: "ArrowUpRight",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-up-right",
+            view: || view! { <ArrowUpRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-tray",
+            component: "ArrowUpTray",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-up-tray",
+            view: || view! { <ArrowUpTray /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up",
+            component: "ArrowUp",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-up",
+            view: || view! { <ArrowUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-uturn-down",
+            component: "ArrowUturnDown",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-uturn-down",
+            view: || view! { <ArrowUturnDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-uturn-left",
+            component: "ArrowUturnLeft",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-uturn-left",
+            view: || view! { <ArrowUturnLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-uturn-right",
+            component: "ArrowUturnRight",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-uturn-right",
+            view: || view! { <ArrowUturnRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-uturn-up",
+            component: "ArrowUturnUp",
+            module: "size_24::outline",
+            feature: "24-outline-arrow-uturn-up",
+            view: || view! { <ArrowUturnUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrows-pointing-in",
+            component: "ArrowsPointingIn",
+            module: "size_24::outline",
+            feature: "24-outline-arrows-pointing-in",
+            view: || view! { <ArrowsPointingIn /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrows-pointing-out",
+            component: "ArrowsPointingOut",
+            module: "size_24::outline",
+            feature: "24-outline-arrows-pointing-out",
+            view: || view! { <ArrowsPointingOut /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrows-right-left",
+            component: "ArrowsRightLeft",
+            module: "size_24::outline",
+            feature: "24-outline-arrows-right-left",
+            view: || view! { <ArrowsRightLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrows-up-down",
+            component: "ArrowsUpDown",
+            module: "size_24::outline",
+            feature: "24-outline-arrows-up-down",
+            view: || view! { <ArrowsUpDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "at-symbol",
+            component: "AtSymbol",
+            module: "size_24::outline",
+            feature: "24-outline-at-symbol",
+            view: || view! { <AtSymbol /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "backspace",
+            component: "Backspace",
+            module: "size_24::outline",
+            feature: "24-outline-backspace",
+            view: || view! { <Backspace /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "backward",
+            component: "Backward",
+            module: "size_24::outline",
+            feature: "24-outline-backward",
+            view: || view! { <Backward /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "banknotes",
+            component: "Banknotes",
+            module: "size_24::outline",
+            feature: "24-outline-banknotes",
+            view: || view! { <Banknotes /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-2",
+            component: "Bars2",
+            module: "size_24::outline",
+            feature: "24-outline-bars-2",
+            view: || view! { <Bars2 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-3-bottom-left",
+            component: "Bars3BottomLeft",
+            module: "size_24::outline",
+            feature: "24-outline-bars-3-bottom-left",
+            view: || view! { <Bars3BottomLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-3-bottom-right",
+            component: "Bars3BottomRight",
+            module: "size_24::outline",
+            feature: "24-outline-bars-3-bottom-right",
+            view: || view! { <Bars3BottomRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-3-center-left",
+            component: "Bars3CenterLeft",
+            module: "size_24::outline",
+            feature: "24-outline-bars-3-center-left",
+            view: || view! { <Bars3CenterLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-3",
+            component: "Bars3",
+            module: "size_24::outline",
+            feature: "24-outline-bars-3",
+            view: || view! { <Bars3 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-4",
+            component: "Bars4",
+            module: "size_24::outline",
+            feature: "24-outline-bars-4",
+            view: || view! { <Bars4 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-arrow-down",
+            component: "BarsArrowDown",
+            module: "size_24::outline",
+            feature: "24-outline-bars-arrow-down",
+            view: || view! { <BarsArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-arrow-up",
+            component: "BarsArrowUp",
+            module: "size_24::outline",
+            feature: "24-outline-bars-arrow-up",
+            view: || view! { <BarsArrowUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "battery-0",
+            component: "Battery0",
+            module: "size_24::outline",
+            feature: "24-outline-battery-0",
+            view: || view! { <Battery0 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "battery-100",
+            component: "Battery100",
+            module: "size_24::outline",
+            feature: "24-outline-battery-100",
+            view: || view! { <Battery100 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "battery-50",
+            component: "Battery50",
+            module: "size_24::outline",
+            feature: "24-outline-battery-50",
+            view: || view! { <Battery50 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "beaker",
+            component: "Beaker",
+            module: "size_24::outline",
+            feature: "24-outline-beaker",
+            view: || view! { <Beaker /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bell-alert",
+            component: "BellAlert",
+            module: "size_24::outline",
+            feature: "24-outline-bell-alert",
+            view: || view! { <BellAlert /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bell-slash",
+            component: "BellSlash",
+            module: "size_24::outline",
+            feature: "24-outline-bell-slash",
+            view: || view! { <BellSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bell-snooze",
+            component: "BellSnooze",
+            module: "size_24::outline",
+            feature: "24-outline-bell-snooze",
+            view: || view! { <BellSnooze /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bell",
+            component: "Bell",
+            module: "size_24::outline",
+            feature: "24-outline-bell",
+            view: || view! { <Bell /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bold",
+            component: "Bold",
+            module: "size_24::outline",
+            feature: "24-outline-bold",
+            view: || view! { <Bold /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bolt-slash",
+            component: "BoltSlash",
+            module: "size_24::outline",
+            feature: "24-outline-bolt-slash",
+            view: || view! { <BoltSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bolt",
+            component: "Bolt",
+            module: "size_24::outline",
+            feature: "24-outline-bolt",
+            view: || view! { <Bolt /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "book-open",
+            component: "BookOpen",
+            module: "size_24::outline",
+            feature: "24-outline-book-open",
+            view: || view! { <BookOpen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bookmark-slash",
+            component: "BookmarkSlash",
+            module: "size_24::outline",
+            feature: "24-outline-bookmark-slash",
+            view: || view! { <BookmarkSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bookmark-square",
+            component: "BookmarkSquare",
+            module: "size_24::outline",
+            feature: "24-outline-bookmark-square",
+            view: || view! { <BookmarkSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bookmark",
+            component: "Bookmark",
+            module: "size_24::outline",
+            feature: "24-outline-bookmark",
+            view: || view! { <Bookmark /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "briefcase",
+            component: "Briefcase",
+            module: "size_24::outline",
+            feature: "24-outline-briefcase",
+            view: || view! { <Briefcase /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bug-ant",
+            component: "BugAnt",
+            module: "size_24::outline",
+            feature: "24-outline-bug-ant",
+            view: || view! { <BugAnt /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "building-library",
+            component: "BuildingLibrary",
+            module: "size_24::outline",
+            feature: "24-outline-building-library",
+            view: || view! { <BuildingLibrary /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "building-office-2",
+            component: "BuildingOffice2",
+            module: "size_24::outline",
+            feature: "24-outline-building-office-2",
+            view: || view! { <BuildingOffice2 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "building-office",
+            component: "BuildingOffice",
+            module: "size_24::outline",
+            feature: "24-outline-building-office",
+            view: || view! { <BuildingOffice /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "building-storefront",
+            component: "BuildingStorefront",
+            module: "size_24::outline",
+            feature: "24-outline-building-storefront",
+            view: || view! { <BuildingStorefront /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cake",
+            component: "Cake",
+            module: "size_24::outline",
+            feature: "24-outline-cake",
+            view: || view! { <Cake /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "calculator",
+            component: "Calculator",
+            module: "size_24::outline",
+            feature: "24-outline-calculator",
+            view: || view! { <Calculator /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "calendar-date-range",
+            component: "CalendarDateRange",
+            module: "size_24::outline",
+            feature: "24-outline-calendar-date-range",
+            view: || view! { <CalendarDateRange /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "calendar-days",
+            component: "CalendarDays",
+            module: "size_24::outline",
+            feature: "24-outline-calendar-days",
+            view: || view! { <CalendarDays /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "calendar",
+            component: "Calendar",
+            module: "size_24::outline",
+            feature: "24-outline-calendar",
+            view: || view! { <Calendar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "camera",
+            component: "Camera",
+            module: "size_24::outline",
+            feature: "24-outline-camera",
+            view: || view! { <Camera /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chart-bar-square",
+            component: "ChartBarSquare",
+            module: "size_24::outline",
+            feature: "24-outline-chart-bar-square",
+            view: || view! { <ChartBarSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chart-bar",
+            component: "ChartBar",
+            module: "size_24::outline",
+            feature: "24-outline-chart-bar",
+            view: || view! { <ChartBar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chart-pie",
+            component: "ChartPie",
+            module: "size_24::outline",
+            feature: "24-outline-chart-pie",
+            view: || view! { <ChartPie /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-bottom-center-text",
+            component: "ChatBubbleBottomCenterText",
+            module: "size_24::outline",
+            feature: "24-outline-chat-bubble-bottom-center-text",
+            view: || view! { <ChatBubbleBottomCenterText /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-bottom-center",
+            component: "ChatBubbleBottomCenter",
+            module: "size_24::outline",
+            feature: "24-outline-chat-bubble-bottom-center",
+            view: || view! { <ChatBubbleBottomCenter /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-left-ellipsis",
+            component: "ChatBubbleLeftEllipsis",
+            module: "size_24::outline",
+            feature: "24-outline-chat-bubble-left-ellipsis",
+            view: || view! { <ChatBubbleLeftEllipsis /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-left-right",
+            component: "ChatBubbleLeftRight",
+            module: "size_24::outline",
+            feature: "24-outline-chat-bubble-left-right",
+            view: || view! { <ChatBubbleLeftRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-left",
+            component: "ChatBubbleLeft",
+            module: "size_24::outline",
+            feature: "24-outline-chat-bubble-left",
+            view: || view! { <ChatBubbleLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-oval-left-ellipsis",
+            component: "ChatBubbleOvalLeftEllipsis",
+            module: "size_24::outline",
+            feature: "24-outline-chat-bubble-oval-left-ellipsis",
+            view: || view! { <ChatBubbleOvalLeftEllipsis /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-oval-left",
+            component: "ChatBubbleOvalLeft",
+            module: "size_24::outline",
+            feature: "24-outline-chat-bubble-oval-left",
+            view: || view! { <ChatBubbleOvalLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "check-badge",
+            component: "CheckBadge",
+            module: "size_24::outline",
+            feature: "24-outline-check-badge",
+            view: || view! { <CheckBadge /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "check-circle",
+            component: "CheckCircle",
+            module: "size_24::outline",
+            feature: "24-outline-check-circle",
+            view: || view! { <CheckCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "check",
+            component: "Check",
+            module: "size_24::outline",
+            feature: "24-outline-check",
+            view: || view! { <Check /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-double-down",
+            component: "ChevronDoubleDown",
+            module: "size_24::outline",
+            feature: "24-outline-chevron-double-down",
+            view: || view! { <ChevronDoubleDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-double-left",
+            component: "ChevronDoubleLeft",
+            module: "size_24::outline",
+            feature: "24-outline-chevron-double-left",
+            view: || view! { <ChevronDoubleLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-double-right",
+            component: "ChevronDoubleRight",
+            module: "size_24::outline",
+            feature: "24-outline-chevron-double-right",
+            view: || view! { <ChevronDoubleRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-double-up",
+            component: "ChevronDoubleUp",
+            module: "size_24::outline",
+            feature: "24-outline-chevron-double-up",
+            view: || view! { <ChevronDoubleUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-down",
+            component: "ChevronDown",
+            module: "size_24::outline",
+            feature: "24-outline-chevron-down",
+            view: || view! { <ChevronDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-left",
+            component: "ChevronLeft",
+            module: "size_24::outline",
+            feature: "24-outline-chevron-left",
+            view: || view! { <ChevronLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-right",
+            component: "ChevronRight",
+            module: "size_24::outline",
+            feature: "24-outline-chevron-right",
+            view: || view! { <ChevronRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-up-down",
+            component: "ChevronUpDown",
+            module: "size_24::outline",
+            feature: "24-outline-chevron-up-down",
+            view: || view! { <ChevronUpDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-up",
+            component: "ChevronUp",
+            module: "size_24::outline",
+            feature: "24-outline-chevron-up",
+            view: || view! { <ChevronUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "circle-stack",
+            component: "CircleStack",
+            module: "size_24::outline",
+            feature: "24-outline-circle-stack",
+            view: || view! { <CircleStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clipboard-document-check",
+            component: "ClipboardDocumentCheck",
+            module: "size_24::outline",
+            feature: "24-outline-clipboard-document-check",
+            view: || view! { <ClipboardDocumentCheck /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clipboard-document-list",
+            component: "ClipboardDocumentList",
+            module: "size_24::outline",
+            feature: "24-outline-clipboard-document-list",
+            view: || view! { <ClipboardDocumentList /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clipboard-document",
+            component: "ClipboardDocument",
+            module: "size_24::outline",
+            feature: "24-outline-clipboard-document",
+            view: || view! { <ClipboardDocument /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clipboard",
+            component: "Clipboard",
+            module: "size_24::outline",
+            feature: "24-outline-clipboard",
+            view: || view! { <Clipboard /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clock",
+            component: "Clock",
+            module: "size_24::outline",
+            feature: "24-outline-clock",
+            view: || view! { <Clock /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cloud-arrow-down",
+            component: "CloudArrowDown",
+            module: "size_24::outline",
+            feature: "24-outline-cloud-arrow-down",
+            view: || view! { <CloudArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cloud-arrow-up",
+            component: "CloudArrowUp",
+            module: "size_24::outline",
+            feature: "24-outline-cloud-arrow-up",
+            view: || view! { <CloudArrowUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cloud",
+            component: "Cloud",
+            module: "size_24::outline",
+            feature: "24-outline-cloud",
+            view: || view! { <Cloud /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "code-bracket-square",
+            component: "CodeBracketSquare",
+            module: "size_24::outline",
+            feature: "24-outline-code-bracket-square",
+            view: || view! { <CodeBracketSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "code-bracket",
+            component: "CodeBracket",
+            module: "size_24::outline",
+            feature: "24-outline-code-bracket",
+            view: || view! { <CodeBracket /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cog-6-tooth",
+            component: "Cog6Tooth",
+            module: "size_24::outline",
+            feature: "24-outline-cog-6-tooth",
+            view: || view! { <Cog6Tooth /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cog-8-tooth",
+            component: "Cog8Tooth",
+            module: "size_24::outline",
+            feature: "24-outline-cog-8-tooth",
+            view: || view! { <Cog8Tooth /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cog",
+            component: "Cog",
+            module: "size_24::outline",
+            feature: "24-outline-cog",
+            view: || view! { <Cog /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "command-line",
+            component: "CommandLine",
+            module: "size_24::outline",
+            feature: "24-outline-command-line",
+            view: || view! { <CommandLine /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "computer-desktop",
+            component: "ComputerDesktop",
+            module: "size_24::outline",
+            feature: "24-outline-computer-desktop",
+            view: || view! { <ComputerDesktop /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cpu-chip",
+            component: "CpuChip",
+            module: "size_24::outline",
+            feature: "24-outline-cpu-chip",
+            view: || view! { <CpuChip /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "credit-card",
+            component: "CreditCard",
+            module: "size_24::outline",
+            feature: "24-outline-credit-card",
+            view: || view! { <CreditCard /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cube-transparent",
+            component: "CubeTransparent",
+            module: "size_24::outline",
+            feature: "24-outline-cube-transparent",
+            view: || view! { <CubeTransparent /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cube",
+            component: "Cube",
+            module: "size_24::outline",
+            feature: "24-outline-cube",
+            view: || view! { <Cube /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-bangladeshi",
+            component: "CurrencyBangladeshi",
+            module: "size_24::outline",
+            feature: "24-outline-currency-bangladeshi",
+            view: || view! { <CurrencyBangladeshi /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-dollar",
+            component: "CurrencyDollar",
+            module: "size_24::outline",
+            feature: "24-outline-currency-dollar",
+            view: || view! { <CurrencyDollar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-euro",
+            component: "CurrencyEuro",
+            module: "size_24::outline",
+            feature: "24-outline-currency-euro",
+            view: || view! { <CurrencyEuro /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-pound",
+            component: "CurrencyPound",
+            module: "size_24::outline",
+            feature: "24-outline-currency-pound",
+            view: || view! { <CurrencyPound /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-rupee",
+            component: "CurrencyRupee",
+            module: "size_24::outline",
+            feature: "24-outline-currency-rupee",
+            view: || view! { <CurrencyRupee /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-yen",
+            component: "CurrencyYen",
+            module: "size_24::outline",
+            feature: "24-outline-currency-yen",
+            view: || view! { <CurrencyYen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cursor-arrow-rays",
+            component: "CursorArrowRays",
+            module: "size_24::outline",
+            feature: "24-outline-cursor-arrow-rays",
+            view: || view! { <CursorArrowRays /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cursor-arrow-ripple",
+            component: "CursorArrowRipple",
+            module: "size_24::outline",
+            feature: "24-outline-cursor-arrow-ripple",
+            view: || view! { <CursorArrowRipple /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "device-phone-mobile",
+            component: "DevicePhoneMobile",
+            module: "size_24::outline",
+            feature: "24-outline-device-phone-mobile",
+            view: || view! { <DevicePhoneMobile /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "device-tablet",
+            component: "DeviceTablet",
+            module: "size_24::outline",
+            feature: "24-outline-device-tablet",
+            view: || view! { <DeviceTablet /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "divide",
+            component: "Divide",
+            module: "size_24::outline",
+            feature: "24-outline-divide",
+            view: || view! { <Divide /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-arrow-down",
+            component: "DocumentArrowDown",
+            module: "size_24::outline",
+            feature: "24-outline-document-arrow-down",
+            view: || view! { <DocumentArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-arrow-up",
+            component: "DocumentArrowUp",
+            module: "size_24::outline",
+            feature: "24-outline-document-arrow-up",
+            view: || view! { <DocumentArrowUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-chart-bar",
+            component: "DocumentChartBar",
+            module: "size_24::outline",
+            feature: "24-outline-document-chart-bar",
+            view: || view! { <DocumentChartBar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-check",
+            component: "DocumentCheck",
+            module: "size_24::outline",
+            feature: "24-outline-document-check",
+            view: || view! { <DocumentCheck /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-bangladeshi",
+            component: "DocumentCurrencyBangladeshi",
+            module: "size_24::outline",
+            feature: "24-outline-document-currency-bangladeshi",
+            view: || view! { <DocumentCurrencyBangladeshi /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-dollar",
+            component: "DocumentCurrencyDollar",
+            module: "size_24::outline",
+            feature: "24-outline-document-currency-dollar",
+            view: || view! { <DocumentCurrencyDollar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-euro",
+            component: "DocumentCurrencyEuro",
+            module: "size_24::outline",
+            feature: "24-outline-document-currency-euro",
+            view: || view! { <DocumentCurrencyEuro /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-pound",
+            component: "DocumentCurrencyPound",
+            module: "size_24::outline",
+            feature: "24-outline-document-currency-pound",
+            view: || view! { <DocumentCurrencyPound /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-rupee",
+            component: "DocumentCurrencyRupee",
+            module: "size_24::outline",
+            feature: "24-outline-document-currency-rupee",
+            view: || view! { <DocumentCurrencyRupee /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-yen",
+            component: "DocumentCurrencyYen",
+            module: "size_24::outline",
+            feature: "24-outline-document-currency-yen",
+            view: || view! { <DocumentCurrencyYen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-duplicate",
+            component: "DocumentDuplicate",
+            module: "size_24::outline",
+            feature: "24-outline-document-duplicate",
+            view: || view! { <DocumentDuplicate /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-magnifying-glass",
+            component: "DocumentMagnifyingGlass",
+            module: "size_24::outline",
+            feature: "24-outline-document-magnifying-glass",
+            view: || view! { <DocumentMagnifyingGlass /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-minus",
+            component: "DocumentMinus",
+            module: "size_24::outline",
+            feature: "24-outline-document-minus",
+            view: || view! { <DocumentMinus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-plus",
+            component: "DocumentPlus",
+            module: "size_24::outline",
+            feature: "24-outline-document-plus",
+            view: || view! { <DocumentPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-text",
+            component: "DocumentText",
+            module: "size_24::outline",
+            feature: "24-outline-document-text",
+            view: || view! { <DocumentText /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document",
+            component: "Document",
+            module: "size_24::outline",
+            feature: "24-outline-document",
+            view: || view! { <Document /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "ellipsis-horizontal-circle",
+            component: "EllipsisHorizontalCircle",
+            module: "size_24::outline",
+            feature: "24-outline-ellipsis-horizontal-circle",
+            view: || view! { <EllipsisHorizontalCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "ellipsis-horizontal",
+            component: "EllipsisHorizontal",
+            module: "size_24::outline",
+            feature: "24-outline-ellipsis-horizontal",
+            view: || view! { <EllipsisHorizontal /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "ellipsis-vertical",
+            component: "EllipsisVertical",
+            module: "size_24::outline",
+            feature: "24-outline-ellipsis-vertical",
+            view: || view! { <EllipsisVertical /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "envelope-open",
+            component: "EnvelopeOpen",
+            module: "size_24::outline",
+            feature: "24-outline-envelope-open",
+            view: || view! { <EnvelopeOpen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "envelope",
+            component: "Envelope",
+            module: "size_24::outline",
+            feature: "24-outline-envelope",
+            view: || view! { <Envelope /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "equals",
+            component: "Equals",
+            module: "size_24::outline",
+            feature: "24-outline-equals",
+            view: || view! { <Equals /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "exclamation-circle",
+            component: "ExclamationCircle",
+            module: "size_24::outline",
+            feature: "24-outline-exclamation-circle",
+            view: || view! { <ExclamationCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "exclamation-triangle",
+            component: "ExclamationTriangle",
+            module: "size_24::outline",
+            feature: "24-outline-exclamation-triangle",
+            view: || view! { <ExclamationTriangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "eye-dropper",
+            component: "EyeDropper",
+            module: "size_24::outline",
+            feature: "24-outline-eye-dropper",
+            view: || view! { <EyeDropper /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "eye-slash",
+            component: "EyeSlash",
+            module: "size_24::outline",
+            feature: "24-outline-eye-slash",
+            view: || view! { <EyeSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "eye",
+            component: "Eye",
+            module: "size_24::outline",
+            feature: "24-outline-eye",
+            view: || view! { <Eye /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "face-frown",
+            component: "FaceFrown",
+            module: "size_24::outline",
+            feature: "24-outline-face-frown",
+            view: || view! { <FaceFrown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "face-smile",
+            component: "FaceSmile",
+            module: "size_24::outline",
+            feature: "24-outline-face-smile",
+            view: || view! { <FaceSmile /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "film",
+            component: "Film",
+            module: "size_24::outline",
+            feature: "24-outline-film",
+            view: || view! { <Film /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "finger-print",
+            component: "FingerPrint",
+            module: "size_24::outline",
+            feature: "24-outline-finger-print",
+            view: || view! { <FingerPrint /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "fire",
+            component: "Fire",
+            module: "size_24::outline",
+            feature: "24-outline-fire",
+            view: || view! { <Fire /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "flag",
+            component: "Flag",
+            module: "size_24::outline",
+            feature: "24-outline-flag",
+            view: || view! { <Flag /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder-arrow-down",
+            component: "FolderArrowDown",
+            module: "size_24::outline",
+            feature: "24-outline-folder-arrow-down",
+            view: || view! { <FolderArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder-minus",
+            component: "FolderMinus",
+            module: "size_24::outline",
+            feature: "24-outline-folder-minus",
+            view: || view! { <FolderMinus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder-open",
+            component: "FolderOpen",
+            module: "size_24::outline",
+            feature: "24-outline-folder-open",
+            view: || view! { <FolderOpen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder-plus",
+            component: "FolderPlus",
+            module: "size_24::outline",
+            feature: "24-outline-folder-plus",
+            view: || view! { <FolderPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder",
+            component: "Folder",
+            module: "size_24::outline",
+            feature: "24-outline-folder",
+            view: || view! { <Folder /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "forward",
+            component: "Forward",
+            module: "size_24::outline",
+            feature: "24-outline-forward",
+            view: || view! { <Forward /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "funnel",
+            component: "Funnel",
+            module: "size_24::outline",
+            feature: "24-outline-funnel",
+            view: || view! { <Funnel /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "gif",
+            component: "Gif",
+            module: "size_24::outline",
+            feature: "24-outline-gif",
+            view: || view! { <Gif /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "gift-top",
+            component: "GiftTop",
+            module: "size_24::outline",
+            feature: "24-outline-gift-top",
+            view: || view! { <GiftTop /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "gift",
+            component: "Gift",
+            module: "size_24::outline",
+            feature: "24-outline-gift",
+            view: || view! { <Gift /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "globe-alt",
+            component: "GlobeAlt",
+            module: "size_24::outline",
+            feature: "24-outline-globe-alt",
+            view: || view! { <GlobeAlt /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "globe-americas",
+            component: "GlobeAmericas",
+            module: "size_24::outline",
+            feature: "24-outline-globe-americas",
+            view: || view! { <GlobeAmericas /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "globe-asia-australia",
+            component: "GlobeAsiaAustralia",
+            module: "size_24::outline",
+            feature: "24-outline-globe-asia-australia",
+            view: || view! { <GlobeAsiaAustralia /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "globe-europe-africa",
+            component: "GlobeEuropeAfrica",
+            module: "size_24::outline",
+            feature: "24-outline-globe-europe-africa",
+            view: || view! { <GlobeEuropeAfrica /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "h1",
+            component: "H1",
+            module: "size_24::outline",
+            feature: "24-outline-h1",
+            view: || view! { <H1 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "h2",
+            component: "H2",
+            module: "size_24::outline",
+            feature: "24-outline-h2",
+            view: || view! { <H2 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "h3",
+            component: "H3",
+            module: "size_24::outline",
+            feature: "24-outline-h3",
+            view: || view! { <H3 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "hand-raised",
+            component: "HandRaised",
+            module: "size_24::outline",
+            feature: "24-outline-hand-raised",
+            view: || view! { <HandRaised /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "hand-thumb-down",
+            component: "HandThumbDown",
+            module: "size_24::outline",
+            feature: "24-outline-hand-thumb-down",
+            view: || view! { <HandThumbDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "hand-thumb-up",
+            component: "HandThumbUp",
+            module: "size_24::outline",
+            feature: "24-outline-hand-thumb-up",
+            view: || view! { <HandThumbUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "hashtag",
+            component: "Hashtag",
+            module: "size_24::outline",
+            feature: "24-outline-hashtag",
+            view: || view! { <Hashtag /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "heart",
+            component: "Heart",
+            module: "size_24::outline",
+            feature: "24-outline-heart",
+            view: || view! { <Heart /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "home-modern",
+            component: "HomeModern",
+            module: "size_24::outline",
+            feature: "24-outline-home-modern",
+            view: || view! { <HomeModern /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "home",
+            component: "Home",
+            module: "size_24::outline",
+            feature: "24-outline-home",
+            view: || view! { <Home /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "identification",
+            component: "Identification",
+            module: "size_24::outline",
+            feature: "24-outline-identification",
+            view: || view! { <Identification /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "inbox-arrow-down",
+            component: "InboxArrowDown",
+            module: "size_24::outline",
+            feature: "24-outline-inbox-arrow-down",
+            view: || view! { <InboxArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "inbox-stack",
+            component: "InboxStack",
+            module: "size_24::outline",
+            feature: "24-outline-inbox-stack",
+            view: || view! { <InboxStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "inbox",
+            component: "Inbox",
+            module: "size_24::outline",
+            feature: "24-outline-inbox",
+            view: || view! { <Inbox /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "information-circle",
+            component: "InformationCircle",
+            module: "size_24::outline",
+            feature: "24-outline-information-circle",
+            view: || view! { <InformationCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "italic",
+            component: "Italic",
+            module: "size_24::outline",
+            feature: "24-outline-italic",
+            view: || view! { <Italic /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "key",
+            component: "Key",
+            module: "size_24::outline",
+            feature: "24-outline-key",
+            view: || view! { <Key /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "language",
+            component: "Language",
+            module: "size_24::outline",
+            feature: "24-outline-language",
+            view: || view! { <Language /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "lifebuoy",
+            component: "Lifebuoy",
+            module: "size_24::outline",
+            feature: "24-outline-lifebuoy",
+            view: || view! { <Lifebuoy /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "light-bulb",
+            component: "LightBulb",
+            module: "size_24::outline",
+            feature: "24-outline-light-bulb",
+            view: || view! { <LightBulb /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "link-slash",
+            component: "LinkSlash",
+            module: "size_24::outline",
+            feature: "24-outline-link-slash",
+            view: || view! { <LinkSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "link",
+            component: "Link",
+            module: "size_24::outline",
+            feature: "24-outline-link",
+            view: || view! { <Link /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "list-bullet",
+            component: "ListBullet",
+            module: "size_24::outline",
+            feature: "24-outline-list-bullet",
+            view: || view! { <ListBullet /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "lock-closed",
+            component: "LockClosed",
+            module: "size_24::outline",
+            feature: "24-outline-lock-closed",
+            view: || view! { <LockClosed /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "lock-open",
+            component: "LockOpen",
+            module: "size_24::outline",
+            feature: "24-outline-lock-open",
+            view: || view! { <LockOpen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "magnifying-glass-circle",
+            component: "MagnifyingGlassCircle",
+            module: "size_24::outline",
+            feature: "24-outline-magnifying-glass-circle",
+            view: || view! { <MagnifyingGlassCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "magnifying-glass-minus",
+            component: "MagnifyingGlassMinus",
+            module: "size_24::outline",
+            feature: "24-outline-magnifying-glass-minus",
+            view: || view! { <MagnifyingGlassMinus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "magnifying-glass-plus",
+            component: "MagnifyingGlassPlus",
+            module: "size_24::outline",
+            feature: "24-outline-magnifying-glass-plus",
+            view: || view! { <MagnifyingGlassPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "magnifying-glass",
+            component: "MagnifyingGlass",
+            module: "size_24::outline",
+            feature: "24-outline-magnifying-glass",
+            view: || view! { <MagnifyingGlass /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "map-pin",
+            component: "MapPin",
+            module: "size_24::outline",
+            feature: "24-outline-map-pin",
+            view: || view! { <MapPin /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "map",
+            component: "Map",
+            module: "size_24::outline",
+            feature: "24-outline-map",
+            view: || view! { <Map /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "megaphone",
+            component: "Megaphone",
+            module: "size_24::outline",
+            feature: "24-outline-megaphone",
+            view: || view! { <Megaphone /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "microphone",
+            component: "Microphone",
+            module: "size_24::outline",
+            feature: "24-outline-microphone",
+            view: || view! { <Microphone /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "minus-circle",
+            component: "MinusCircle",
+            module: "size_24::outline",
+            feature: "24-outline-minus-circle",
+            view: || view! { <MinusCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "minus-small",
+            component: "MinusSmall",
+            module: "size_24::outline",
+            feature: "24-outline-minus-small",
+            view: || view! { <MinusSmall /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "minus",
+            component: "Minus",
+            module: "size_24::outline",
+            feature: "24-outline-minus",
+            view: || view! { <Minus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "moon",
+            component: "Moon",
+            module: "size_24::outline",
+            feature: "24-outline-moon",
+            view: || view! { <Moon /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "musical-note",
+            component: "MusicalNote",
+            module: "size_24::outline",
+            feature: "24-outline-musical-note",
+            view: || view! { <MusicalNote /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "newspaper",
+            component: "Newspaper",
+            module: "size_24::outline",
+            feature: "24-outline-newspaper",
+            view: || view! { <Newspaper /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "no-symbol",
+            component: "NoSymbol",
+            module: "size_24::outline",
+            feature: "24-outline-no-symbol",
+            view: || view! { <NoSymbol /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "numbered-list",
+            component: "NumberedList",
+            module: "size_24::outline",
+            feature: "24-outline-numbered-list",
+            view: || view! { <NumberedList /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "paint-brush",
+            component: "PaintBrush",
+            module: "size_24::outline",
+            feature: "24-outline-paint-brush",
+            view: || view! { <PaintBrush /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "paper-airplane",
+            component: "PaperAirplane",
+            module: "size_24::outline",
+            feature: "24-outline-paper-airplane",
+            view: || view! { <PaperAirplane /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "paper-clip",
+            component: "PaperClip",
+            module: "size_24::outline",
+            feature: "24-outline-paper-clip",
+            view: || view! { <PaperClip /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "pause-circle",
+            component: "PauseCircle",
+            module: "size_24::outline",
+            feature: "24-outline-pause-circle",
+            view: || view! { <PauseCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "pause",
+            component: "Pause",
+            module: "size_24::outline",
+            feature: "24-outline-pause",
+            view: || view! { <Pause /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "pencil-square",
+            component: "PencilSquare",
+            module: "size_24::outline",
+            feature: "24-outline-pencil-square",
+            view: || view! { <PencilSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "pencil",
+            component: "Pencil",
+            module: "size_24::outline",
+            feature: "24-outline-pencil",
+            view: || view! { <Pencil /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "percent-badge",
+            component: "PercentBadge",
+            module: "size_24::outline",
+            feature: "24-outline-percent-badge",
+            view: || view! { <PercentBadge /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "phone-arrow-down-left",
+            component: "PhoneArrowDownLeft",
+            module: "size_24::outline",
+            feature: "24-outline-phone-arrow-down-left",
+            view: || view! { <PhoneArrowDownLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "phone-arrow-up-right",
+            component: "PhoneArrowUpRight",
+            module: "size_24::outline",
+            feature: "24-outline-phone-arrow-up-right",
+            view: || view! { <PhoneArrowUpRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "phone-x-mark",
+            component: "PhoneXMark",
+            module: "size_24::outline",
+            feature: "24-outline-phone-x-mark",
+            view: || view! { <PhoneXMark /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "phone",
+            component: "Phone",
+            module: "size_24::outline",
+            feature: "24-outline-phone",
+            view: || view! { <Phone /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "photo",
+            component: "Photo",
+            module: "size_24::outline",
+            feature: "24-outline-photo",
+            view: || view! { <Photo /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "play-circle",
+            component: "PlayCircle",
+            module: "size_24::outline",
+            feature: "24-outline-play-circle",
+            view: || view! { <PlayCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "play-pause",
+            component: "PlayPause",
+            module: "size_24::outline",
+            feature: "24-outline-play-pause",
+            view: || view! { <PlayPause /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "play",
+            component: "Play",
+            module: "size_24::outline",
+            feature: "24-outline-play",
+            view: || view! { <Play /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "plus-circle",
+            component: "PlusCircle",
+            module: "size_24::outline",
+            feature: "24-outline-plus-circle",
+            view: || view! { <PlusCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "plus-small",
+            component: "PlusSmall",
+            module: "size_24::outline",
+            feature: "24-outline-plus-small",
+            view: || view! { <PlusSmall /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "plus",
+            component: "Plus",
+            module: "size_24::outline",
+            feature: "24-outline-plus",
+            view: || view! { <Plus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "power",
+            component: "Power",
+            module: "size_24::outline",
+            feature: "24-outline-power",
+            view: || view! { <Power /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "presentation-chart-bar",
+            component: "PresentationChartBar",
+            module: "size_24::outline",
+            feature: "24-outline-presentation-chart-bar",
+            view: || view! { <PresentationChartBar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "presentation-chart-line",
+            component: "PresentationChartLine",
+            module: "size_24::outline",
+            feature: "24-outline-presentation-chart-line",
+            view: || view! { <PresentationChartLine /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "printer",
+            component: "Printer",
+            module: "size_24::outline",
+            feature: "24-outline-printer",
+            view: || view! { <Printer /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "puzzle-piece",
+            component: "PuzzlePiece",
+            module: "size_24::outline",
+            feature: "24-outline-puzzle-piece",
+            view: || view! { <PuzzlePiece /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "qr-code",
+            component: "QrCode",
+            module: "size_24::outline",
+            feature: "24-outline-qr-code",
+            view: || view! { <QrCode /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "question-mark-circle",
+            component: "QuestionMarkCircle",
+            module: "size_24::outline",
+            feature: "24-outline-question-mark-circle",
+            view: || view! { <QuestionMarkCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "queue-list",
+            component: "QueueList",
+            module: "size_24::outline",
+            feature: "24-outline-queue-list",
+            view: || view! { <QueueList /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "radio",
+            component: "Radio",
+            module: "size_24::outline",
+            feature: "24-outline-radio",
+            view: || view! { <Radio /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "receipt-percent",
+            component: "ReceiptPercent",
+            module: "size_24::outline",
+            feature: "24-outline-receipt-percent",
+            view: || view! { <ReceiptPercent /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "receipt-refund",
+            component: "ReceiptRefund",
+            module: "size_24::outline",
+            feature: "24-outline-receipt-refund",
+            view: || view! { <ReceiptRefund /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "rectangle-group",
+            component: "RectangleGroup",
+            module: "size_24::outline",
+            feature: "24-outline-rectangle-group",
+            view: || view! { <RectangleGroup /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "rectangle-stack",
+            component: "RectangleStack",
+            module: "size_24::outline",
+            feature: "24-outline-rectangle-stack",
+            view: || view! { <RectangleStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "rocket-launch",
+            component: "RocketLaunch",
+            module: "size_24::outline",
+            feature: "24-outline-rocket-launch",
+            view: || view! { <RocketLaunch /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "rss",
+            component: "Rss",
+            module: "size_24::outline",
+            feature: "24-outline-rss",
+            view: || view! { <Rss /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "scale",
+            component: "Scale",
+            module: "size_24::outline",
+            feature: "24-outline-scale",
+            view: || view! { <Scale /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "scissors",
+            component: "Scissors",
+            module: "size_24::outline",
+            feature: "24-outline-scissors",
+            view: || view! { <Scissors /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "server-stack",
+            component: "ServerStack",
+            module: "size_24::outline",
+            feature: "24-outline-server-stack",
+            view: || view! { <ServerStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "server",
+            component: "Server",
+            module: "size_24::outline",
+            feature: "24-outline-server",
+            view: || view! { <Server /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "share",
+            component: "Share",
+            module: "size_24::outline",
+            feature: "24-outline-share",
+            view: || view! { <Share /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "shield-check",
+            component: "ShieldCheck",
+            module: "size_24::outline",
+            feature: "24-outline-shield-check",
+            view: || view! { <ShieldCheck /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "shield-exclamation",
+            component: "ShieldExclamation",
+            module: "size_24::outline",
+            feature: "24-outline-shield-exclamation",
+            view: || view! { <ShieldExclamation /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "shopping-bag",
+            component: "ShoppingBag",
+            module: "size_24::outline",
+            feature: "24-outline-shopping-bag",
+            view: || view! { <ShoppingBag /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "shopping-cart",
+            component: "ShoppingCart",
+            module: "size_24::outline",
+            feature: "24-outline-shopping-cart",
+            view: || view! { <ShoppingCart /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "signal-slash",
+            component: "SignalSlash",
+            module: "size_24::outline",
+            feature: "24-outline-signal-slash",
+            view: || view! { <SignalSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "signal",
+            component: "Signal",
+            module: "size_24::outline",
+            feature: "24-outline-signal",
+            view: || view! { <Signal /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "slash",
+            component: "Slash",
+            module: "size_24::outline",
+            feature: "24-outline-slash",
+            view: || view! { <Slash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "sparkles",
+            component: "Sparkles",
+            module: "size_24::outline",
+            feature: "24-outline-sparkles",
+            view: || view! { <Sparkles /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "speaker-wave",
+            component: "SpeakerWave",
+            module: "size_24::outline",
+            feature: "24-outline-speaker-wave",
+            view: || view! { <SpeakerWave /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "speaker-x-mark",
+            component: "SpeakerXMark",
+            module: "size_24::outline",
+            feature: "24-outline-speaker-x-mark",
+            view: || view! { <SpeakerXMark /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "square-2-stack",
+            component: "Square2Stack",
+            module: "size_24::outline",
+            feature: "24-outline-square-2-stack",
+            view: || view! { <Square2Stack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "square-3-stack-3d",
+            component: "Square3Stack3D",
+            module: "size_24::outline",
+            feature: "24-outline-square-3-stack-3d",
+            view: || view! { <Square3Stack3D /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "squares-2x2",
+            component: "Squares2X2",
+            module: "size_24::outline",
+            feature: "24-outline-squares-2x2",
+            view: || view! { <Squares2X2 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "squares-plus",
+            component: "SquaresPlus",
+            module: "size_24::outline",
+            feature: "24-outline-squares-plus",
+            view: || view! { <SquaresPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "star",
+            component: "Star",
+            module: "size_24::outline",
+            feature: "24-outline-star",
+            view: || view! { <Star /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "stop-circle",
+            component: "StopCircle",
+            module: "size_24::outline",
+            feature: "24-outline-stop-circle",
+            view: || view! { <StopCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "stop",
+            component: "Stop",
+            module: "size_24::outline",
+            feature: "24-outline-stop",
+            view: || view! { <Stop /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "strikethrough",
+            component: "Strikethrough",
+            module: "size_24::outline",
+            feature: "24-outline-strikethrough",
+            view: || view! { <Strikethrough /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "sun",
+            component: "Sun",
+            module: "size_24::outline",
+            feature: "24-outline-sun",
+            view: || view! { <Sun /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "swatch",
+            component: "Swatch",
+            module: "size_24::outline",
+            feature: "24-outline-swatch",
+            view: || view! { <Swatch /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "table-cells",
+            component: "TableCells",
+            module: "size_24::outline",
+            feature: "24-outline-table-cells",
+            view: || view! { <TableCells /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "tag",
+            component: "Tag",
+            module: "size_24::outline",
+            feature: "24-outline-tag",
+            view: || view! { <Tag /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "ticket",
+            component: "Ticket",
+            module: "size_24::outline",
+            feature: "24-outline-ticket",
+            view: || view! { <Ticket /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "trash",
+            component: "Trash",
+            module: "size_24::outline",
+            feature: "24-outline-trash",
+            view: || view! { <Trash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "trophy",
+            component: "Trophy",
+            module: "size_24::outline",
+            feature: "24-outline-trophy",
+            view: || view! { <Trophy /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "truck",
+            component: "Truck",
+            module: "size_24::outline",
+            feature: "24-outline-truck",
+            view: || view! { <Truck /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "tv",
+            component: "Tv",
+            module: "size_24::outline",
+            feature: "24-outline-tv",
+            view: || view! { <Tv /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "underline",
+            component: "Underline",
+            module: "size_24::outline",
+            feature: "24-outline-underline",
+            view: || view! { <Underline /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user-circle",
+            component: "UserCircle",
+            module: "size_24::outline",
+            feature: "24-outline-user-circle",
+            view: || view! { <UserCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user-group",
+            component: "UserGroup",
+            module: "size_24::outline",
+            feature: "24-outline-user-group",
+            view: || view! { <UserGroup /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user-minus",
+            component: "UserMinus",
+            module: "size_24::outline",
+            feature: "24-outline-user-minus",
+            view: || view! { <UserMinus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user-plus",
+            component: "UserPlus",
+            module: "size_24::outline",
+            feature: "24-outline-user-plus",
+            view: || view! { <UserPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user",
+            component: "User",
+            module: "size_24::outline",
+            feature: "24-outline-user",
+            view: || view! { <User /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "users",
+            component: "Users",
+            module: "size_24::outline",
+            feature: "24-outline-users",
+            view: || view! { <Users /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "variable",
+            component: "Variable",
+            module: "size_24::outline",
+            feature: "24-outline-variable",
+            view: || view! { <Variable /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "video-camera-slash",
+            component: "VideoCameraSlash",
+            module: "size_24::outline",
+            feature: "24-outline-video-camera-slash",
+            view: || view! { <VideoCameraSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "video-camera",
+            component: "VideoCamera",
+            module: "size_24::outline",
+            feature: "24-outline-video-camera",
+            view: || view! { <VideoCamera /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "view-columns",
+            component: "ViewColumns",
+            module: "size_24::outline",
+            feature: "24-outline-view-columns",
+            view: || view! { <ViewColumns /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "viewfinder-circle",
+            component: "ViewfinderCircle",
+            module: "size_24::outline",
+            feature: "24-outline-viewfinder-circle",
+            view: || view! { <ViewfinderCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "wallet",
+            component: "Wallet",
+            module: "size_24::outline",
+            feature: "24-outline-wallet",
+            view: || view! { <Wallet /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "wifi",
+            component: "Wifi",
+            module: "size_24::outline",
+            feature: "24-outline-wifi",
+            view: || view! { <Wifi /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "window",
+            component: "Window",
+            module: "size_24::outline",
+            feature: "24-outline-window",
+            view: || view! { <Window /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "wrench-screwdriver",
+            component: "WrenchScrewdriver",
+            module: "size_24::outline",
+            feature: "24-outline-wrench-screwdriver",
+            view: || view! { <WrenchScrewdriver /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "wrench",
+            component: "Wrench",
+            module: "size_24::outline",
+            feature: "24-outline-wrench",
+            view: || view! { <Wrench /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "x-circle",
+            component: "XCircle",
+            module: "size_24::outline",
+            feature: "24-outline-x-circle",
+            view: || view! { <XCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "x-mark",
+            component: "XMark",
+            module: "size_24::outline",
+            feature: "24-outline-x-mark",
+            view: || view! { <XMark /> }.into_any(),
+        },
     ]
 }
 
-pub fn size_16_solid() -> Vec<impl IntoView> {
-    use leptos_heroicons::size_16::solid::*;
+pub fn size_24_solid() -> Vec<GalleryIcon> {
+    use leptos_heroicons::size_24::solid::*;
 
     vec![
-        view! { <Lifebuoy /> }.into_any(),
-        view! { <Equals /> }.into_any(),
-        view! { <Truck /> }.into_any(),
-        view! { <LightBulb /> }.into_any(),
-        view! { <PhoneArrowDownLeft /> }.into_any(),
-        view! { <CurrencyEuro /> }.into_any(),
-        view! { <Moon /> }.into_any(),
-        view! { <ComputerDesktop /> }.into_any(),
-        view! { <Clipboard /> }.into_any(),
-        view! { <ArchiveBoxArrowDown /> }.into_any(),
-        view! { <ArrowTurnRightDown /> }.into_any(),
-        view! { <ArrowUp /> }.into_any(),
-        view! { <Backward /> }.into_any(),
-        view! { <ChatBubbleOvalLeft /> }.into_any(),
-        view! { <Pause /> }.into_any(),
-        view! { <Inbox /> }.into_any(),
-        view! { <DocumentCurrencyDollar /> }.into_any(),
-        view! { <ArrowsRightLeft /> }.into_any(),
-        view! { <Underline /> }.into_any(),
-        view! { <Beaker /> }.into_any(),
-        view! { <ChatBubbleOvalLeftEllipsis /> }.into_any(),
-        view! { <Fire /> }.into_any(),
-        view! { <Battery50 /> }.into_any(),
-        view! { <GlobeAlt /> }.into_any(),
-        view! { <Identification /> }.into_any(),
-        view! { <ChevronDoubleLeft /> }.into_any(),
-        view! { <Printer /> }.into_any(),
-        view! { <EllipsisVertical /> }.into_any(),
-        view! { <Tv /> }.into_any(),
-        view! { <Cog8Tooth /> }.into_any(),
-        view! { <DocumentCurrencyYen /> }.into_any(),
-        view! { <AdjustmentsVertical /> }.into_any(),
-        view! { <Wrench /> }.into_any(),
-        view! { <PencilSquare /> }.into_any(),
-        view! { <BoltSlash /> }.into_any(),
-        view! { <Phone /> }.into_any(),
-        view! { <Cake /> }.into_any(),
-        view! { <HandRaised /> }.into_any(),
-        view! { <PhoneArrowUpRight /> }.into_any(),
-        view! { <ArrowDownRight /> }.into_any(),
-        view! { <Rss /> }.into_any(),
-        view! { <BookmarkSlash /> }.into_any(),
-        view! { <BuildingStorefront /> }.into_any(),
-        view! { <DocumentCurrencyBangladeshi /> }.into_any(),
-        view! { <ListBullet /> }.into_any(),
-        view! { <Radio /> }.into_any(),
-        view! { <UserPlus /> }.into_any(),
-        view! { <DocumentCurrencyEuro /> }.into_any(),
-        view! { <ArrowUpOnSquare /> }.into_any(),
-        view! { <Square3Stack3D /> }.into_any(),
-        view! { <ArrowRightStartOnRectangle /> }.into_any(),
-        view! { <LockClosed /> }.into_any(),
-        view! { <Check /> }.into_any(),
-        view! { <Forward /> }.into_any(),
-        view! { <ArrowTopRightOnSquare /> }.into_any(),
-        view! { <RectangleGroup /> }.into_any(),
-        view! { <AdjustmentsHorizontal /> }.into_any(),
-        view! { <ArrowTurnLeftUp /> }.into_any(),
-        view! { <Document /> }.into_any(),
-        view! { <CalendarDays /> }.into_any(),
-        view! { <Tag /> }.into_any(),
-        view! { <UserCircle /> }.into_any(),
-        view! { <ChevronDoubleDown /> }.into_any(),
-        view! { <MagnifyingGlass /> }.into_any(),
-        view! { <NoSymbol /> }.into_any(),
-        view! { <BellSnooze /> }.into_any(),
-        view! { <Plus /> }.into_any(),
-        view! { <FingerPrint /> }.into_any(),
-        view! { <Scissors /> }.into_any(),
-        view! { <SpeakerXMark /> }.into_any(),
-        view! { <ArrowTurnDownRight /> }.into_any(),
-        view! { <Ticket /> }.into_any(),
-        view! { <ShieldCheck /> }.into_any(),
-        view! { <Envelope /> }.into_any(),
-        view! { <Sparkles /> }.into_any(),
-        view! { <Battery100 /> }.into_any(),
-        view! { <ArrowUpOnSquareStack /> }.into_any(),
-        view! { <XCircle /> }.into_any(),
-        view! { <StopCircle /> }.into_any(),
-        view! { <ArrowRightEndOnRectangle /> }.into_any(),
-        view! { <ChevronDoubleRight /> }.into_any(),
-        view! { <CalendarDateRange /> }.into_any(),
-        view! { <Cube /> }.into_any(),
-        view! { <BellSlash /> }.into_any(),
-        view! { <DocumentArrowUp /> }.into_any(),
-        view! { <ArrowLeftEndOnRectangle /> }.into_any(),
-        view! { <Strikethrough /> }.into_any(),
-        view! { <ArrowUpRight /> }.into_any(),
-        view! { <Photo /> }.into_any(),
-        view! { <Bars3BottomLeft /> }.into_any(),
-        view! { <QueueList /> }.into_any(),
-        view! { <CheckBadge /> }.into_any(),
-        view! { <CurrencyPound /> }.into_any(),
-        view! { <ChatBubbleLeft /> }.into_any(),
-        view! { <EllipsisHorizontal /> }.into_any(),
-        view! { <Eye /> }.into_any(),
-        view! { <CommandLine /> }.into_any(),
-        view! { <Backspace /> }.into_any(),
-        view! { <HandThumbDown /> }.into_any(),
-        view! { <User /> }.into_any(),
-        view! { <Slash /> }.into_any(),
-        view! { <ChatBubbleLeftEllipsis /> }.into_any(),
-        view! { <CreditCard /> }.into_any(),
-        view! { <BarsArrowDown /> }.into_any(),
-        view! { <SignalSlash /> }.into_any(),
-        view! { <ShoppingCart /> }.into_any(),
-        view! { <DocumentCheck /> }.into_any(),
-        view! { <ArrowDownOnSquare /> }.into_any(),
-        view! { <MagnifyingGlassCircle /> }.into_any(),
-        view! { <PaperAirplane /> }.into_any(),
-        view! { <VideoCameraSlash /> }.into_any(),
-        view! { <Bars3BottomRight /> }.into_any(),
-        view! { <Scale /> }.into_any(),
-        view! { <WrenchScrewdriver /> }.into_any(),
-        view! { <ChevronDoubleUp /> }.into_any(),
-        view! { <EyeDropper /> }.into_any(),
-        view! { <UserMinus /> }.into_any(),
-        view! { <ArrowUturnLeft /> }.into_any(),
-        view! { <LockOpen /> }.into_any(),
-        view! { <ArrowLongUp /> }.into_any(),
-        view! { <BugAnt /> }.into_any(),
-        view! { <Signal /> }.into_any(),
-        view! { <PuzzlePiece /> }.into_any(),
-        view! { <Folder /> }.into_any(),
-        view! { <Cog6Tooth /> }.into_any(),
-        view! { <Bold /> }.into_any(),
-        view! { <BarsArrowUp /> }.into_any(),
-        view! { <DocumentText /> }.into_any(),
-        view! { <CurrencyYen /> }.into_any(),
-        view! { <CodeBracket /> }.into_any(),
-        view! { <PaperClip /> }.into_any(),
-        view! { <EllipsisHorizontalCircle /> }.into_any(),
-        view! { <Calendar /> }.into_any(),
-        view! { <ArrowDownOnSquareStack /> }.into_any(),
-        view! { <ExclamationTriangle /> }.into_any(),
-        view! { <ArchiveBox /> }.into_any(),
-        view! { <ArrowUpLeft /> }.into_any(),
-        view! { <Briefcase /> }.into_any(),
-        view! { <ClipboardDocument /> }.into_any(),
-        view! { <BellAlert /> }.into_any(),
-        view! { <Key /> }.into_any(),
-        view! { <MapPin /> }.into_any(),
-        view! { <ArrowTrendingUp /> }.into_any(),
-        view! { <Wallet /> }.into_any(),
-        view! { <MusicalNote /> }.into_any(),
-        view! { <Calculator /> }.into_any(),
-        view! { <Battery0 /> }.into_any(),
-        view! { <CpuChip /> }.into_any(),
-        view! { <ArrowUturnDown /> }.into_any(),
-        view! { <H1 /> }.into_any(),
-        view! { <BookmarkSquare /> }.into_any(),
-        view! { <ArrowUpTray /> }.into_any(),
-        view! { <Cloud /> }.into_any(),
-        view! { <DocumentCurrencyPound /> }.into_any(),
-        view! { <Camera /> }.into_any(),
-        view! { <ArrowsPointingIn /> }.into_any(),
-        view! { <GlobeAsiaAustralia /> }.into_any(),
-        view! { <Gift /> }.into_any(),
-        view! { <CodeBracketSquare /> }.into_any(),
-        view! { <GiftTop /> }.into_any(),
-        view! { <MagnifyingGlassPlus /> }.into_any(),
-        view! { <ClipboardDocumentList /> }.into_any(),
-        view! { <ArrowsUpDown /> }.into_any(),
-        view! { <TableCells /> }.into_any(),
-        view! { <ViewColumns /> }.into_any(),
-        view! { <ArrowPathRoundedSquare /> }.into_any(),
-        view! { <PlusCircle /> }.into_any(),
-        view! { <CubeTransparent /> }.into_any(),
-        view! { <ServerStack /> }.into_any(),
-        view! { <DeviceTablet /> }.into_any(),
-        view! { <Square2Stack /> }.into_any(),
-        view! { <Stop /> }.into_any(),
-        view! { <PaintBrush /> }.into_any(),
-        view! { <MagnifyingGlassMinus /> }.into_any(),
-        view! { <Italic /> }.into_any(),
-        view! { <CursorArrowRipple /> }.into_any(),
-        view! { <ArrowPath /> }.into_any(),
-        view! { <RectangleStack /> }.into_any(),
-        view! { <LinkSlash /> }.into_any(),
-        view! { <ArrowUturnUp /> }.into_any(),
-        view! { <InformationCircle /> }.into_any(),
-        view! { <ArrowLongLeft /> }.into_any(),
-        view! { <ArrowDownLeft /> }.into_any(),
-        view! { <ChartBarSquare /> }.into_any(),
-        view! { <Power /> }.into_any(),
-        view! { <Wifi /> }.into_any(),
-        view! { <Bars3CenterLeft /> }.into_any(),
-        view! { <Language /> }.into_any(),
-        view! { <Play /> }.into_any(),
-        view! { <ChatBubbleLeftRight /> }.into_any(),
-        view! { <Sun /> }.into_any(),
-        view! { <ArrowDownTray /> }.into_any(),
-        view! { <CursorArrowRays /> }.into_any(),
-        view! { <FaceSmile /> }.into_any(),
-        view! { <Share /> }.into_any(),
-        view! { <NumberedList /> }.into_any(),
-        view! { <H2 /> }.into_any(),
-        view! { <ArrowLeftCircle /> }.into_any(),
-        view! { <ArrowTurnLeftDown /> }.into_any(),
-        view! { <UserGroup /> }.into_any(),
-        view! { <PhoneXMark /> }.into_any(),
-        view! { <ArchiveBoxXMark /> }.into_any(),
-        view! { <DocumentDuplicate /> }.into_any(),
-        view! { <DevicePhoneMobile /> }.into_any(),
-        view! { <VideoCamera /> }.into_any(),
-        view! { <QrCode /> }.into_any(),
-        view! { <Clock /> }.into_any(),
-        view! { <Map /> }.into_any(),
-        view! { <ArrowLongDown /> }.into_any(),
-        view! { <Minus /> }.into_any(),
-        view! { <Squares2X2 /> }.into_any(),
-        view! { <InboxStack /> }.into_any(),
-        view! { <EnvelopeOpen /> }.into_any(),
-        view! { <Trash /> }.into_any(),
-        view! { <PresentationChartLine /> }.into_any(),
-        view! { <ChatBubbleBottomCenterText /> }.into_any(),
-        view! { <ArrowDownCircle /> }.into_any(),
-        view! { <RocketLaunch /> }.into_any(),
-        view! { <Microphone /> }.into_any(),
-        view! { <ClipboardDocumentCheck /> }.into_any(),
-        view! { <ReceiptRefund /> }.into_any(),
-        view! { <ArrowRight /> }.into_any(),
-        view! { <ChartPie /> }.into_any(),
-        view! { <XMark /> }.into_any(),
-        view! { <ChevronUp /> }.into_any(),
-        view! { <Bars2 /> }.into_any(),
-        view! { <ArrowTurnDownLeft /> }.into_any(),
-        view! { <MinusCircle /> }.into_any(),
-        view! { <HomeModern /> }.into_any(),
-        view! { <Swatch /> }.into_any(),
-        view! { <ShoppingBag /> }.into_any(),
-        view! { <PauseCircle /> }.into_any(),
-        view! { <PercentBadge /> }.into_any(),
-        view! { <CurrencyBangladeshi /> }.into_any(),
-        view! { <ArrowRightCircle /> }.into_any(),
-        view! { <CircleStack /> }.into_any(),
-        view! { <DocumentArrowDown /> }.into_any(),
-        view! { <HandThumbUp /> }.into_any(),
-        view! { <Gif /> }.into_any(),
-        view! { <Cog /> }.into_any(),
-        view! { <ArrowLongRight /> }.into_any(),
-        view! { <Star /> }.into_any(),
-        view! { <Bookmark /> }.into_any(),
-        view! { <FolderOpen /> }.into_any(),
-        view! { <ChatBubbleBottomCenter /> }.into_any(),
-        view! { <SquaresPlus /> }.into_any(),
-        view! { <AtSymbol /> }.into_any(),
-        view! { <SpeakerWave /> }.into_any(),
-        view! { <Hashtag /> }.into_any(),
-        view! { <FolderPlus /> }.into_any(),
-        view! { <ViewfinderCircle /> }.into_any(),
-        view! { <ChevronRight /> }.into_any(),
-        view! { <Bolt /> }.into_any(),
-        view! { <InboxArrowDown /> }.into_any(),
-        view! { <Heart /> }.into_any(),
-        view! { <H3 /> }.into_any(),
-        view! { <ArrowLeftStartOnRectangle /> }.into_any(),
-        view! { <GlobeAmericas /> }.into_any(),
-        view! { <Link /> }.into_any(),
-        view! { <AcademicCap /> }.into_any(),
-        view! { <FolderMinus /> }.into_any(),
-        view! { <Banknotes /> }.into_any(),
-        view! { <Pencil /> }.into_any(),
-        view! { <PlayPause /> }.into_any(),
-        view! { <DocumentCurrencyRupee /> }.into_any(),
-        view! { <ArrowDown /> }.into_any(),
-        view! { <ArrowTurnRightUp /> }.into_any(),
-        view! { <ExclamationCircle /> }.into_any(),
-        view! { <Window /> }.into_any(),
-        view! { <CloudArrowUp /> }.into_any(),
-        view! { <ArrowTurnUpLeft /> }.into_any(),
-        view! { <FaceFrown /> }.into_any(),
-        view! { <DocumentPlus /> }.into_any(),
-        view! { <ChevronLeft /> }.into_any(),
-        view! { <DocumentMinus /> }.into_any(),
-        view! { <QuestionMarkCircle /> }.into_any(),
-        view! { <Home /> }.into_any(),
-        view! { <BuildingOffice2 /> }.into_any(),
-        view! { <BuildingOffice /> }.into_any(),
-        view! { <ArrowUpCircle /> }.into_any(),
-        view! { <ChevronUpDown /> }.into_any(),
-        view! { <Variable /> }.into_any(),
-        view! { <DocumentMagnifyingGlass /> }.into_any(),
-        view! { <Funnel /> }.into_any(),
-        view! { <EyeSlash /> }.into_any(),
-        view! { <BookOpen /> }.into_any(),
-        view! { <CheckCircle /> }.into_any(),
-        view! { <ArrowsPointingOut /> }.into_any(),
-        view! { <CurrencyRupee /> }.into_any(),
-        view! { <ArrowUturnRight /> }.into_any(),
-        view! { <Users /> }.into_any(),
-        view! { <BuildingLibrary /> }.into_any(),
-        view! { <Film /> }.into_any(),
-        view! { <ReceiptPercent /> }.into_any(),
-        view! { <FolderArrowDown /> }.into_any(),
-        view! { <ChartBar /> }.into_any(),
-        view! { <Bell /> }.into_any(),
-        view! { <ChevronDown /> }.into_any(),
-        view! { <Bars3 /> }.into_any(),
-        view! { <PresentationChartBar /> }.into_any(),
-        view! { <Flag /> }.into_any(),
-        view! { <CloudArrowDown /> }.into_any(),
-        view! { <Bars4 /> }.into_any(),
-        view! { <Server /> }.into_any(),
-        view! { <Newspaper /> }.into_any(),
-        view! { <DocumentChartBar /> }.into_any(),
-        view! { <GlobeEuropeAfrica /> }.into_any(),
-        view! { <Trophy /> }.into_any(),
-        view! { <ShieldExclamation /> }.into_any(),
-        view! { <ArrowLeft /> }.into_any(),
-        view! { <Megaphone /> }.into_any(),
-        view! { <ArrowTurnUpRight /> }.into_any(),
-        view! { <ArrowTrendingDown /> }.into_any(),
-        view! { <PlayCircle /> }.into_any(),
-        view! { <CurrencyDollar /> }.into_any(),
-        view! { <Divide /> }.into_any(),
+        GalleryIcon {
+            name: "academic-cap",
+            component: "AcademicCap",
+            module: "size_24::solid",
+            feature: "24-solid-academic-cap",
+            view: || view! { <AcademicCap /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "adjustments-horizontal",
+            component: "AdjustmentsHorizontal",
+            module: "size_24::solid",
+            feature: "24-solid-adjustments-horizontal",
+            view: || view! { <AdjustmentsHorizontal /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "adjustments-vertical",
+            component: "AdjustmentsVertical",
+            module: "size_24::solid",
+            feature: "24-solid-adjustments-vertical",
+            view: || view! { <AdjustmentsVertical /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "archive-box-arrow-down",
+            component: "ArchiveBoxArrowDown",
+            module: "size_24::solid",
+            feature: "24-solid-archive-box-arrow-down",
+            view: || view! { <ArchiveBoxArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "archive-box-x-mark",
+            component: "ArchiveBoxXMark",
+            module: "size_24::solid",
+            feature: "24-solid-archive-box-x-mark",
+            view: || view! { <ArchiveBoxXMark /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "archive-box",
+            component: "ArchiveBox",
+            module: "size_24::solid",
+            feature: "24-solid-archive-box",
+            view: || view! { <ArchiveBox /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-circle",
+            component: "ArrowDownCircle",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-down-circle",
+            view: || view! { <ArrowDownCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-left",
+            component: "ArrowDownLeft",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-down-left",
+            view: || view! { <ArrowDownLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-on-square-stack",
+            component: "ArrowDownOnSquareStack",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-down-on-square-stack",
+            view: || view! { <ArrowDownOnSquareStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-on-square",
+            component: "ArrowDownOnSquare",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-down-on-square",
+            view: || view! { <ArrowDownOnSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-right",
+            component: "ArrowDownRight",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-down-right",
+            view: || view! { <ArrowDownRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down-tray",
+            component: "ArrowDownTray",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-down-tray",
+            view: || view! { <ArrowDownTray /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-down",
+            component: "ArrowDown",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-down",
+            view: || view! { <ArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left-circle",
+            component: "ArrowLeftCircle",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-left-circle",
+            view: || view! { <ArrowLeftCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left-end-on-rectangle",
+            component: "ArrowLeftEndOnRectangle",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-left-end-on-rectangle",
+            view: || view! { <ArrowLeftEndOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left-on-rectangle",
+            component: "ArrowLeftOnRectangle",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-left-on-rectangle",
+            view: || view! { <ArrowLeftOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left-start-on-rectangle",
+            component: "ArrowLeftStartOnRectangle",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-left-start-on-rectangle",
+            view: || view! { <ArrowLeftStartOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-left",
+            component: "ArrowLeft",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-left",
+            view: || view! { <ArrowLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-long-down",
+            component: "ArrowLongDown",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-long-down",
+            view: || view! { <ArrowLongDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-long-left",
+            component: "ArrowLongLeft",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-long-left",
+            view: || view! { <ArrowLongLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-long-right",
+            component: "ArrowLongRight",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-long-right",
+            view: || view! { <ArrowLongRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-long-up",
+            component: "ArrowLongUp",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-long-up",
+            view: || view! { <ArrowLongUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-path-rounded-square",
+            component: "ArrowPathRoundedSquare",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-path-rounded-square",
+            view: || view! { <ArrowPathRoundedSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-path",
+            component: "ArrowPath",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-path",
+            view: || view! { <ArrowPath /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right-circle",
+            component: "ArrowRightCircle",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-right-circle",
+            view: || view! { <ArrowRightCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right-end-on-rectangle",
+            component: "ArrowRightEndOnRectangle",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-right-end-on-rectangle",
+            view: || view! { <ArrowRightEndOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right-on-rectangle",
+            component: "ArrowRightOnRectangle",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-right-on-rectangle",
+            view: || view! { <ArrowRightOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right-start-on-rectangle",
+            component: "ArrowRightStartOnRectangle",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-right-start-on-rectangle",
+            view: || view! { <ArrowRightStartOnRectangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-right",
+            component: "ArrowRight",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-right",
+            view: || view! { <ArrowRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-small-down",
+            component: "ArrowSmallDown",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-small-down",
+            view: || view! { <ArrowSmallDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-small-left",
+            component: "ArrowSmallLeft",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-small-left",
+            view: || view! { <ArrowSmallLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-small-right",
+            component: "ArrowSmallRight",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-small-right",
+            view: || view! { <ArrowSmallRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-small-up",
+            component: "ArrowSmallUp",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-small-up",
+            view: || view! { <ArrowSmallUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-top-right-on-square",
+            component: "ArrowTopRightOnSquare",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-top-right-on-square",
+            view: || view! { <ArrowTopRightOnSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-trending-down",
+            component: "ArrowTrendingDown",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-trending-down",
+            view: || view! { <ArrowTrendingDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-trending-up",
+            component: "ArrowTrendingUp",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-trending-up",
+            view: || view! { <ArrowTrendingUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-down-left",
+            component: "ArrowTurnDownLeft",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-turn-down-left",
+            view: || view! { <ArrowTurnDownLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-down-right",
+            component: "ArrowTurnDownRight",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-turn-down-right",
+            view: || view! { <ArrowTurnDownRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-left-down",
+            component: "ArrowTurnLeftDown",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-turn-left-down",
+            view: || view! { <ArrowTurnLeftDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-left-up",
+            component: "ArrowTurnLeftUp",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-turn-left-up",
+            view: || view! { <ArrowTurnLeftUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-right-down",
+            component: "ArrowTurnRightDown",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-turn-right-down",
+            view: || view! { <ArrowTurnRightDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-right-up",
+            component: "ArrowTurnRightUp",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-turn-right-up",
+            view: || view! { <ArrowTurnRightUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-up-left",
+            component: "ArrowTurnUpLeft",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-turn-up-left",
+            view: || view! { <ArrowTurnUpLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-turn-up-right",
+            component: "ArrowTurnUpRight",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-turn-up-right",
+            view: || view! { <ArrowTurnUpRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-circle",
+            component: "ArrowUpCircle",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-up-circle",
+            view: || view! { <ArrowUpCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-left",
+            component: "ArrowUpLeft",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-up-left",
+            view: || view! { <ArrowUpLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-on-square-stack",
+            component: "ArrowUpOnSquareStack",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-up-on-square-stack",
+            view: || view! { <ArrowUpOnSquareStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-on-square",
+            component: "ArrowUpOnSquare",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-up-on-square",
+            view: || view! { <ArrowUpOnSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-right",
+            component: "ArrowUpRight",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-up-right",
+            view: || view! { <ArrowUpRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up-tray",
+            component: "ArrowUpTray",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-up-tray",
+            view: || view! { <ArrowUpTray /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-up",
+            component: "ArrowUp",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-up",
+            view: || view! { <ArrowUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-uturn-down",
+            component: "ArrowUturnDown",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-uturn-down",
+            view: || view! { <ArrowUturnDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-uturn-left",
+            component: "ArrowUturnLeft",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-uturn-left",
+            view: || view! { <ArrowUturnLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-uturn-right",
+            component: "ArrowUturnRight",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-uturn-right",
+            view: || view! { <ArrowUturnRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrow-uturn-up",
+            component: "ArrowUturnUp",
+            module: "size_24::solid",
+            feature: "24-solid-arrow-uturn-up",
+            view: || view! { <ArrowUturnUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrows-pointing-in",
+            component: "ArrowsPointingIn",
+            module: "size_24::solid",
+            feature: "24-solid-arrows-pointing-in",
+            view: || view! { <ArrowsPointingIn /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrows-pointing-out",
+            component: "ArrowsPointingOut",
+            module: "size_24::solid",
+            feature: "24-solid-arrows-pointing-out",
+            view: || view! { <ArrowsPointingOut /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrows-right-left",
+            component: "ArrowsRightLeft",
+            module: "size_24::solid",
+            feature: "24-solid-arrows-right-left",
+            view: || view! { <ArrowsRightLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "arrows-up-down",
+            component: "ArrowsUpDown",
+            module: "size_24::solid",
+            feature: "24-solid-arrows-up-down",
+            view: || view! { <ArrowsUpDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "at-symbol",
+            component: "AtSymbol",
+            module: "size_24::solid",
+            feature: "24-solid-at-symbol",
+            view: || view! { <AtSymbol /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "backspace",
+            component: "Backspace",
+            module: "size_24::solid",
+            feature: "24-solid-backspace",
+            view: || view! { <Backspace /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "backward",
+            component: "Backward",
+            module: "size_24::solid",
+            feature: "24-solid-backward",
+            view: || view! { <Backward /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "banknotes",
+            component: "Banknotes",
+            module: "size_24::solid",
+            feature: "24-solid-banknotes",
+            view: || view! { <Banknotes /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-2",
+            component: "Bars2",
+            module: "size_24::solid",
+            feature: "24-solid-bars-2",
+            view: || view! { <Bars2 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-3-bottom-left",
+            component: "Bars3BottomLeft",
+            module: "size_24::solid",
+            feature: "24-solid-bars-3-bottom-left",
+            view: || view! { <Bars3BottomLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-3-bottom-right",
+            component: "Bars3BottomRight",
+            module: "size_24::solid",
+            feature: "24-solid-bars-3-bottom-right",
+            view: || view! { <Bars3BottomRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-3-center-left",
+            component: "Bars3CenterLeft",
+            module: "size_24::solid",
+            feature: "24-solid-bars-3-center-left",
+            view: || view! { <Bars3CenterLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-3",
+            component: "Bars3",
+            module: "size_24::solid",
+            feature: "24-solid-bars-3",
+            view: || view! { <Bars3 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-4",
+            component: "Bars4",
+            module: "size_24::solid",
+            feature: "24-solid-bars-4",
+            view: || view! { <Bars4 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-arrow-down",
+            component: "BarsArrowDown",
+            module: "size_24::solid",
+            feature: "24-solid-bars-arrow-down",
+            view: || view! { <BarsArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bars-arrow-up",
+            component: "BarsArrowUp",
+            module: "size_24::solid",
+            feature: "24-solid-bars-arrow-up",
+            view: || view! { <BarsArrowUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "battery-0",
+            component: "Battery0",
+            module: "size_24::solid",
+            feature: "24-solid-battery-0",
+            view: || view! { <Battery0 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "battery-100",
+            component: "Battery100",
+            module: "size_24::solid",
+            feature: "24-solid-battery-100",
+            view: || view! { <Battery100 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "battery-50",
+            component: "Battery50",
+            module: "size_24::solid",
+            feature: "24-solid-battery-50",
+            view: || view! { <Battery50 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "beaker",
+            component: "Beaker",
+            module: "size_24::solid",
+            feature: "24-solid-beaker",
+            view: || view! { <Beaker /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bell-alert",
+            component: "BellAlert",
+            module: "size_24::solid",
+            feature: "24-solid-bell-alert",
+            view: || view! { <BellAlert /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bell-slash",
+            component: "BellSlash",
+            module: "size_24::solid",
+            feature: "24-solid-bell-slash",
+            view: || view! { <BellSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bell-snooze",
+            component: "BellSnooze",
+            module: "size_24::solid",
+            feature: "24-solid-bell-snooze",
+            view: || view! { <BellSnooze /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bell",
+            component: "Bell",
+            module: "size_24::solid",
+            feature: "24-solid-bell",
+            view: || view! { <Bell /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bold",
+            component: "Bold",
+            module: "size_24::solid",
+            feature: "24-solid-bold",
+            view: || view! { <Bold /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bolt-slash",
+            component: "BoltSlash",
+            module: "size_24::solid",
+            feature: "24-solid-bolt-slash",
+            view: || view! { <BoltSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bolt",
+            component: "Bolt",
+            module: "size_24::solid",
+            feature: "24-solid-bolt",
+            view: || view! { <Bolt /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "book-open",
+            component: "BookOpen",
+            module: "size_24::solid",
+            feature: "24-solid-book-open",
+            view: || view! { <BookOpen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bookmark-slash",
+            component: "BookmarkSlash",
+            module: "size_24::solid",
+            feature: "24-solid-bookmark-slash",
+            view: || view! { <BookmarkSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bookmark-square",
+            component: "BookmarkSquare",
+            module: "size_24::solid",
+            feature: "24-solid-bookmark-square",
+            view: || view! { <BookmarkSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bookmark",
+            component: "Bookmark",
+            module: "size_24::solid",
+            feature: "24-solid-bookmark",
+            view: || view! { <Bookmark /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "briefcase",
+            component: "Briefcase",
+            module: "size_24::solid",
+            feature: "24-solid-briefcase",
+            view: || view! { <Briefcase /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "bug-ant",
+            component: "BugAnt",
+            module: "size_24::solid",
+            feature: "24-solid-bug-ant",
+            view: || view! { <BugAnt /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "building-library",
+            component: "BuildingLibrary",
+            module: "size_24::solid",
+            feature: "24-solid-building-library",
+            view: || view! { <BuildingLibrary /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "building-office-2",
+            component: "BuildingOffice2",
+            module: "size_24::solid",
+            feature: "24-solid-building-office-2",
+            view: || view! { <BuildingOffice2 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "building-office",
+            component: "BuildingOffice",
+            module: "size_24::solid",
+            feature: "24-solid-building-office",
+            view: || view! { <BuildingOffice /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "building-storefront",
+            component: "BuildingStorefront",
+            module: "size_24::solid",
+            feature: "24-solid-building-storefront",
+            view: || view! { <BuildingStorefront /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cake",
+            component: "Cake",
+            module: "size_24::solid",
+            feature: "24-solid-cake",
+            view: || view! { <Cake /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "calculator",
+            component: "Calculator",
+            module: "size_24::solid",
+            feature: "24-solid-calculator",
+            view: || view! { <Calculator /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "calendar-date-range",
+            component: "CalendarDateRange",
+            module: "size_24::solid",
+            feature: "24-solid-calendar-date-range",
+            view: || view! { <CalendarDateRange /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "calendar-days",
+            component: "CalendarDays",
+            module: "size_24::solid",
+            feature: "24-solid-calendar-days",
+            view: || view! { <CalendarDays /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "calendar",
+            component: "Calendar",
+            module: "size_24::solid",
+            feature: "24-solid-calendar",
+            view: || view! { <Calendar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "camera",
+            component: "Camera",
+            module: "size_24::solid",
+            feature: "24-solid-camera",
+            view: || view! { <Camera /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chart-bar-square",
+            component: "ChartBarSquare",
+            module: "size_24::solid",
+            feature: "24-solid-chart-bar-square",
+            view: || view! { <ChartBarSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chart-bar",
+            component: "ChartBar",
+            module: "size_24::solid",
+            feature: "24-solid-chart-bar",
+            view: || view! { <ChartBar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chart-pie",
+            component: "ChartPie",
+            module: "size_24::solid",
+            feature: "24-solid-chart-pie",
+            view: || view! { <ChartPie /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-bottom-center-text",
+            component: "ChatBubbleBottomCenterText",
+            module: "size_24::solid",
+            feature: "24-solid-chat-bubble-bottom-center-text",
+            view: || view! { <ChatBubbleBottomCenterText /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-bottom-center",
+            component: "ChatBubbleBottomCenter",
+            module: "size_24::solid",
+            feature: "24-solid-chat-bubble-bottom-center",
+            view: || view! { <ChatBubbleBottomCenter /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-left-ellipsis",
+            component: "ChatBubbleLeftEllipsis",
+            module: "size_24::solid",
+            feature: "24-solid-chat-bubble-left-ellipsis",
+            view: || view! { <ChatBubbleLeftEllipsis /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-left-right",
+            component: "ChatBubbleLeftRight",
+            module: "size_24::solid",
+            feature: "24-solid-chat-bubble-left-right",
+            view: || view! { <ChatBubbleLeftRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-left",
+            component: "ChatBubbleLeft",
+            module: "size_24::solid",
+            feature: "24-solid-chat-bubble-left",
+            view: || view! { <ChatBubbleLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-oval-left-ellipsis",
+            component: "ChatBubbleOvalLeftEllipsis",
+            module: "size_24::solid",
+            feature: "24-solid-chat-bubble-oval-left-ellipsis",
+            view: || view! { <ChatBubbleOvalLeftEllipsis /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chat-bubble-oval-left",
+            component: "ChatBubbleOvalLeft",
+            module: "size_24::solid",
+            feature: "24-solid-chat-bubble-oval-left",
+            view: || view! { <ChatBubbleOvalLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "check-badge",
+            component: "CheckBadge",
+            module: "size_24::solid",
+            feature: "24-solid-check-badge",
+            view: || view! { <CheckBadge /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "check-circle",
+            component: "CheckCircle",
+            module: "size_24::solid",
+            feature: "24-solid-check-circle",
+            view: || view! { <CheckCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "check",
+            component: "Check",
+            module: "size_24::solid",
+            feature: "24-solid-check",
+            view: || view! { <Check /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-double-down",
+            component: "ChevronDoubleDown",
+            module: "size_24::solid",
+            feature: "24-solid-chevron-double-down",
+            view: || view! { <ChevronDoubleDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-double-left",
+            component: "ChevronDoubleLeft",
+            module: "size_24::solid",
+            feature: "24-solid-chevron-double-left",
+            view: || view! { <ChevronDoubleLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-double-right",
+            component: "ChevronDoubleRight",
+            module: "size_24::solid",
+            feature: "24-solid-chevron-double-right",
+            view: || view! { <ChevronDoubleRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-double-up",
+            component: "ChevronDoubleUp",
+            module: "size_24::solid",
+            feature: "24-solid-chevron-double-up",
+            view: || view! { <ChevronDoubleUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-down",
+            component: "ChevronDown",
+            module: "size_24::solid",
+            feature: "24-solid-chevron-down",
+            view: || view! { <ChevronDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-left",
+            component: "ChevronLeft",
+            module: "size_24::solid",
+            feature: "24-solid-chevron-left",
+            view: || view! { <ChevronLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-right",
+            component: "ChevronRight",
+            module: "size_24::solid",
+            feature: "24-solid-chevron-right",
+            view: || view! { <ChevronRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-up-down",
+            component: "ChevronUpDown",
+            module: "size_24::solid",
+            feature: "24-solid-chevron-up-down",
+            view: || view! { <ChevronUpDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "chevron-up",
+            component: "ChevronUp",
+            module: "size_24::solid",
+            feature: "24-solid-chevron-up",
+            view: || view! { <ChevronUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "circle-stack",
+            component: "CircleStack",
+            module: "size_24::solid",
+            feature: "24-solid-circle-stack",
+            view: || view! { <CircleStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clipboard-document-check",
+            component: "ClipboardDocumentCheck",
+            module: "size_24::solid",
+            feature: "24-solid-clipboard-document-check",
+            view: || view! { <ClipboardDocumentCheck /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clipboard-document-list",
+            component: "ClipboardDocumentList",
+            module: "size_24::solid",
+            feature: "24-solid-clipboard-document-list",
+            view: || view! { <ClipboardDocumentList /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clipboard-document",
+            component: "ClipboardDocument",
+            module: "size_24::solid",
+            feature: "24-solid-clipboard-document",
+            view: || view! { <ClipboardDocument /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clipboard",
+            component: "Clipboard",
+            module: "size_24::solid",
+            feature: "24-solid-clipboard",
+            view: || view! { <Clipboard /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "clock",
+            component: "Clock",
+            module: "size_24::solid",
+            feature: "24-solid-clock",
+            view: || view! { <Clock /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cloud-arrow-down",
+            component: "CloudArrowDown",
+            module: "size_24::solid",
+            feature: "24-solid-cloud-arrow-down",
+            view: || view! { <CloudArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cloud-arrow-up",
+            component: "CloudArrowUp",
+            module: "size_24::solid",
+            feature: "24-solid-cloud-arrow-up",
+            view: || view! { <CloudArrowUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cloud",
+            component: "Cloud",
+            module: "size_24::solid",
+            feature: "24-solid-cloud",
+            view: || view! { <Cloud /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "code-bracket-square",
+            component: "CodeBracketSquare",
+            module: "size_24::solid",
+            feature: "24-solid-code-bracket-square",
+            view: || view! { <CodeBracketSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "code-bracket",
+            component: "CodeBracket",
+            module: "size_24::solid",
+            feature: "24-solid-code-bracket",
+            view: || view! { <CodeBracket /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cog-6-tooth",
+            component: "Cog6Tooth",
+            module: "size_24::solid",
+            feature: "24-solid-cog-6-tooth",
+            view: || view! { <Cog6Tooth /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cog-8-tooth",
+            component: "Cog8Tooth",
+            module: "size_24::solid",
+            feature: "24-solid-cog-8-tooth",
+            view: || view! { <Cog8Tooth /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cog",
+            component: "Cog",
+            module: "size_24::solid",
+            feature: "24-solid-cog",
+            view: || view! { <Cog /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "command-line",
+            component: "CommandLine",
+            module: "size_24::solid",
+            feature: "24-solid-command-line",
+            view: || view! { <CommandLine /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "computer-desktop",
+            component: "ComputerDesktop",
+            module: "size_24::solid",
+            feature: "24-solid-computer-desktop",
+            view: || view! { <ComputerDesktop /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cpu-chip",
+            component: "CpuChip",
+            module: "size_24::solid",
+            feature: "24-solid-cpu-chip",
+            view: || view! { <CpuChip /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "credit-card",
+            component: "CreditCard",
+            module: "size_24::solid",
+            feature: "24-solid-credit-card",
+            view: || view! { <CreditCard /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cube-transparent",
+            component: "CubeTransparent",
+            module: "size_24::solid",
+            feature: "24-solid-cube-transparent",
+            view: || view! { <CubeTransparent /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cube",
+            component: "Cube",
+            module: "size_24::solid",
+            feature: "24-solid-cube",
+            view: || view! { <Cube /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-bangladeshi",
+            component: "CurrencyBangladeshi",
+            module: "size_24::solid",
+            feature: "24-solid-currency-bangladeshi",
+            view: || view! { <CurrencyBangladeshi /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-dollar",
+            component: "CurrencyDollar",
+            module: "size_24::solid",
+            feature: "24-solid-currency-dollar",
+            view: || view! { <CurrencyDollar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-euro",
+            component: "CurrencyEuro",
+            module: "size_24::solid",
+            feature: "24-solid-currency-euro",
+            view: || view! { <CurrencyEuro /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-pound",
+            component: "CurrencyPound",
+            module: "size_24::solid",
+            feature: "24-solid-currency-pound",
+            view: || view! { <CurrencyPound /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-rupee",
+            component: "CurrencyRupee",
+            module: "size_24::solid",
+            feature: "24-solid-currency-rupee",
+            view: || view! { <CurrencyRupee /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "currency-yen",
+            component: "CurrencyYen",
+            module: "size_24::solid",
+            feature: "24-solid-currency-yen",
+            view: || view! { <CurrencyYen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cursor-arrow-rays",
+            component: "CursorArrowRays",
+            module: "size_24::solid",
+            feature: "24-solid-cursor-arrow-rays",
+            view: || view! { <CursorArrowRays /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "cursor-arrow-ripple",
+            component: "CursorArrowRipple",
+            module: "size_24::solid",
+            feature: "24-solid-cursor-arrow-ripple",
+            view: || view! { <CursorArrowRipple /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "device-phone-mobile",
+            component: "DevicePhoneMobile",
+            module: "size_24::solid",
+            feature: "24-solid-device-phone-mobile",
+            view: || view! { <DevicePhoneMobile /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "device-tablet",
+            component: "DeviceTablet",
+            module: "size_24::solid",
+            feature: "24-solid-device-tablet",
+            view: || view! { <DeviceTablet /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "divide",
+            component: "Divide",
+            module: "size_24::solid",
+            feature: "24-solid-divide",
+            view: || view! { <Divide /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-arrow-down",
+            component: "DocumentArrowDown",
+            module: "size_24::solid",
+            feature: "24-solid-document-arrow-down",
+            view: || view! { <DocumentArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-arrow-up",
+            component: "DocumentArrowUp",
+            module: "size_24::solid",
+            feature: "24-solid-document-arrow-up",
+            view: || view! { <DocumentArrowUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-chart-bar",
+            component: "DocumentChartBar",
+            module: "size_24::solid",
+            feature: "24-solid-document-chart-bar",
+            view: || view! { <DocumentChartBar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-check",
+            component: "DocumentCheck",
+            module: "size_24::solid",
+            feature: "24-solid-document-check",
+            view: || view! { <DocumentCheck /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-bangladeshi",
+            component: "DocumentCurrencyBangladeshi",
+            module: "size_24::solid",
+            feature: "24-solid-document-currency-bangladeshi",
+            view: || view! { <DocumentCurrencyBangladeshi /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-dollar",
+            component: "DocumentCurrencyDollar",
+            module: "size_24::solid",
+            feature: "24-solid-document-currency-dollar",
+            view: || view! { <DocumentCurrencyDollar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-euro",
+            component: "DocumentCurrencyEuro",
+            module: "size_24::solid",
+            feature: "24-solid-document-currency-euro",
+            view: || view! { <DocumentCurrencyEuro /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-pound",
+            component: "DocumentCurrencyPound",
+            module: "size_24::solid",
+            feature: "24-solid-document-currency-pound",
+            view: || view! { <DocumentCurrencyPound /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-rupee",
+            component: "DocumentCurrencyRupee",
+            module: "size_24::solid",
+            feature: "24-solid-document-currency-rupee",
+            view: || view! { <DocumentCurrencyRupee /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-currency-yen",
+            component: "DocumentCurrencyYen",
+            module: "size_24::solid",
+            feature: "24-solid-document-currency-yen",
+            view: || view! { <DocumentCurrencyYen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-duplicate",
+            component: "DocumentDuplicate",
+            module: "size_24::solid",
+            feature: "24-solid-document-duplicate",
+            view: || view! { <DocumentDuplicate /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-magnifying-glass",
+            component: "DocumentMagnifyingGlass",
+            module: "size_24::solid",
+            feature: "24-solid-document-magnifying-glass",
+            view: || view! { <DocumentMagnifyingGlass /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-minus",
+            component: "DocumentMinus",
+            module: "size_24::solid",
+            feature: "24-solid-document-minus",
+            view: || view! { <DocumentMinus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-plus",
+            component: "DocumentPlus",
+            module: "size_24::solid",
+            feature: "24-solid-document-plus",
+            view: || view! { <DocumentPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document-text",
+            component: "DocumentText",
+            module: "size_24::solid",
+            feature: "24-solid-document-text",
+            view: || view! { <DocumentText /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "document",
+            component: "Document",
+            module: "size_24::solid",
+            feature: "24-solid-document",
+            view: || view! { <Document /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "ellipsis-horizontal-circle",
+            component: "EllipsisHorizontalCircle",
+            module: "size_24::solid",
+            feature: "24-solid-ellipsis-horizontal-circle",
+            view: || view! { <EllipsisHorizontalCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "ellipsis-horizontal",
+            component: "EllipsisHorizontal",
+            module: "size_24::solid",
+            feature: "24-solid-ellipsis-horizontal",
+            view: || view! { <EllipsisHorizontal /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "ellipsis-vertical",
+            component: "EllipsisVertical",
+            module: "size_24::solid",
+            feature: "24-solid-ellipsis-vertical",
+            view: || view! { <EllipsisVertical /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "envelope-open",
+            component: "EnvelopeOpen",
+            module: "size_24::solid",
+            feature: "24-solid-envelope-open",
+            view: || view! { <EnvelopeOpen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "envelope",
+            component: "Envelope",
+            module: "size_24::solid",
+            feature: "24-solid-envelope",
+            view: || view! { <Envelope /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "equals",
+            component: "Equals",
+            module: "size_24::solid",
+            feature: "24-solid-equals",
+            view: || view! { <Equals /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "exclamation-circle",
+            component: "ExclamationCircle",
+            module: "size_24::solid",
+            feature: "24-solid-exclamation-circle",
+            view: || view! { <ExclamationCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "exclamation-triangle",
+            component: "ExclamationTriangle",
+            module: "size_24::solid",
+            feature: "24-solid-exclamation-triangle",
+            view: || view! { <ExclamationTriangle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "eye-dropper",
+            component: "EyeDropper",
+            module: "size_24::solid",
+            feature: "24-solid-eye-dropper",
+            view: || view! { <EyeDropper /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "eye-slash",
+            component: "EyeSlash",
+            module: "size_24::solid",
+            feature: "24-solid-eye-slash",
+            view: || view! { <EyeSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "eye",
+            component: "Eye",
+            module: "size_24::solid",
+            feature: "24-solid-eye",
+            view: || view! { <Eye /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "face-frown",
+            component: "FaceFrown",
+            module: "size_24::solid",
+            feature: "24-solid-face-frown",
+            view: || view! { <FaceFrown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "face-smile",
+            component: "FaceSmile",
+            module: "size_24::solid",
+            feature: "24-solid-face-smile",
+            view: || view! { <FaceSmile /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "film",
+            component: "Film",
+            module: "size_24::solid",
+            feature: "24-solid-film",
+            view: || view! { <Film /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "finger-print",
+            component: "FingerPrint",
+            module: "size_24::solid",
+            feature: "24-solid-finger-print",
+            view: || view! { <FingerPrint /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "fire",
+            component: "Fire",
+            module: "size_24::solid",
+            feature: "24-solid-fire",
+            view: || view! { <Fire /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "flag",
+            component: "Flag",
+            module: "size_24::solid",
+            feature: "24-solid-flag",
+            view: || view! { <Flag /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder-arrow-down",
+            component: "FolderArrowDown",
+            module: "size_24::solid",
+            feature: "24-solid-folder-arrow-down",
+            view: || view! { <FolderArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder-minus",
+            component: "FolderMinus",
+            module: "size_24::solid",
+            feature: "24-solid-folder-minus",
+            view: || view! { <FolderMinus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder-open",
+            component: "FolderOpen",
+            module: "size_24::solid",
+            feature: "24-solid-folder-open",
+            view: || view! { <FolderOpen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder-plus",
+            component: "FolderPlus",
+            module: "size_24::solid",
+            feature: "24-solid-folder-plus",
+            view: || view! { <FolderPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "folder",
+            component: "Folder",
+            module: "size_24::solid",
+            feature: "24-solid-folder",
+            view: || view! { <Folder /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "forward",
+            component: "Forward",
+            module: "size_24::solid",
+            feature: "24-solid-forward",
+            view: || view! { <Forward /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "funnel",
+            component: "Funnel",
+            module: "size_24::solid",
+            feature: "24-solid-funnel",
+            view: || view! { <Funnel /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "gif",
+            component: "Gif",
+            module: "size_24::solid",
+            feature: "24-solid-gif",
+            view: || view! { <Gif /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "gift-top",
+            component: "GiftTop",
+            module: "size_24::solid",
+            feature: "24-solid-gift-top",
+            view: || view! { <GiftTop /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "gift",
+            component: "Gift",
+            module: "size_24::solid",
+            feature: "24-solid-gift",
+            view: || view! { <Gift /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "globe-alt",
+            component: "GlobeAlt",
+            module: "size_24::solid",
+            feature: "24-solid-globe-alt",
+            view: || view! { <GlobeAlt /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "globe-americas",
+            component: "GlobeAmericas",
+            module: "size_24::solid",
+            feature: "24-solid-globe-americas",
+            view: || view! { <GlobeAmericas /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "globe-asia-australia",
+            component: "GlobeAsiaAustralia",
+            module: "size_24::solid",
+            feature: "24-solid-globe-asia-australia",
+            view: || view! { <GlobeAsiaAustralia /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "globe-europe-africa",
+            component: "GlobeEuropeAfrica",
+            module: "size_24::solid",
+            feature: "24-solid-globe-europe-africa",
+            view: || view! { <GlobeEuropeAfrica /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "h1",
+            component: "H1",
+            module: "size_24::solid",
+            feature: "24-solid-h1",
+            view: || view! { <H1 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "h2",
+            component: "H2",
+            module: "size_24::solid",
+            feature: "24-solid-h2",
+            view: || view! { <H2 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "h3",
+            component: "H3",
+            module: "size_24::solid",
+            feature: "24-solid-h3",
+            view: || view! { <H3 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "hand-raised",
+            component: "HandRaised",
+            module: "size_24::solid",
+            feature: "24-solid-hand-raised",
+            view: || view! { <HandRaised /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "hand-thumb-down",
+            component: "HandThumbDown",
+            module: "size_24::solid",
+            feature: "24-solid-hand-thumb-down",
+            view: || view! { <HandThumbDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "hand-thumb-up",
+            component: "HandThumbUp",
+            module: "size_24::solid",
+            feature: "24-solid-hand-thumb-up",
+            view: || view! { <HandThumbUp /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "hashtag",
+            component: "Hashtag",
+            module: "size_24::solid",
+            feature: "24-solid-hashtag",
+            view: || view! { <Hashtag /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "heart",
+            component: "Heart",
+            module: "size_24::solid",
+            feature: "24-solid-heart",
+            view: || view! { <Heart /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "home-modern",
+            component: "HomeModern",
+            module: "size_24::solid",
+            feature: "24-solid-home-modern",
+            view: || view! { <HomeModern /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "home",
+            component: "Home",
+            module: "size_24::solid",
+            feature: "24-solid-home",
+            view: || view! { <Home /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "identification",
+            component: "Identification",
+            module: "size_24::solid",
+            feature: "24-solid-identification",
+            view: || view! { <Identification /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "inbox-arrow-down",
+            component: "InboxArrowDown",
+            module: "size_24::solid",
+            feature: "24-solid-inbox-arrow-down",
+            view: || view! { <InboxArrowDown /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "inbox-stack",
+            component: "InboxStack",
+            module: "size_24::solid",
+            feature: "24-solid-inbox-stack",
+            view: || view! { <InboxStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "inbox",
+            component: "Inbox",
+            module: "size_24::solid",
+            feature: "24-solid-inbox",
+            view: || view! { <Inbox /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "information-circle",
+            component: "InformationCircle",
+            module: "size_24::solid",
+            feature: "24-solid-information-circle",
+            view: || view! { <InformationCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "italic",
+            component: "Italic",
+            module: "size_24::solid",
+            feature: "24-solid-italic",
+            view: || view! { <Italic /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "key",
+            component: "Key",
+            module: "size_24::solid",
+            feature: "24-solid-key",
+            view: || view! { <Key /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "language",
+            component: "Language",
+            module: "size_24::solid",
+            feature: "24-solid-language",
+            view: || view! { <Language /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "lifebuoy",
+            component: "Lifebuoy",
+            module: "size_24::solid",
+            feature: "24-solid-lifebuoy",
+            view: || view! { <Lifebuoy /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "light-bulb",
+            component: "LightBulb",
+            module: "size_24::solid",
+            feature: "24-solid-light-bulb",
+            view: || view! { <LightBulb /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "link-slash",
+            component: "LinkSlash",
+            module: "size_24::solid",
+            feature: "24-solid-link-slash",
+            view: || view! { <LinkSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "link",
+            component: "Link",
+            module: "size_24::solid",
+            feature: "24-solid-link",
+            view: || view! { <Link /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "list-bullet",
+            component: "ListBullet",
+            module: "size_24::solid",
+            feature: "24-solid-list-bullet",
+            view: || view! { <ListBullet /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "lock-closed",
+            component: "LockClosed",
+            module: "size_24::solid",
+            feature: "24-solid-lock-closed",
+            view: || view! { <LockClosed /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "lock-open",
+            component: "LockOpen",
+            module: "size_24::solid",
+            feature: "24-solid-lock-open",
+            view: || view! { <LockOpen /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "magnifying-glass-circle",
+            component: "MagnifyingGlassCircle",
+            module: "size_24::solid",
+            feature: "24-solid-magnifying-glass-circle",
+            view: || view! { <MagnifyingGlassCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "magnifying-glass-minus",
+            component: "MagnifyingGlassMinus",
+            module: "size_24::solid",
+            feature: "24-solid-magnifying-glass-minus",
+            view: || view! { <MagnifyingGlassMinus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "magnifying-glass-plus",
+            component: "MagnifyingGlassPlus",
+            module: "size_24::solid",
+            feature: "24-solid-magnifying-glass-plus",
+            view: || view! { <MagnifyingGlassPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "magnifying-glass",
+            component: "MagnifyingGlass",
+            module: "size_24::solid",
+            feature: "24-solid-magnifying-glass",
+            view: || view! { <MagnifyingGlass /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "map-pin",
+            component: "MapPin",
+            module: "size_24::solid",
+            feature: "24-solid-map-pin",
+            view: || view! { <MapPin /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "map",
+            component: "Map",
+            module: "size_24::solid",
+            feature: "24-solid-map",
+            view: || view! { <Map /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "megaphone",
+            component: "Megaphone",
+            module: "size_24::solid",
+            feature: "24-solid-megaphone",
+            view: || view! { <Megaphone /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "microphone",
+            component: "Microphone",
+            module: "size_24::solid",
+            feature: "24-solid-microphone",
+            view: || view! { <Microphone /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "minus-circle",
+            component: "MinusCircle",
+            module: "size_24::solid",
+            feature: "24-solid-minus-circle",
+            view: || view! { <MinusCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "minus-small",
+            component: "MinusSmall",
+            module: "size_24::solid",
+            feature: "24-solid-minus-small",
+            view: || view! { <MinusSmall /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "minus",
+            component: "Minus",
+            module: "size_24::solid",
+            feature: "24-solid-minus",
+            view: || view! { <Minus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "moon",
+            component: "Moon",
+            module: "size_24::solid",
+            feature: "24-solid-moon",
+            view: || view! { <Moon /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "musical-note",
+            component: "MusicalNote",
+            module: "size_24::solid",
+            feature: "24-solid-musical-note",
+            view: || view! { <MusicalNote /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "newspaper",
+            component: "Newspaper",
+            module: "size_24::solid",
+            feature: "24-solid-newspaper",
+            view: || view! { <Newspaper /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "no-symbol",
+            component: "NoSymbol",
+            module: "size_24::solid",
+            feature: "24-solid-no-symbol",
+            view: || view! { <NoSymbol /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "numbered-list",
+            component: "NumberedList",
+            module: "size_24::solid",
+            feature: "24-solid-numbered-list",
+            view: || view! { <NumberedList /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "paint-brush",
+            component: "PaintBrush",
+            module: "size_24::solid",
+            feature: "24-solid-paint-brush",
+            view: || view! { <PaintBrush /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "paper-airplane",
+            component: "PaperAirplane",
+            module: "size_24::solid",
+            feature: "24-solid-paper-airplane",
+            view: || view! { <PaperAirplane /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "paper-clip",
+            component: "PaperClip",
+            module: "size_24::solid",
+            feature: "24-solid-paper-clip",
+            view: || view! { <PaperClip /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "pause-circle",
+            component: "PauseCircle",
+            module: "size_24::solid",
+            feature: "24-solid-pause-circle",
+            view: || view! { <PauseCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "pause",
+            component: "Pause",
+            module: "size_24::solid",
+            feature: "24-solid-pause",
+            view: || view! { <Pause /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "pencil-square",
+            component: "PencilSquare",
+            module: "size_24::solid",
+            feature: "24-solid-pencil-square",
+            view: || view! { <PencilSquare /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "pencil",
+            component: "Pencil",
+            module: "size_24::solid",
+            feature: "24-solid-pencil",
+            view: || view! { <Pencil /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "percent-badge",
+            component: "PercentBadge",
+            module: "size_24::solid",
+            feature: "24-solid-percent-badge",
+            view: || view! { <PercentBadge /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "phone-arrow-down-left",
+            component: "PhoneArrowDownLeft",
+            module: "size_24::solid",
+            feature: "24-solid-phone-arrow-down-left",
+            view: || view! { <PhoneArrowDownLeft /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "phone-arrow-up-right",
+            component: "PhoneArrowUpRight",
+            module: "size_24::solid",
+            feature: "24-solid-phone-arrow-up-right",
+            view: || view! { <PhoneArrowUpRight /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "phone-x-mark",
+            component: "PhoneXMark",
+            module: "size_24::solid",
+            feature: "24-solid-phone-x-mark",
+            view: || view! { <PhoneXMark /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "phone",
+            component: "Phone",
+            module: "size_24::solid",
+            feature: "24-solid-phone",
+            view: || view! { <Phone /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "photo",
+            component: "Photo",
+            module: "size_24::solid",
+            feature: "24-solid-photo",
+            view: || view! { <Photo /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "play-circle",
+            component: "PlayCircle",
+            module: "size_24::solid",
+            feature: "24-solid-play-circle",
+            view: || view! { <PlayCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "play-pause",
+            component: "PlayPause",
+            module: "size_24::solid",
+            feature: "24-solid-play-pause",
+            view: || view! { <PlayPause /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "play",
+            component: "Play",
+            module: "size_24::solid",
+            feature: "24-solid-play",
+            view: || view! { <Play /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "plus-circle",
+            component: "PlusCircle",
+            module: "size_24::solid",
+            feature: "24-solid-plus-circle",
+            view: || view! { <PlusCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "plus-small",
+            component: "PlusSmall",
+            module: "size_24::solid",
+            feature: "24-solid-plus-small",
+            view: || view! { <PlusSmall /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "plus",
+            component: "Plus",
+            module: "size_24::solid",
+            feature: "24-solid-plus",
+            view: || view! { <Plus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "power",
+            component: "Power",
+            module: "size_24::solid",
+            feature: "24-solid-power",
+            view: || view! { <Power /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "presentation-chart-bar",
+            component: "PresentationChartBar",
+            module: "size_24::solid",
+            feature: "24-solid-presentation-chart-bar",
+            view: || view! { <PresentationChartBar /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "presentation-chart-line",
+            component: "PresentationChartLine",
+            module: "size_24::solid",
+            feature: "24-solid-presentation-chart-line",
+            view: || view! { <PresentationChartLine /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "printer",
+            component: "Printer",
+            module: "size_24::solid",
+            feature: "24-solid-printer",
+            view: || view! { <Printer /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "puzzle-piece",
+            component: "PuzzlePiece",
+            module: "size_24::solid",
+            feature: "24-solid-puzzle-piece",
+            view: || view! { <PuzzlePiece /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "qr-code",
+            component: "QrCode",
+            module: "size_24::solid",
+            feature: "24-solid-qr-code",
+            view: || view! { <QrCode /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "question-mark-circle",
+            component: "QuestionMarkCircle",
+            module: "size_24::solid",
+            feature: "24-solid-question-mark-circle",
+            view: || view! { <QuestionMarkCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "queue-list",
+            component: "QueueList",
+            module: "size_24::solid",
+            feature: "24-solid-queue-list",
+            view: || view! { <QueueList /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "radio",
+            component: "Radio",
+            module: "size_24::solid",
+            feature: "24-solid-radio",
+            view: || view! { <Radio /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "receipt-percent",
+            component: "ReceiptPercent",
+            module: "size_24::solid",
+            feature: "24-solid-receipt-percent",
+            view: || view! { <ReceiptPercent /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "receipt-refund",
+            component: "ReceiptRefund",
+            module: "size_24::solid",
+            feature: "24-solid-receipt-refund",
+            view: || view! { <ReceiptRefund /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "rectangle-group",
+            component: "RectangleGroup",
+            module: "size_24::solid",
+            feature: "24-solid-rectangle-group",
+            view: || view! { <RectangleGroup /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "rectangle-stack",
+            component: "RectangleStack",
+            module: "size_24::solid",
+            feature: "24-solid-rectangle-stack",
+            view: || view! { <RectangleStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "rocket-launch",
+            component: "RocketLaunch",
+            module: "size_24::solid",
+            feature: "24-solid-rocket-launch",
+            view: || view! { <RocketLaunch /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "rss",
+            component: "Rss",
+            module: "size_24::solid",
+            feature: "24-solid-rss",
+            view: || view! { <Rss /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "scale",
+            component: "Scale",
+            module: "size_24::solid",
+            feature: "24-solid-scale",
+            view: || view! { <Scale /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "scissors",
+            component: "Scissors",
+            module: "size_24::solid",
+            feature: "24-solid-scissors",
+            view: || view! { <Scissors /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "server-stack",
+            component: "ServerStack",
+            module: "size_24::solid",
+            feature: "24-solid-server-stack",
+            view: || view! { <ServerStack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "server",
+            component: "Server",
+            module: "size_24::solid",
+            feature: "24-solid-server",
+            view: || view! { <Server /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "share",
+            component: "Share",
+            module: "size_24::solid",
+            feature: "24-solid-share",
+            view: || view! { <Share /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "shield-check",
+            component: "ShieldCheck",
+            module: "size_24::solid",
+            feature: "24-solid-shield-check",
+            view: || view! { <ShieldCheck /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "shield-exclamation",
+            component: "ShieldExclamation",
+            module: "size_24::solid",
+            feature: "24-solid-shield-exclamation",
+            view: || view! { <ShieldExclamation /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "shopping-bag",
+            component: "ShoppingBag",
+            module: "size_24::solid",
+            feature: "24-solid-shopping-bag",
+            view: || view! { <ShoppingBag /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "shopping-cart",
+            component: "ShoppingCart",
+            module: "size_24::solid",
+            feature: "24-solid-shopping-cart",
+            view: || view! { <ShoppingCart /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "signal-slash",
+            component: "SignalSlash",
+            module: "size_24::solid",
+            feature: "24-solid-signal-slash",
+            view: || view! { <SignalSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "signal",
+            component: "Signal",
+            module: "size_24::solid",
+            feature: "24-solid-signal",
+            view: || view! { <Signal /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "slash",
+            component: "Slash",
+            module: "size_24::solid",
+            feature: "24-solid-slash",
+            view: || view! { <Slash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "sparkles",
+            component: "Sparkles",
+            module: "size_24::solid",
+            feature: "24-solid-sparkles",
+            view: || view! { <Sparkles /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "speaker-wave",
+            component: "SpeakerWave",
+            module: "size_24::solid",
+            feature: "24-solid-speaker-wave",
+            view: || view! { <SpeakerWave /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "speaker-x-mark",
+            component: "SpeakerXMark",
+            module: "size_24::solid",
+            feature: "24-solid-speaker-x-mark",
+            view: || view! { <SpeakerXMark /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "square-2-stack",
+            component: "Square2Stack",
+            module: "size_24::solid",
+            feature: "24-solid-square-2-stack",
+            view: || view! { <Square2Stack /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "square-3-stack-3d",
+            component: "Square3Stack3D",
+            module: "size_24::solid",
+            feature: "24-solid-square-3-stack-3d",
+            view: || view! { <Square3Stack3D /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "squares-2x2",
+            component: "Squares2X2",
+            module: "size_24::solid",
+            feature: "24-solid-squares-2x2",
+            view: || view! { <Squares2X2 /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "squares-plus",
+            component: "SquaresPlus",
+            module: "size_24::solid",
+            feature: "24-solid-squares-plus",
+            view: || view! { <SquaresPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "star",
+            component: "Star",
+            module: "size_24::solid",
+            feature: "24-solid-star",
+            view: || view! { <Star /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "stop-circle",
+            component: "StopCircle",
+            module: "size_24::solid",
+            feature: "24-solid-stop-circle",
+            view: || view! { <StopCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "stop",
+            component: "Stop",
+            module: "size_24::solid",
+            feature: "24-solid-stop",
+            view: || view! { <Stop /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "strikethrough",
+            component: "Strikethrough",
+            module: "size_24::solid",
+            feature: "24-solid-strikethrough",
+            view: || view! { <Strikethrough /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "sun",
+            component: "Sun",
+            module: "size_24::solid",
+            feature: "24-solid-sun",
+            view: || view! { <Sun /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "swatch",
+            component: "Swatch",
+            module: "size_24::solid",
+            feature: "24-solid-swatch",
+            view: || view! { <Swatch /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "table-cells",
+            component: "TableCells",
+            module: "size_24::solid",
+            feature: "24-solid-table-cells",
+            view: || view! { <TableCells /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "tag",
+            component: "Tag",
+            module: "size_24::solid",
+            feature: "24-solid-tag",
+            view: || view! { <Tag /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "ticket",
+            component: "Ticket",
+            module: "size_24::solid",
+            feature: "24-solid-ticket",
+            view: || view! { <Ticket /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "trash",
+            component: "Trash",
+            module: "size_24::solid",
+            feature: "24-solid-trash",
+            view: || view! { <Trash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "trophy",
+            component: "Trophy",
+            module: "size_24::solid",
+            feature: "24-solid-trophy",
+            view: || view! { <Trophy /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "truck",
+            component: "Truck",
+            module: "size_24::solid",
+            feature: "24-solid-truck",
+            view: || view! { <Truck /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "tv",
+            component: "Tv",
+            module: "size_24::solid",
+            feature: "24-solid-tv",
+            view: || view! { <Tv /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "underline",
+            component: "Underline",
+            module: "size_24::solid",
+            feature: "24-solid-underline",
+            view: || view! { <Underline /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user-circle",
+            component: "UserCircle",
+            module: "size_24::solid",
+            feature: "24-solid-user-circle",
+            view: || view! { <UserCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user-group",
+            component: "UserGroup",
+            module: "size_24::solid",
+            feature: "24-solid-user-group",
+            view: || view! { <UserGroup /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user-minus",
+            component: "UserMinus",
+            module: "size_24::solid",
+            feature: "24-solid-user-minus",
+            view: || view! { <UserMinus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user-plus",
+            component: "UserPlus",
+            module: "size_24::solid",
+            feature: "24-solid-user-plus",
+            view: || view! { <UserPlus /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "user",
+            component: "User",
+            module: "size_24::solid",
+            feature: "24-solid-user",
+            view: || view! { <User /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "users",
+            component: "Users",
+            module: "size_24::solid",
+            feature: "24-solid-users",
+            view: || view! { <Users /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "variable",
+            component: "Variable",
+            module: "size_24::solid",
+            feature: "24-solid-variable",
+            view: || view! { <Variable /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "video-camera-slash",
+            component: "VideoCameraSlash",
+            module: "size_24::solid",
+            feature: "24-solid-video-camera-slash",
+            view: || view! { <VideoCameraSlash /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "video-camera",
+            component: "VideoCamera",
+            module: "size_24::solid",
+            feature: "24-solid-video-camera",
+            view: || view! { <VideoCamera /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "view-columns",
+            component: "ViewColumns",
+            module: "size_24::solid",
+            feature: "24-solid-view-columns",
+            view: || view! { <ViewColumns /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "viewfinder-circle",
+            component: "ViewfinderCircle",
+            module: "size_24::solid",
+            feature: "24-solid-viewfinder-circle",
+            view: || view! { <ViewfinderCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "wallet",
+            component: "Wallet",
+            module: "size_24::solid",
+            feature: "24-solid-wallet",
+            view: || view! { <Wallet /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "wifi",
+            component: "Wifi",
+            module: "size_24::solid",
+            feature: "24-solid-wifi",
+            view: || view! { <Wifi /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "window",
+            component: "Window",
+            module: "size_24::solid",
+            feature: "24-solid-window",
+            view: || view! { <Window /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "wrench-screwdriver",
+            component: "WrenchScrewdriver",
+            module: "size_24::solid",
+            feature: "24-solid-wrench-screwdriver",
+            view: || view! { <WrenchScrewdriver /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "wrench",
+            component: "Wrench",
+            module: "size_24::solid",
+            feature: "24-solid-wrench",
+            view: || view! { <Wrench /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "x-circle",
+            component: "XCircle",
+            module: "size_24::solid",
+            feature: "24-solid-x-circle",
+            view: || view! { <XCircle /> }.into_any(),
+        },
+        GalleryIcon {
+            name: "x-mark",
+            component: "XMark",
+            module: "size_24::solid",
+            feature: "24-solid-x-mark",
+            view: || view! { <XMark /> }.into_any(),
+        },
     ]
 }
+
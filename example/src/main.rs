@@ -1,5 +1,7 @@
-use leptos::prelude::*;
+use std::time::Duration;
+
 use leptos::either::EitherOf4;
+use leptos::prelude::*;
 
 mod components;
 
@@ -13,6 +15,43 @@ enum IconType {
     Micro,
 }
 
+/// What clicking an icon copies.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CopyMode {
+    /// The `use` path and `view!` of the component.
+    Snippet,
+    /// The Cargo feature that enables the icon.
+    Feature,
+}
+
+/// An icon of the gallery, see `components.rs`.
+#[derive(Clone, Copy)]
+pub struct GalleryIcon {
+    pub name: &'static str,
+    pub component: &'static str,
+    pub module: &'static str,
+    pub feature: &'static str,
+    pub view: fn() -> AnyView,
+}
+
+impl GalleryIcon {
+    fn copy_text(&self, mode: CopyMode) -> String {
+        match mode {
+            CopyMode::Snippet => format!(
+                "use leptos_heroicons::{}::{};\n\nview! {{ <{} /> }}",
+                self.module, self.component, self.component
+            ),
+            CopyMode::Feature => format!("\"{}\",", self.feature),
+        }
+    }
+
+    fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        self.name.contains(&query)
+            || self.component.to_lowercase().contains(&query)
+    }
+}
+
 pub fn main() {
     mount_to_body(move || {
         view! { <App /> }
@@ -22,6 +61,14 @@ pub fn main() {
 #[component]
 fn App() -> impl IntoView {
     let (active_icon_type, set_icon_type) = signal(IconType::Solid);
+    let (query, set_query) = signal(String::new());
+    let (copy_mode, set_copy_mode) = signal(CopyMode::Snippet);
+    let (copied, set_copied) = signal(None::<&'static str>);
+    let gallery = Gallery {
+        query,
+        copy_mode,
+        set_copied,
+    };
 
     view! {
         <div class="min-h-full">
@@ -48,6 +95,16 @@ fn App() -> impl IntoView {
                                     set_icon_type />
                             </div>
                         </div>
+                        <div class="flex items-center space-x-4">
+                            <input
+                                type="search"
+                                placeholder="Search icons"
+                                class="rounded-md border border-gray-300 px-3 py-1.5 text-sm"
+                                prop:value=query
+                                on:input=move |ev| set_query.set(event_target_value(&ev)) />
+                            <CopyModeButton mode=CopyMode::Snippet copy_mode set_copy_mode />
+                            <CopyModeButton mode=CopyMode::Feature copy_mode set_copy_mode />
+                        </div>
                     </div>
                 </div>
             </nav>
@@ -56,18 +113,40 @@ fn App() -> impl IntoView {
                 <main>
                     <div class="mx-auto max-w-7xl sm:px-6 lg:px-8">
                         {move || match active_icon_type.get() {
-                            IconType::Solid => EitherOf4::A(view! { <Solid24IconGrid /> }),
-                            IconType::Outline => EitherOf4::B(view! { <Outline24IconGrid /> }),
-                            IconType::Mini => EitherOf4::C(view! { <Solid20IconGrid /> }),
-                            IconType::Micro => EitherOf4::D(view! { <Solid16IconGrid /> }),
+                            IconType::Solid => EitherOf4::A(view! {
+                                <IconGrid icons=size_24_solid() class="w-6 h-6" gallery />
+                            }),
+                            IconType::Outline => EitherOf4::B(view! {
+                                <IconGrid icons=size_24_outline() class="w-6 h-6" gallery />
+                            }),
+                            IconType::Mini => EitherOf4::C(view! {
+                                <IconGrid icons=size_20_solid() class="w-4 h-4" gallery />
+                            }),
+                            IconType::Micro => EitherOf4::D(view! {
+                                <IconGrid icons=size_16_solid() class="w-3 h-3" gallery />
+                            }),
                         }}
                     </div>
                 </main>
             </div>
+
+            {move || copied.get().map(|name| view! {
+                <div class="fixed bottom-4 right-4 rounded-md bg-gray-900 px-4 py-2 text-sm text-white">
+                    "Copied " {name}
+                </div>
+            })}
         </div>
     }
 }
 
+/// The state shared by every icon of the gallery.
+#[derive(Clone, Copy)]
+struct Gallery {
+    query: ReadSignal<String>,
+    copy_mode: ReadSignal<CopyMode>,
+    set_copied: WriteSignal<Option<&'static str>>,
+}
+
 #[component]
 fn NavLink(
     #[prop()] icon_type: IconType,
@@ -91,80 +170,91 @@ fn NavLink(
 }
 
 #[component]
-fn Solid24IconGrid() -> impl IntoView {
-    let icons = size_24_solid()
-        .into_iter()
-        .map(|icon| {
-            view! { <IconCell class="w-6 h-6">{icon}</IconCell>}
-        })
-        .collect_view();
-
-    view! {
-        <IconGrid>{icons}</IconGrid>
-    }
-}
-
-#[component]
-fn Outline24IconGrid() -> impl IntoView {
-    let icons = size_24_outline()
-        .into_iter()
-        .map(|icon| {
-            view! { <IconCell class="w-6 h-6">{icon}</IconCell>}
-        })
-        .collect_view();
-
-    view! {
-        <IconGrid>{icons}</IconGrid>
-    }
-}
-
-#[component]
-fn Solid20IconGrid() -> impl IntoView {
-    let icons = size_20_solid()
-        .into_iter()
-        .map(|icon| {
-            view! { <IconCell class="w-4 h-4">{icon}</IconCell>}
-        })
-        .collect_view();
-
+fn CopyModeButton(
+    #[prop()] mode: CopyMode,
+    #[prop()] copy_mode: ReadSignal<CopyMode>,
+    #[prop()] set_copy_mode: WriteSignal<CopyMode>,
+) -> impl IntoView {
+    let label = match mode {
+        CopyMode::Snippet => "Copy snippet",
+        CopyMode::Feature => "Copy feature",
+    };
     view! {
-        <IconGrid>{icons}</IconGrid>
+        <button
+            class=move || {
+                if copy_mode.get() == mode {
+                    "rounded-md bg-indigo-50 px-2 py-1 text-sm font-medium text-indigo-700"
+                } else {
+                    "rounded-md px-2 py-1 text-sm font-medium text-gray-500 hover:text-gray-700"
+                }
+            }
+            on:click=move |_| set_copy_mode.set(mode)>
+            {label}
+        </button>
     }
 }
 
 #[component]
-fn Solid16IconGrid() -> impl IntoView {
-    let icons = size_16_solid()
+fn IconGrid(
+    icons: Vec<GalleryIcon>,
+    #[prop(into)] class: String,
+    gallery: Gallery,
+) -> impl IntoView {
+    let icons = icons
         .into_iter()
         .map(|icon| {
-            view! { <IconCell class="w-3 h-3">{icon}</IconCell>}
+            view! { <IconCell icon class=class.clone() gallery /> }
         })
         .collect_view();
 
-    view! {
-        <IconGrid>{icons}</IconGrid>
-    }
-}
-
-#[component]
-fn IconGrid(children: Children) -> impl IntoView {
     view! {
         <ul role="list" class="grid grid-cols-4 gap-x-4 gap-y-8 sm:grid-cols-8 sm:gap-x-6 lg:grid-cols-12 xl:gap-x-8">
-            {children()}
+            {icons}
         </ul>
     }
 }
 
 #[component]
 fn IconCell(
-    #[prop(optional, into)] class: Option<String>,
-    children: Children,
+    icon: GalleryIcon,
+    #[prop(into)] class: String,
+    gallery: Gallery,
 ) -> impl IntoView {
+    let Gallery {
+        query,
+        copy_mode,
+        set_copied,
+    } = gallery;
+    let copy = move |_| {
+        let text = icon.copy_text(copy_mode.get_untracked());
+        let _ = window().navigator().clipboard().write_text(&text);
+        set_copied.set(Some(icon.name));
+        set_timeout(
+            move || {
+                set_copied.update(|copied| {
+                    if *copied == Some(icon.name) {
+                        *copied = None;
+                    }
+                })
+            },
+            Duration::from_secs(2),
+        );
+    };
+
     view! {
-        <li class="relative flex flex-col items-center">
-            <div class=class>{children()}</div>
-            // TODO: list the name of the icon underneath it
-            // <p class="pointer-events-none block text-sm font-medium text-gray-500">"file.size"</p>
+        <li
+            class="relative flex flex-col items-center"
+            class:hidden=move || !icon.matches(&query.read())>
+            <button
+                type="button"
+                title=icon.component
+                class="rounded-md p-2 text-gray-900 hover:bg-gray-100"
+                on:click=copy>
+                <div class=class>{(icon.view)()}</div>
+            </button>
+            <p class="pointer-events-none mt-1 block text-center text-xs font-medium text-gray-500">
+                {icon.name}
+            </p>
         </li>
     }
 }
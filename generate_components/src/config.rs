@@ -0,0 +1,137 @@
+use std::fs;
+use std::path;
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+use crate::constants::*;
+use crate::template::Backend;
+
+/// Where to read icons from and what kind of crate to generate.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Root of the crate to write, i.e. where its `Cargo.toml` lives.
+    #[serde(rename = "output", default = "default_output_dir")]
+    pub output_dir: path::PathBuf,
+    /// Package name used when the output crate has no manifest yet.
+    #[serde(default = "default_crate_name")]
+    pub crate_name: String,
+    /// The framework to generate components for.
+    #[serde(default)]
+    pub backend: Backend,
+    /// The icon families to include.
+    #[serde(rename = "source")]
+    pub sources: Vec<Source>,
+}
+
+/// A family of icons, e.g. heroicons or a checkout of simple-icons.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Source {
+    /// Directory containing the SVG icons. Sources without one use the
+    /// latest heroicons, which the CLI downloads.
+    #[serde(rename = "input")]
+    pub input_dir: Option<path::PathBuf>,
+    /// Top-level module the family is generated into, e.g. `brands`. A
+    /// source without one is generated at the root of the crate.
+    pub module: Option<String>,
+    /// Prepended to every feature of the family, e.g. `brands` gives
+    /// `brands-github`.
+    #[serde(default)]
+    pub feature_prefix: String,
+    /// TOML file of search keywords, mapping icon names to lists of tags,
+    /// e.g. `trash = ["delete", "bin"]`.
+    pub tags: Option<path::PathBuf>,
+    /// TOML file of categories, mapping category names to lists of icon
+    /// names, e.g. `arrows = ["arrow-up", "arrow-down"]`. Every directory of
+    /// icons gets a feature per category enabling the icons it has, e.g.
+    /// `24-outline-arrows`.
+    pub categories: Option<path::PathBuf>,
+    /// TOML file of `[[transform]]` rules rewriting the attributes of every
+    /// icon, e.g. removing `data-slot`. See [`Transform`].
+    ///
+    /// [`Transform`]: crate::Transform
+    pub transforms: Option<path::PathBuf>,
+    /// TOML file overriding which icons are mirrored in right-to-left
+    /// layouts, besides the ones with `left` or `right` in their names, e.g.
+    /// `mirror = ["backspace"]` and `keep = ["arrows-right-left"]`.
+    pub rtl: Option<path::PathBuf>,
+}
+
+fn default_output_dir() -> path::PathBuf {
+    HEROICONS_CRATE_DIR.into()
+}
+
+fn default_crate_name() -> String {
+    HEROICONS_CRATE_NAME.to_string()
+}
+
+impl Config {
+    /// Reads a config file. Relative paths are resolved against the
+    /// directory the file is in.
+    pub fn from_file(path: &path::Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("reading {}", path.display()))?;
+        let mut config: Config = toml::from_str(&contents)
+            .with_context(|| format!("parsing {}", path.display()))?;
+
+        let base_dir = path.parent().unwrap_or(path::Path::new(""));
+        config.output_dir = base_dir.join(&config.output_dir);
+        for source in &mut config.sources {
+            if let Some(input_dir) = &mut source.input_dir {
+                *input_dir = base_dir.join(&input_dir);
+            }
+            if let Some(tags) = &mut source.tags {
+                *tags = base_dir.join(&tags);
+            }
+            if let Some(categories) = &mut source.categories {
+                *categories = base_dir.join(&categories);
+            }
+            if let Some(transforms) = &mut source.transforms {
+                *transforms = base_dir.join(&transforms);
+            }
+            if let Some(rtl) = &mut source.rtl {
+                *rtl = base_dir.join(&rtl);
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn lib_path(&self) -> path::PathBuf {
+        self.output_dir.join("src").join("lib.rs")
+    }
+
+    pub fn toml_path(&self) -> path::PathBuf {
+        self.output_dir.join("Cargo.toml")
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        if self.sources.is_empty() {
+            bail!("no icon sources configured");
+        }
+        if self.sources.iter().filter(|s| s.module.is_none()).count() > 1 {
+            bail!("only one source can be generated without a module");
+        }
+
+        let mut modules = vec![];
+        for source in &self.sources {
+            match &source.input_dir {
+                Some(input_dir) if !input_dir.is_dir() => {
+                    bail!("{} is not a directory", input_dir.display())
+                }
+                Some(_) => {}
+                None => bail!("a source has no input directory"),
+            }
+            if let Some(module) = &source.module {
+                if modules.contains(&module) {
+                    bail!("more than one source uses the `{module}` module");
+                }
+                modules.push(module);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -5,7 +5,9 @@ pub const ZIP_FILE: &str = "heroicons.zip";
 
 pub const UNZIPPED_DIR: &str = "heroicons-master";
 pub const OPTIMIZED_PATH: &str = "heroicons-master/optimized";
+pub const HEROICONS_TAGS_PATH: &str = "heroicons-tags.toml";
+pub const HEROICONS_RTL_PATH: &str = "heroicons-rtl.toml";
+pub const HEROICONS_CATEGORIES_PATH: &str = "heroicons-categories.toml";
 
-pub const LIB_PATH: &str = "../src/lib.rs";
-
-pub const TOML_PATH: &str = "../Cargo.toml";
+pub const HEROICONS_CRATE_DIR: &str = "..";
+pub const HEROICONS_CRATE_NAME: &str = "leptos_heroicons";
@@ -6,21 +6,23 @@ use std::path;
 
 use crate::constants::*;
 
-pub fn download_latest_icons() -> Result<()> {
+/// Downloads the heroicons archive into `dir`.
+pub fn download_latest_icons(dir: &path::Path) -> Result<()> {
     let client = Client::new();
     let mut response = client.get(HEROICONS_ARCHIVE_URL).send()?;
 
-    let mut zip_file = fs::File::create(ZIP_FILE)?;
+    let mut zip_file = fs::File::create(dir.join(ZIP_FILE))?;
     io::copy(&mut response, &mut zip_file)?;
 
     Ok(())
 }
 
-/// Extracts the zip archive.
+/// Extracts the zip archive in `dir`, leaving the icons in
+/// `dir.join(OPTIMIZED_PATH)`.
 /// See: https://github.com/zip-rs/zip/blob/master/examples/extract.rs
-pub fn extract_latest_icons() -> Result<()> {
-    let zip_file_name = path::Path::new(ZIP_FILE);
-    let zip_file = fs::File::open(zip_file_name).unwrap();
+pub fn extract_latest_icons(dir: &path::Path) -> Result<()> {
+    let zip_file_name = dir.join(ZIP_FILE);
+    let zip_file = fs::File::open(&zip_file_name).unwrap();
 
     let mut archive = zip::ZipArchive::new(zip_file).unwrap();
 
@@ -35,6 +37,7 @@ pub fn extract_latest_icons() -> Result<()> {
         if !outpath.starts_with(OPTIMIZED_PATH) {
             continue;
         }
+        let outpath = dir.join(outpath);
 
         println!("Extracting file {outpath:?}");
 
@@ -0,0 +1,625 @@
+//! Generates a crate of Leptos components from directories of SVG icons.
+//!
+//! Directories become `pub mod`s (numeric directories, such as heroicons'
+//! sizes, get a `size_` prefix) and every SVG file becomes a component gated
+//! by its own feature and by a feature for the directory it lives in. Each
+//! icon family can live in its own top-level module with its own feature
+//! prefix.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path;
+
+use anyhow::{bail, Context, Result};
+use convert_case::Casing;
+use indoc::formatdoc;
+use mirroring::Mirroring;
+
+mod config;
+mod constants;
+pub mod download_heroicons;
+mod mirroring;
+pub mod svg;
+mod template;
+mod transform;
+
+pub use config::*;
+pub use constants::*;
+use svg::AttributeValue;
+use template::indent_continuation;
+pub use template::{
+    Backend, Dioxus, DualIcon, Icon, IconEntry, Leptos, Sycamore, Template, Yew,
+};
+pub use transform::Transform;
+
+/// Writes the library and its features into the output crate, creating the
+/// crate if it doesn't exist yet.
+pub fn generate(config: &Config) -> Result<()> {
+    generate_with_template(config, config.backend.template())
+}
+
+/// Like [`generate`], but renders components with a custom template instead
+/// of the configured backend.
+pub fn generate_with_template(
+    config: &Config,
+    template: &dyn Template,
+) -> Result<()> {
+    config.validate()?;
+
+    let toml_path = config.toml_path();
+    let mut toml_table = if toml_path.exists() {
+        toml::from_str::<toml::Table>(&fs::read_to_string(&toml_path)?)?
+    } else {
+        new_manifest(&config.crate_name, template)
+    };
+
+    let lib_path = config.lib_path();
+    fs::create_dir_all(lib_path.parent().unwrap())?;
+    let mut lib_file = fs::File::create(&lib_path)?;
+    writeln!(lib_file, "{}\n", template.prelude())?;
+    if !template.header().is_empty() {
+        writeln!(lib_file, "{}", template.header())?;
+    }
+
+    // Replace the features of the previous run, keeping hand-written ones.
+    let mut features = match toml_table.remove("features") {
+        Some(toml::Value::Table(features)) => features,
+        _ => toml::Table::new(),
+    };
+    let mut generated = vec![];
+    for source in &config.sources {
+        generated.extend(feature_roots(source)?);
+    }
+    features.retain(|name, _| {
+        !generated
+            .iter()
+            .any(|root| name == root || name.starts_with(&format!("{root}-")))
+    });
+    for (feature, enables) in
+        [("default", &[][..])].iter().chain(template.features())
+    {
+        features.entry(feature.to_string()).or_insert_with(|| {
+            toml::Value::Array(
+                enables.iter().map(|&enable| enable.into()).collect(),
+            )
+        });
+    }
+    let optional_dependencies = template.optional_dependencies();
+    if !optional_dependencies.is_empty() {
+        let dependencies = toml_table
+            .entry("dependencies")
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+        if let toml::Value::Table(dependencies) = dependencies {
+            for (name, dependency) in optional_dependencies {
+                dependencies.entry(name).or_insert(dependency);
+            }
+        }
+    }
+
+    let mut icons = vec![];
+    for source in &config.sources {
+        let input_dir = source.input_dir.as_deref().unwrap();
+        let prep = Prep {
+            transforms: match &source.transforms {
+                Some(path) => Transform::from_file(path)?,
+                None => vec![],
+            },
+            mirroring: match &source.rtl {
+                Some(path) => Mirroring::from_file(path)?,
+                None => Mirroring::default(),
+            },
+        };
+        let scope = Scope {
+            modules: source.module.iter().cloned().collect(),
+            features: match source.feature_prefix.as_str() {
+                "" => vec![],
+                prefix => vec![prefix.to_string()],
+            },
+            size: None,
+            style: None,
+        };
+
+        let first_icon = icons.len();
+        match &source.module {
+            Some(module) => {
+                write_module_start(&mut lib_file, template, module, 0)?;
+                generate_library(
+                    &mut lib_file,
+                    template,
+                    &mut features,
+                    &mut icons,
+                    input_dir,
+                    &scope,
+                    &prep,
+                )?;
+                writeln!(lib_file, "}}\n")?;
+            }
+            None => generate_library(
+                &mut lib_file,
+                template,
+                &mut features,
+                &mut icons,
+                input_dir,
+                &scope,
+                &prep,
+            )?,
+        }
+        if let Some(tags) = &source.tags {
+            add_tags(&mut icons[first_icon..], tags)?;
+        }
+        if let Some(categories) = &source.categories {
+            add_categories(&mut features, &icons[first_icon..], categories)?;
+        }
+        if let Some(rtl) = &source.rtl {
+            let names = icons[first_icon..].iter().map(|icon| &icon.name[..]);
+            prep.mirroring.check(names, rtl)?;
+        }
+    }
+    write!(lib_file, "{}", template.registry(&icons)?)?;
+
+    toml_table.insert("features".to_string(), toml::Value::Table(features));
+
+    fs::write(&toml_path, toml::to_string(&toml_table)?)?;
+
+    Ok(())
+}
+
+fn new_manifest(crate_name: &str, template: &dyn Template) -> toml::Table {
+    let (dependency, version) = template.dependency();
+    let mut manifest = toml::toml! {
+        [package]
+        name = crate_name
+        version = "0.1.0"
+        edition = "2021"
+    };
+    manifest.insert(
+        "dependencies".to_string(),
+        toml::Value::Table(toml::Table::from_iter([(
+            dependency.to_string(),
+            version,
+        )])),
+    );
+    manifest
+}
+
+/// Where a directory of icons ends up in the generated crate.
+#[derive(Debug, Clone)]
+struct Scope {
+    /// The path of the directory's module, e.g. `["size_24", "solid"]`.
+    modules: Vec<String>,
+    /// The segments of the directory's feature, e.g. `["24", "solid"]`.
+    features: Vec<String>,
+    /// The innermost numeric directory, e.g. `24`.
+    size: Option<u32>,
+    /// The innermost other directory, e.g. `solid`.
+    style: Option<String>,
+}
+
+/// How the icons of a source are prepared before their components are
+/// written.
+struct Prep {
+    transforms: Vec<Transform>,
+    mirroring: Mirroring,
+}
+
+impl Prep {
+    /// Whether the icons are painted by the `duotone` rule.
+    fn duotone(&self) -> bool {
+        self.transforms.contains(&Transform::Duotone)
+    }
+}
+
+/// Recursively convert the icons directory into a library module.
+fn generate_library(
+    lib_file: &mut fs::File,
+    template: &dyn Template,
+    features: &mut toml::map::Map<String, toml::Value>,
+    icons: &mut Vec<IconEntry>,
+    curr: &path::Path,
+    scope: &Scope,
+    prep: &Prep,
+) -> Result<()> {
+    let indent = scope.modules.len() * 4;
+
+    // Sort the entries so that regenerating the library is deterministic.
+    let mut entries = fs::read_dir(curr)?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        let file_type = entry.file_type()?;
+
+        if file_type.is_dir() {
+            let dir_name = entry_name(&entry);
+            let mut inner = scope.clone();
+
+            // If we can parse the dir_name as an integer, then we need to add
+            // the `size_` prefix.
+            let module_name = match dir_name.parse::<u32>() {
+                Ok(size) => {
+                    inner.size = Some(size);
+                    format!("size_{dir_name}")
+                }
+                Err(_) => {
+                    inner.style = Some(dir_name.clone());
+                    dir_name.to_case(convert_case::Case::Snake)
+                }
+            };
+            inner.modules.push(module_name.clone());
+            inner.features.push(dir_name);
+
+            write_module_start(lib_file, template, &module_name, indent)?;
+            generate_library(
+                lib_file,
+                template,
+                features,
+                icons,
+                &entry.path(),
+                &inner,
+                prep,
+            )?;
+            writeln!(lib_file, "{:indent$}}}\n", "")?;
+        } else if entry.path().extension().is_some_and(|ext| ext == "svg") {
+            // We found an icon. Create a component.
+            let icon_name = path::Path::new(&entry_name(&entry))
+                .file_stem()
+                .unwrap()
+                .to_string_lossy()
+                .into_owned();
+            let (svg, default_class) =
+                get_and_prep_svg(&entry, &prep.transforms)
+                    .with_context(|| format!("{}", entry.path().display()))?;
+
+            // The feature path is the directory path we took to get to this
+            // component it's used to create a specific feature flag
+            // (e.g., 24-solid-banana) and also a high-level feature
+            // (e.g., 24-solid). Icons at the root of an unprefixed input only
+            // get the specific one.
+            let group_feature = scope.features.join("-");
+            let feature_name =
+                [&scope.features[..], std::slice::from_ref(&icon_name)]
+                    .concat()
+                    .join("-");
+            let component_name = component_name(&icon_name);
+            let cfg = feature_cfg(&feature_name, &group_feature);
+
+            let component = make_component(
+                template,
+                &Icon {
+                    component_name: &component_name,
+                    cfg: &cfg,
+                    svg: &svg,
+                    default_class: default_class.as_deref(),
+                    directional: prep.mirroring.is_directional(&icon_name),
+                    duotone: prep.duotone(),
+                },
+                indent,
+            )
+            .with_context(|| format!("{}", entry.path().display()))?;
+
+            if features
+                .insert(feature_name.clone(), toml::Value::Array(vec![]))
+                .is_some()
+            {
+                bail!(
+                    "the `{feature_name}` feature is generated more than once, \
+                     give the icon families different feature prefixes"
+                );
+            }
+            if !group_feature.is_empty() {
+                features.insert(group_feature, toml::Value::Array(vec![]));
+            }
+            write!(lib_file, "{}", component)?;
+
+            icons.push(IconEntry {
+                name: icon_name,
+                module: scope.modules.join("::"),
+                component_name,
+                size: scope.size,
+                style: scope.style.clone(),
+                view_box: view_box(&svg),
+                feature: feature_name,
+                tags: vec![],
+                cfg,
+            });
+        }
+    }
+
+    write_dual_components(lib_file, template, curr, scope, prep)?;
+
+    Ok(())
+}
+
+/// Writes a component switching between the styles of every icon that is in
+/// both the `outline` and `solid` directories of `curr`, if it has them.
+fn write_dual_components(
+    lib_file: &mut fs::File,
+    template: &dyn Template,
+    curr: &path::Path,
+    scope: &Scope,
+    prep: &Prep,
+) -> Result<()> {
+    let icon_names = |style: &str| -> Result<Vec<String>> {
+        let dir = curr.join(style);
+        if !dir.is_dir() {
+            return Ok(vec![]);
+        }
+        let mut names = vec![];
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "svg") {
+                names.push(path.file_stem().unwrap().to_string_lossy().into());
+            }
+        }
+        names.sort();
+        Ok(names)
+    };
+    let solid_names = icon_names("solid")?;
+    let style_cfg = |style: &str, icon_name: &str| {
+        let group = [&scope.features[..], &[style.to_string()]].concat();
+        let feature = [&group[..], &[icon_name.to_string()]].concat();
+        format!(
+            "any(feature = \"{}\", feature = \"{}\")",
+            feature.join("-"),
+            group.join("-")
+        )
+    };
+
+    for icon_name in icon_names("outline")? {
+        if !solid_names.contains(&icon_name) {
+            continue;
+        }
+        let cfg = format!(
+            "#[cfg(all({}, {}))]",
+            style_cfg("outline", &icon_name),
+            style_cfg("solid", &icon_name)
+        );
+        let component = template.dual_component(&DualIcon {
+            component_name: &component_name(&icon_name),
+            cfg: &cfg,
+            outline_module: "outline",
+            solid_module: "solid",
+            duotone: prep.duotone(),
+        })?;
+        if component.is_empty() {
+            continue;
+        }
+        let indent = scope.modules.len() * 4;
+        let component: String =
+            component.split('\n').map(indent_line(indent)).collect();
+        write!(lib_file, "{}", component)?;
+    }
+
+    Ok(())
+}
+
+/// The names that every feature generated for a source starts with.
+fn feature_roots(source: &Source) -> Result<Vec<String>> {
+    if !source.feature_prefix.is_empty() {
+        return Ok(vec![source.feature_prefix.clone()]);
+    }
+
+    let input_dir = source.input_dir.as_deref().unwrap();
+    let mut roots = vec![];
+    for entry in fs::read_dir(input_dir)? {
+        let path = entry?.path();
+        if path.is_dir() || path.extension().is_some_and(|ext| ext == "svg") {
+            roots
+                .push(path.file_stem().unwrap().to_string_lossy().into_owned());
+        }
+    }
+    Ok(roots)
+}
+
+/// Reads a tags file and gives every icon the tags listed for its name.
+fn add_tags(icons: &mut [IconEntry], path: &path::Path) -> Result<()> {
+    let tags: BTreeMap<String, Vec<String>> =
+        toml::from_str(&fs::read_to_string(path)?)
+            .with_context(|| format!("parsing {}", path.display()))?;
+
+    for (name, tags) in tags {
+        // `search` lowercases its query, so the tags are lowercased to match.
+        let tags: Vec<String> =
+            tags.iter().map(|tag| tag.to_lowercase()).collect();
+        let mut found = false;
+        for icon in icons.iter_mut().filter(|icon| icon.name == name) {
+            icon.tags.clone_from(&tags);
+            found = true;
+        }
+        if !found {
+            bail!(
+                "{} has tags for `{name}`, which isn't an icon",
+                path.display()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads a categories file and adds a feature for every category of every
+/// directory, enabling the icons of the category in it, e.g.
+/// `24-outline-arrows`. Icons at the root of an unprefixed input have no
+/// directory feature, so they aren't in any.
+fn add_categories(
+    features: &mut toml::Table,
+    icons: &[IconEntry],
+    path: &path::Path,
+) -> Result<()> {
+    let categories: BTreeMap<String, Vec<String>> =
+        toml::from_str(&fs::read_to_string(path)?)
+            .with_context(|| format!("parsing {}", path.display()))?;
+
+    for (category, names) in categories {
+        if let Some(name) = names
+            .iter()
+            .find(|name| !icons.iter().any(|icon| icon.name == **name))
+        {
+            bail!(
+                "{} lists `{name}` in `{category}`, which isn't an icon",
+                path.display()
+            );
+        }
+
+        let mut bundles: BTreeMap<String, Vec<toml::Value>> = BTreeMap::new();
+        for icon in icons.iter().filter(|icon| names.contains(&icon.name)) {
+            if let Some(group) =
+                icon.feature.strip_suffix(&format!("-{}", icon.name))
+            {
+                bundles
+                    .entry(format!("{group}-{category}"))
+                    .or_default()
+                    .push(icon.feature.clone().into());
+            }
+        }
+        for (feature, enables) in bundles {
+            if features
+                .insert(feature.clone(), toml::Value::Array(enables))
+                .is_some()
+            {
+                bail!(
+                    "the `{feature}` feature of the `{category}` category is \
+                     already a feature, rename the category"
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn write_module_start(
+    lib_file: &mut fs::File,
+    template: &dyn Template,
+    module_name: &str,
+    indent: usize,
+) -> Result<()> {
+    let module_start = formatdoc! {r#"
+        pub mod {module_name} {{
+            {prelude}"#, prelude = indent_continuation(template.prelude(), 4)};
+    let module_start: String =
+        module_start.split('\n').map(indent_line(indent)).collect();
+
+    writeln!(lib_file, "{}", module_start)?;
+    Ok(())
+}
+
+fn entry_name(entry: &fs::DirEntry) -> String {
+    entry.file_name().to_string_lossy().into_owned()
+}
+
+/// Reads an icon file and prepares it with [`prep_svg`].
+fn get_and_prep_svg(
+    entry: &fs::DirEntry,
+    transforms: &[Transform],
+) -> Result<(svg::SvgElement, Option<String>)> {
+    prep_svg(&fs::read_to_string(entry.path())?, transforms)
+}
+
+/// Parses an icon and applies the source's transforms, returning it without
+/// its `class` attribute, which becomes the default for the component's
+/// `class` prop.
+fn prep_svg(
+    source: &str,
+    transforms: &[Transform],
+) -> Result<(svg::SvgElement, Option<String>)> {
+    let mut svg = svg::parse(source)?;
+    for transform in transforms {
+        transform.apply(&mut svg);
+    }
+
+    let default_class = match svg.remove_attribute("class") {
+        Some(AttributeValue::Literal(class)) => Some(class),
+        _ => None,
+    };
+
+    Ok((svg, default_class))
+}
+
+fn view_box(svg: &svg::SvgElement) -> String {
+    svg.attributes
+        .iter()
+        .find_map(|attribute| match (&attribute.name[..], &attribute.value) {
+            ("viewBox", AttributeValue::Literal(value)) => Some(value.clone()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+fn make_component(
+    template: &dyn Template,
+    icon: &Icon,
+    indent: usize,
+) -> Result<String> {
+    let component = template.component(icon)?;
+
+    Ok(component.split('\n').map(indent_line(indent)).collect())
+}
+
+fn feature_cfg(feature_name: &str, group_feature: &str) -> String {
+    match group_feature {
+        "" => format!("#[cfg(feature = \"{feature_name}\")]"),
+        _ => format!(
+            "#[cfg(any(feature = \"{feature_name}\", feature = \"{group_feature}\"))]"
+        ),
+    }
+}
+
+/// Converts an icon's file name into a component name, prefixing names that
+/// would otherwise start with a digit.
+fn component_name(icon_name: &str) -> String {
+    let name = icon_name.to_case(convert_case::Case::UpperCamel);
+    match name.starts_with(|c: char| c.is_ascii_digit()) {
+        true => format!("Icon{name}"),
+        false => name,
+    }
+}
+
+fn indent_line(indent: usize) -> impl FnMut(&str) -> String {
+    move |line| match line {
+        "" => "\n".to_string(),
+        _ => format!("{:indent$}{line}\n", ""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn upstream_class_becomes_the_default_class() {
+        let source = r#"<svg xmlns="http://www.w3.org/2000/svg" class="size-6 shrink-0" viewBox="0 0 24 24"><path d="M0 0h24"/></svg>"#;
+        let (svg, default_class) = prep_svg(source, &[]).unwrap();
+        assert_eq!(default_class.as_deref(), Some("size-6 shrink-0"));
+        assert!(svg.attributes.iter().all(|a| a.name != "class"));
+    }
+
+    #[test]
+    fn icons_without_a_class_have_no_default_class() {
+        let source = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><path d="M0 0h24"/></svg>"#;
+        let (_, default_class) = prep_svg(source, &[]).unwrap();
+        assert_eq!(default_class, None);
+    }
+
+    #[test]
+    fn tags_are_lowercased() {
+        let path = std::env::temp_dir()
+            .join(format!("generate_components_tags_{}", std::process::id()));
+        fs::write(&path, r#"trash = ["Delete", "BIN"]"#).unwrap();
+        let mut icons = vec![IconEntry {
+            name: "trash".into(),
+            module: "size_24::solid".into(),
+            component_name: "Trash".into(),
+            size: Some(24),
+            style: Some("solid".into()),
+            view_box: "0 0 24 24".into(),
+            feature: "24-solid-trash".into(),
+            tags: vec![],
+            cfg: String::new(),
+        }];
+        let added = add_tags(&mut icons, &path);
+        fs::remove_file(&path).unwrap();
+        added.unwrap();
+        assert_eq!(icons[0].tags, ["delete", "bin"]);
+    }
+}
@@ -1,157 +1,104 @@
 use std::fs;
-use std::io::Write;
 use std::path;
 
 use anyhow::Result;
-use convert_case::Casing;
-use indoc::formatdoc;
-
-mod constants;
-mod download_heroicons;
-
-use constants::*;
-use download_heroicons::*;
-
-fn main() -> Result<()> {
-    download_latest_icons()?;
-    extract_latest_icons()?;
-
-    let mut lib_file = fs::File::create(LIB_PATH)?;
-    let mut toml_table =
-        toml::from_str::<toml::Table>(&fs::read_to_string(TOML_PATH)?)?;
-
-    let mut features = toml::value::Table::from_iter([(
-        "default".to_string(),
-        toml::Value::Array(vec![]),
-    )]);
-
-    generate_library(&mut lib_file, &mut features, OPTIMIZED_PATH, 0)?;
-
-    toml_table.insert("features".to_string(), toml::Value::Table(features));
-
-    fs::write(TOML_PATH, toml::to_string(&toml_table)?)?;
-
-    // remove unzipped icon directory
-    fs::remove_dir_all(UNZIPPED_DIR)?;
-
-    Ok(())
+use clap::Parser;
+use generate_components::download_heroicons::*;
+use generate_components::*;
+
+/// Generates a crate of Leptos components from directories of SVG icons.
+///
+/// Without `--input` or `--config`, the latest heroicons are downloaded and
+/// written into this repository's `leptos_heroicons` crate.
+#[derive(Debug, Parser)]
+struct Args {
+    /// TOML file listing the icon families to generate, see the README.
+    #[arg(
+        long,
+        conflicts_with_all = ["input", "output", "crate_name", "feature_prefix", "tags", "categories", "transforms", "rtl", "backend"]
+    )]
+    config: Option<path::PathBuf>,
+    /// Directory of SVG icons. Sub-directories become modules.
+    #[arg(long)]
+    input: Option<path::PathBuf>,
+    /// Root directory of the crate to generate.
+    #[arg(long, default_value = HEROICONS_CRATE_DIR)]
+    output: path::PathBuf,
+    /// Package name used when the output crate doesn't exist yet.
+    #[arg(long, default_value = HEROICONS_CRATE_NAME)]
+    crate_name: String,
+    /// Prefix for every generated feature, e.g. `ourco`.
+    #[arg(long, default_value = "")]
+    feature_prefix: String,
+    /// TOML file of search keywords for the icons, see the README. Defaults
+    /// to this crate's heroicons tags when they are downloaded.
+    #[arg(long)]
+    tags: Option<path::PathBuf>,
+    /// TOML file of icon categories, each becoming a feature of every
+    /// directory, see the README. Defaults to this crate's heroicons
+    /// categories when they are downloaded.
+    #[arg(long)]
+    categories: Option<path::PathBuf>,
+    /// TOML file of rules rewriting the attributes of every icon, see the
+    /// README.
+    #[arg(long)]
+    transforms: Option<path::PathBuf>,
+    /// TOML file overriding which icons are mirrored in right-to-left
+    /// layouts, see the README. Defaults to this crate's heroicons overrides
+    /// when they are downloaded.
+    #[arg(long)]
+    rtl: Option<path::PathBuf>,
+    /// The framework to generate components for.
+    #[arg(long, value_enum, default_value_t)]
+    backend: Backend,
 }
 
-/// Recursively convert the downloaded icons directory into a library module.
-fn generate_library(
-    lib_file: &mut fs::File,
-    features: &mut toml::map::Map<String, toml::Value>,
-    curr: &str,
-    depth: usize,
-) -> Result<()> {
-    let indent = depth * 4;
-
-    for entry_result in fs::read_dir(curr)? {
-        let entry = entry_result?;
-        let file_type = entry.file_type()?;
-
-        if file_type.is_dir() {
-            let dir_name = entry_name(&entry);
-
-            // If we can parse the dir_name as an integer, then we need to add
-            // the `size_` prefix.
-            let module_name = match dir_name.parse::<i32>() {
-                Ok(_) => format!("size_{dir_name}"),
-                Err(_) => dir_name,
-            };
-
-            let module_start = formatdoc! {r#"
-                pub mod {module_name} {{
-                    #[allow(unused_imports)]
-                    use leptos::prelude::*;"#};
-            let module_start: String =
-                module_start.split('\n').map(indent_line(indent)).collect();
-
-            writeln!(lib_file, "{}", module_start)?;
-            generate_library(
-                lib_file,
-                features,
-                &entry.path().to_string_lossy().to_owned(),
-                depth + 1,
-            )?;
-            writeln!(lib_file, "{}\n", format!("{:indent$}}}", ""))?;
-        } else {
-            // We found an icon. Create a component.
-            let icon_name = path::Path::new(&entry_name(&entry))
-                .file_stem()
-                .unwrap()
-                .to_string_lossy()
-                .into_owned();
-            let svg = get_and_prep_svg(&entry, indent)?;
-
-            // The feature path is the directory path we took to get to this
-            // component it's used to create a specific feature flag
-            // (e.g., 24-solid-banana) and also a high-level feature
-            // (e.g., 24-solid).
-            let feature_path = entry
-                .path()
-                .parent()
-                .unwrap()
-                .to_string_lossy()
-                .into_owned()
-                .strip_prefix(&format!("{}/", OPTIMIZED_PATH))
-                .unwrap()
-                .replace("/", "-");
-
-            let component =
-                make_component(&icon_name, &feature_path, &svg, indent);
-
-            features.insert(
-                format!("{feature_path}-{icon_name}"),
-                toml::Value::Array(vec![]),
-            );
-            features.insert(feature_path, toml::Value::Array(vec![]));
-            write!(lib_file, "{}", component)?;
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    let mut config = match args.config {
+        Some(path) => Config::from_file(&path)?,
+        None => Config {
+            output_dir: args.output,
+            crate_name: args.crate_name,
+            backend: args.backend,
+            sources: vec![Source {
+                input_dir: args.input,
+                module: None,
+                feature_prefix: args.feature_prefix,
+                tags: args.tags,
+                categories: args.categories,
+                transforms: args.transforms,
+                rtl: args.rtl,
+            }],
+        },
+    };
+
+    let download = config.sources.iter().any(|s| s.input_dir.is_none());
+    if download {
+        download_latest_icons(path::Path::new(""))?;
+        extract_latest_icons(path::Path::new(""))?;
+
+        for source in &mut config.sources {
+            if source.input_dir.is_none() {
+                source.input_dir = Some(OPTIMIZED_PATH.into());
+                source
+                    .tags
+                    .get_or_insert_with(|| HEROICONS_TAGS_PATH.into());
+                source
+                    .categories
+                    .get_or_insert_with(|| HEROICONS_CATEGORIES_PATH.into());
+                source.rtl.get_or_insert_with(|| HEROICONS_RTL_PATH.into());
+            }
         }
     }
 
-    Ok(())
-}
-
-fn entry_name(entry: &fs::DirEntry) -> String {
-    entry.file_name().to_string_lossy().into_owned()
-}
-
-fn get_and_prep_svg(entry: &fs::DirEntry, indent: usize) -> Result<String> {
-    let svg = fs::read_to_string(entry.path())?;
-    let svg = svg.trim();
+    generate(&config)?;
 
-    // add the ability to override the classes via props
-    let re = regex::Regex::new(r"<svg ").unwrap();
-    let svg = re.replace(svg, "<svg class=class ");
-
-    let indented_svg: String =
-        svg.split('\n').map(indent_line(indent)).collect();
-
-    Ok(indented_svg.trim().to_string())
-}
-
-fn make_component(
-    icon_name: &str,
-    feature_path: &str,
-    svg: &str,
-    indent: usize,
-) -> String {
-    let component_name = icon_name.to_case(convert_case::Case::UpperCamel);
-    let feature_name = format!("{feature_path}-{icon_name}");
-    let component = formatdoc! {r#"
-        #[cfg(any(feature = "{feature_name}", feature = "{feature_path}"))]
-        #[component]
-        pub fn {component_name}(#[prop(optional)] class: &'static str) -> impl IntoView {{
-            view! {{
-                {svg}
-            }}
-        }}
-    "#};
-
-    component.split('\n').map(indent_line(indent)).collect()
-}
+    if download {
+        // remove unzipped icon directory
+        fs::remove_dir_all(UNZIPPED_DIR)?;
+    }
 
-fn indent_line(indent: usize) -> impl FnMut(&str) -> String {
-    move |line| format!("{:indent$}{line}\n", "")
+    Ok(())
 }
@@ -0,0 +1,58 @@
+use std::fs;
+use std::path;
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+/// Which icons of a source point left or right, and are mirrored in
+/// right-to-left layouts: the ones with `left` or `right` in their names,
+/// e.g. `arrow-left` or `chevron-double-right`, with overrides.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Mirroring {
+    /// Icons mirrored although their names don't say so, e.g. `backspace`.
+    #[serde(default)]
+    mirror: Vec<String>,
+    /// Icons not mirrored although their names do.
+    #[serde(default)]
+    keep: Vec<String>,
+}
+
+impl Mirroring {
+    /// Reads the overrides of a mirroring file.
+    pub(crate) fn from_file(path: &path::Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("reading {}", path.display()))?;
+        toml::from_str(&contents)
+            .with_context(|| format!("parsing {}", path.display()))
+    }
+
+    /// Whether an icon, by file name, is mirrored.
+    pub(crate) fn is_directional(&self, icon_name: &str) -> bool {
+        let by_name = icon_name
+            .split('-')
+            .any(|word| word == "left" || word == "right");
+        match by_name {
+            true => !self.keep.iter().any(|name| name == icon_name),
+            false => self.mirror.iter().any(|name| name == icon_name),
+        }
+    }
+
+    /// Fails if an override isn't one of the icons.
+    pub(crate) fn check<'a>(
+        &self,
+        icon_names: impl Iterator<Item = &'a str> + Clone,
+        path: &path::Path,
+    ) -> Result<()> {
+        for name in self.mirror.iter().chain(&self.keep) {
+            if !icon_names.clone().any(|icon_name| icon_name == name) {
+                bail!(
+                    "{} overrides the mirroring of `{name}`, which isn't an \
+                     icon",
+                    path.display()
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,224 @@
+use anyhow::{bail, Context, Result};
+
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
+/// An SVG element parsed from an icon file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgElement {
+    pub name: String,
+    pub attributes: Vec<Attribute>,
+    pub children: Vec<SvgNode>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SvgNode {
+    Element(SvgElement),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attribute {
+    pub name: String,
+    pub value: AttributeValue,
+}
+
+/// The value of an attribute as it will appear in the `view!` macro.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttributeValue {
+    /// A string literal, e.g. `fill="none"`.
+    Literal(String),
+    /// A Rust expression, e.g. `class=class`.
+    Expr(String),
+}
+
+impl Attribute {
+    pub fn literal(name: &str, value: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            value: AttributeValue::Literal(value.to_string()),
+        }
+    }
+
+    pub fn expr(name: &str, expr: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            value: AttributeValue::Expr(expr.to_string()),
+        }
+    }
+}
+
+/// Parses an icon file into an element tree.
+///
+/// Comments, processing instructions and whitespace between elements are
+/// dropped. The root must be an `<svg>` element with a `viewBox`.
+pub fn parse(source: &str) -> Result<SvgElement> {
+    let document = roxmltree::Document::parse(source).context("invalid XML")?;
+    let root = document.root_element();
+
+    if root.tag_name().name() != "svg" {
+        bail!(
+            "expected an <svg> root element, found <{}>",
+            root.tag_name().name()
+        );
+    }
+    if root.tag_name().namespace() != Some(SVG_NAMESPACE) {
+        bail!("the <svg> root element must use the {SVG_NAMESPACE} namespace");
+    }
+    if !root.has_attribute("viewBox") {
+        bail!("the <svg> root element has no viewBox");
+    }
+
+    let mut svg = convert_element(root)?;
+    // Namespace declarations are not attributes in the XML tree, so restore
+    // the one we validated above. The view is rendered as HTML, where the
+    // attribute is what tells standalone consumers that this is SVG.
+    svg.attributes
+        .insert(0, Attribute::literal("xmlns", SVG_NAMESPACE));
+
+    Ok(svg)
+}
+
+fn convert_element(node: roxmltree::Node) -> Result<SvgElement> {
+    let name = node.tag_name().name().to_string();
+    if node.tag_name().namespace() != Some(SVG_NAMESPACE) {
+        bail!("<{name}> is not an SVG element");
+    }
+
+    let attributes = node
+        .attributes()
+        .map(|attribute| {
+            if attribute.namespace().is_some() {
+                bail!(
+                    "namespaced attribute `{}` on <{name}> is not supported",
+                    attribute.name()
+                );
+            }
+            Ok(Attribute::literal(attribute.name(), attribute.value()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut children = vec![];
+    for child in node.children() {
+        if child.is_element() {
+            children.push(SvgNode::Element(convert_element(child)?));
+        } else if child.is_text() {
+            let text = child.text().unwrap_or_default().trim();
+            if !text.is_empty() {
+                children.push(SvgNode::Text(text.to_string()));
+            }
+        }
+    }
+
+    Ok(SvgElement {
+        name,
+        attributes,
+        children,
+    })
+}
+
+impl SvgElement {
+    /// Removes an attribute, returning its value if it was present.
+    pub fn remove_attribute(&mut self, name: &str) -> Option<AttributeValue> {
+        let index = self
+            .attributes
+            .iter()
+            .position(|attribute| attribute.name == name)?;
+        Some(self.attributes.remove(index).value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(source: &str) -> String {
+        format!("{:#}", parse(source).unwrap_err())
+    }
+
+    #[test]
+    fn drops_comments_and_whitespace() {
+        let source = r#"<?xml version="1.0"?>
+<!-- Exported by an editor -->
+<svg
+    xmlns="http://www.w3.org/2000/svg"
+    viewBox="0 0 24 24"   fill="none"
+>
+    <!-- The outline -->
+    <title>
+        Bell
+    </title>
+    <path   d="M0 0h24" />
+</svg>
+"#;
+        let svg = parse(source).unwrap();
+        assert_eq!(
+            svg,
+            SvgElement {
+                name: "svg".into(),
+                attributes: vec![
+                    Attribute::literal("xmlns", SVG_NAMESPACE),
+                    Attribute::literal("viewBox", "0 0 24 24"),
+                    Attribute::literal("fill", "none"),
+                ],
+                children: vec![
+                    SvgNode::Element(SvgElement {
+                        name: "title".into(),
+                        attributes: vec![],
+                        children: vec![SvgNode::Text("Bell".into())],
+                    }),
+                    SvgNode::Element(SvgElement {
+                        name: "path".into(),
+                        attributes: vec![Attribute::literal("d", "M0 0h24")],
+                        children: vec![],
+                    }),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn keeps_the_class_attribute() {
+        let source = r#"<svg xmlns="http://www.w3.org/2000/svg" class="size-6" viewBox="0 0 24 24"/>"#;
+        let mut svg = parse(source).unwrap();
+        assert_eq!(
+            svg.remove_attribute("class"),
+            Some(AttributeValue::Literal("size-6".into()))
+        );
+    }
+
+    #[test]
+    fn rejects_other_roots() {
+        let source = r#"<g xmlns="http://www.w3.org/2000/svg"/>"#;
+        assert!(
+            error(source).contains("expected an <svg> root element, found <g>")
+        );
+    }
+
+    #[test]
+    fn rejects_a_missing_or_wrong_namespace() {
+        let missing = r#"<svg viewBox="0 0 24 24"/>"#;
+        assert!(error(missing).contains("must use the"));
+        let wrong =
+            r#"<svg xmlns="http://example.com/svg" viewBox="0 0 24 24"/>"#;
+        assert!(error(wrong).contains("must use the"));
+        let child = r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:x="http://example.com" viewBox="0 0 24 24"><x:path/></svg>"#;
+        assert!(error(child).contains("<path> is not an SVG element"));
+    }
+
+    #[test]
+    fn rejects_a_missing_view_box() {
+        let source = r#"<svg xmlns="http://www.w3.org/2000/svg" width="24"/>"#;
+        assert!(error(source).contains("has no viewBox"));
+    }
+
+    #[test]
+    fn rejects_namespaced_attributes() {
+        let source = r##"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" viewBox="0 0 24 24"><use xlink:href="#a"/></svg>"##;
+        assert!(error(source).contains("namespaced attribute `href` on <use>"));
+    }
+
+    #[test]
+    fn rejects_invalid_xml() {
+        assert!(error("<svg").contains("invalid XML"));
+    }
+}
@@ -0,0 +1,1439 @@
+use anyhow::{bail, Context, Result};
+use clap::ValueEnum;
+use convert_case::Casing;
+use indoc::formatdoc;
+use serde::Deserialize;
+
+use crate::svg::{Attribute, AttributeValue, SvgElement, SvgNode};
+
+/// An icon that is ready to be turned into a component.
+#[derive(Debug)]
+pub struct Icon<'a> {
+    pub component_name: &'a str,
+    /// The `#[cfg(...)]` attribute gating the icon.
+    pub cfg: &'a str,
+    /// The parsed icon, without its `class` attribute.
+    pub svg: &'a SvgElement,
+    /// The icon's own `class`, which becomes the default for the `class`
+    /// prop.
+    pub default_class: Option<&'a str>,
+    /// Whether the icon points left or right, and is mirrored in
+    /// right-to-left layouts.
+    pub directional: bool,
+    /// Whether the icon was painted by the `duotone` rule, so its component
+    /// takes the two colors.
+    pub duotone: bool,
+}
+
+/// An icon that is in both the `outline` and the `solid` directory of the
+/// module the component is written to.
+#[derive(Debug)]
+pub struct DualIcon<'a> {
+    pub component_name: &'a str,
+    /// The `#[cfg(...)]` attribute gating the component, which requires both
+    /// styles of the icon.
+    pub cfg: &'a str,
+    /// The module of the outline icon, relative to the component's.
+    pub outline_module: &'a str,
+    /// The module of the solid icon, relative to the component's.
+    pub solid_module: &'a str,
+    /// Whether the icons were painted by the `duotone` rule, so the component
+    /// takes the two colors.
+    pub duotone: bool,
+}
+
+/// An icon's entry in the generated crate's icon table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IconEntry {
+    /// The icon's file name, e.g. `arrow-down`.
+    pub name: String,
+    /// The path of the component's module, e.g. `size_24::solid`.
+    pub module: String,
+    pub component_name: String,
+    /// The size directory the icon is in, e.g. `24`.
+    pub size: Option<u32>,
+    /// The innermost non-numeric directory the icon is in, e.g. `solid`.
+    pub style: Option<String>,
+    pub view_box: String,
+    /// The icon's own feature, e.g. `24-solid-arrow-down`.
+    pub feature: String,
+    /// Search keywords for the icon besides its name.
+    pub tags: Vec<String>,
+    /// The `#[cfg(...)]` attribute gating the icon.
+    pub cfg: String,
+}
+
+/// Renders icons as components for a UI framework.
+pub trait Template {
+    /// Imports at the top of the library and of every module.
+    fn prelude(&self) -> &'static str;
+
+    /// Items written once at the top of the library, after the prelude.
+    fn header(&self) -> &'static str {
+        ""
+    }
+
+    /// Features the header refers to, with the features and dependencies
+    /// they enable, added to the generated crate's features.
+    fn features(&self) -> &'static [(&'static str, &'static [&'static str])] {
+        &[]
+    }
+
+    /// Optional dependencies the features enable, added to the generated
+    /// crate's `[dependencies]`.
+    fn optional_dependencies(&self) -> Vec<(&'static str, toml::Value)> {
+        vec![]
+    }
+
+    /// The framework dependency for newly created crates, as a
+    /// `[dependencies]` entry.
+    fn dependency(&self) -> (&'static str, toml::Value);
+
+    /// Renders a component and anything it needs, each gated by the icon's
+    /// `cfg` attribute. The generator adds the indentation.
+    fn component(&self, icon: &Icon) -> Result<String>;
+
+    /// Renders a component showing either style of a [`DualIcon`]. Templates
+    /// without one render nothing.
+    fn dual_component(&self, _icon: &DualIcon) -> Result<String> {
+        Ok(String::new())
+    }
+
+    /// Items written once at the end of the library, listing every icon.
+    fn registry(&self, _icons: &[IconEntry]) -> Result<String> {
+        Ok(String::new())
+    }
+}
+
+/// The frameworks components can be generated for.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ValueEnum,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Backend {
+    #[default]
+    Leptos,
+    Yew,
+    Dioxus,
+    Sycamore,
+}
+
+impl Backend {
+    pub fn template(self) -> &'static dyn Template {
+        match self {
+            Backend::Leptos => &Leptos,
+            Backend::Yew => &Yew,
+            Backend::Dioxus => &Dioxus,
+            Backend::Sycamore => &Sycamore,
+        }
+    }
+}
+
+/// Renders every icon through one shared function, with the SVG data in a
+/// `static`, so the `view!` macro only expands once per crate.
+pub struct Leptos;
+
+impl Template for Leptos {
+    fn prelude(&self) -> &'static str {
+        "#[allow(unused_imports)]\nuse leptos::prelude::*;"
+    }
+
+    fn header(&self) -> &'static str {
+        concat!(
+            include_str!("../templates/leptos_header.rs"),
+            include_str!("../templates/leptos_render.rs"),
+            include_str!("../templates/leptos_picker.rs"),
+            include_str!("../templates/leptos_animations.rs"),
+            include_str!("../templates/leptos_stack.rs"),
+            include_str!("../templates/leptos_data_uri.rs"),
+            include_str!("../templates/leptos_maud.rs"),
+            include_str!("../templates/leptos_askama.rs"),
+        )
+    }
+
+    fn features(&self) -> &'static [(&'static str, &'static [&'static str])] {
+        &[
+            ("icon-picker", &[]),
+            ("animations", &[]),
+            ("stack", &[]),
+            ("data-uri", &[]),
+            ("ssr", &["leptos/ssr"]),
+            ("maud", &["dep:maud", "ssr"]),
+            ("askama", &["dep:askama", "ssr"]),
+        ]
+    }
+
+    fn optional_dependencies(&self) -> Vec<(&'static str, toml::Value)> {
+        vec![
+            (
+                "maud",
+                toml::toml! {
+                    version = "0.27"
+                    optional = true
+                }
+                .into(),
+            ),
+            (
+                "askama",
+                toml::toml! {
+                    version = "0.16"
+                    optional = true
+                }
+                .into(),
+            ),
+        ]
+    }
+
+    fn dependency(&self) -> (&'static str, toml::Value) {
+        ("leptos", toml::toml! { version = "0.8" }.into())
+    }
+
+    fn component(&self, icon: &Icon) -> Result<String> {
+        let Icon {
+            component_name,
+            cfg,
+            default_class,
+            duotone,
+            ..
+        } = icon;
+        let data_name = data_name(component_name);
+        let LeptosIcon { data, shapes } =
+            leptos_icon(icon.svg, icon.directional)?;
+        // Only duotone icons take the colors, which do nothing on others.
+        let (props_type, render_fn) = match duotone {
+            true => ("DuotoneIconProps", "render_duotone_icon"),
+            false => ("IconProps", "render_icon_with"),
+        };
+        let render = match shapes {
+            None => format!("crate::{render_fn}(&{data_name}, props)"),
+            Some(shapes) => formatdoc! {"
+                crate::{render_fn}_with_shapes(
+                    &{data_name},
+                    props,
+                    view! {{
+                        {shapes}
+                    }},
+                )",
+                shapes = indent_continuation(&shapes, 8),
+            },
+        };
+        // Components share their props rather than generating their own.
+        let (props, default_class) = match default_class {
+            Some(class) => (
+                "mut props",
+                formatdoc! {"
+                    if props.class.is_empty() {{
+                        props.class = {class:?};
+                    }}
+                "},
+            ),
+            None => ("props", String::new()),
+        };
+
+        Ok(formatdoc! {r#"
+            /// The SVG data of [`{component_name}`].
+            {cfg}
+            pub static {data_name}: crate::IconData = {data};
+
+            /// Renders [`{data_name}`], with the props of [`crate::{props_type}`].
+            {cfg}
+            #[allow(non_snake_case)]
+            pub fn {component_name}({props}: crate::{props_type}) -> impl IntoView {{
+                {default_class}{render}
+            }}
+        "#,
+            default_class = indent_continuation(&default_class, 4),
+            render = indent_continuation(&render, 4),
+        })
+    }
+
+    fn dual_component(&self, icon: &DualIcon) -> Result<String> {
+        let DualIcon {
+            component_name,
+            cfg,
+            outline_module,
+            solid_module,
+            duotone,
+        } = icon;
+        let data_name = data_name(component_name);
+        let (props_type, render_fn) = match duotone {
+            true => ("DualDuotoneIconProps", "render_dual_duotone_icon"),
+            false => ("DualIconProps", "render_dual_icon"),
+        };
+
+        Ok(formatdoc! {r#"
+            /// [`{solid_module}::{component_name}`] while `solid` is true and
+            /// [`{outline_module}::{component_name}`] otherwise, with the props
+            /// of [`crate::{props_type}`].
+            {cfg}
+            #[allow(non_snake_case)]
+            pub fn {component_name}(props: crate::{props_type}) -> impl IntoView {{
+                crate::{render_fn}(
+                    &{outline_module}::{data_name},
+                    &{solid_module}::{data_name},
+                    props,
+                )
+            }}
+        "#})
+    }
+
+    fn registry(&self, icons: &[IconEntry]) -> Result<String> {
+        let entries: String = icons
+            .iter()
+            .map(|icon| {
+                let IconEntry {
+                    name,
+                    module,
+                    component_name,
+                    size,
+                    style,
+                    view_box,
+                    feature,
+                    tags,
+                    cfg,
+                } = icon;
+                let data = match module.as_str() {
+                    "" => data_name(component_name),
+                    _ => format!("{module}::{}", data_name(component_name)),
+                };
+
+                formatdoc! {r#"
+                        {cfg}
+                        IconMeta {{
+                            name: {name:?},
+                            module: {module:?},
+                            component: {component_name:?},
+                            size: {size:?},
+                            style: {style},
+                            view_box: {view_box:?},
+                            feature: {feature:?},
+                            tags: &{tags:?},
+                            data: &{data},
+                        }},
+                    "#,
+                    style = match style {
+                        Some(style) => format!("Some({style:?})"),
+                        None => "None".to_string(),
+                    },
+                }
+            })
+            .collect();
+
+        let entries: String = entries
+            .lines()
+            .map(|line| format!("\n    {line}"))
+            .collect();
+
+        Ok(formatdoc! {r#"
+            /// Every icon enabled by the crate's features, in module order.
+            pub const ICONS: &[IconMeta] = &[{entries}
+            ];
+        "#})
+    }
+}
+
+/// The name of the `static` holding an icon's data, e.g. `BOLT_SLASH`.
+pub fn data_name(component_name: &str) -> String {
+    component_name.to_case(convert_case::Case::UpperSnake)
+}
+
+/// The fields of `IconData` only set for icons that need them, which are
+/// otherwise filled in from `IconData::EMPTY`.
+const ICON_DATA_OPTIONAL_FIELDS: [&str; 8] = [
+    "fill",
+    "stroke",
+    "stroke_width",
+    "aria_hidden",
+    "data_slot",
+    "attributes",
+    "directional",
+    "markup",
+];
+
+/// The fields of `IconPath`, which are filled in from `IconPath::EMPTY` when
+/// some are missing.
+const ICON_PATH_FIELDS: [&str; 7] = [
+    "d",
+    "fill_rule",
+    "clip_rule",
+    "stroke_linecap",
+    "stroke_linejoin",
+    "fill",
+    "stroke",
+];
+
+/// Presentation attributes a `<g>` passes down to its children, unless they
+/// set their own.
+const INHERITED_ATTRIBUTES: [&str; 12] = [
+    "fill",
+    "fill-opacity",
+    "fill-rule",
+    "clip-rule",
+    "stroke",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-miterlimit",
+    "stroke-opacity",
+    "stroke-width",
+];
+
+/// An icon's `IconData` expression and, for icons whose shapes don't all fit
+/// `IconPath`s, the `view!` markup of those shapes.
+struct LeptosIcon {
+    data: String,
+    shapes: Option<String>,
+}
+
+/// Converts an icon into an `IconData` expression.
+///
+/// Icons whose shapes can't all be drawn as `IconPath`s, e.g. because of a
+/// `transform` or an `opacity`, keep their markup in `IconData::markup`, and
+/// their components render the shapes with `view!`.
+fn leptos_icon(svg: &SvgElement, directional: bool) -> Result<LeptosIcon> {
+    let mut view_box = None;
+    let mut fields = vec![];
+    let mut attributes = vec![];
+    for Attribute { name, value } in &svg.attributes {
+        let AttributeValue::Literal(value) = value else {
+            bail!("unexpected expression in the `{name}` attribute");
+        };
+        match name.as_str() {
+            "viewBox" => view_box = Some(value),
+            // Always rendered, as the icon was validated to be SVG.
+            "xmlns" => {}
+            "fill" | "stroke" | "stroke-width" | "aria-hidden"
+            | "data-slot" => {
+                let field = name.replace('-', "_");
+                fields.push(format!("{field}: Some({value:?})"));
+            }
+            _ => attributes.push(format!("({name:?}, {value:?})")),
+        }
+    }
+    let view_box = view_box.context("the <svg> element has no viewBox")?;
+    let stroked = svg.attributes.iter().any(|attribute| {
+        attribute.name == "stroke"
+            && attribute.value != AttributeValue::Literal("none".into())
+    });
+    let mode = match stroked {
+        true => "Stroke",
+        false => "Fill",
+    };
+
+    let (title, paths, shapes) = match icon_paths(svg) {
+        Ok((title, paths)) => (title, paths, None),
+        Err(_) => {
+            let title = svg.children.iter().find_map(text_title);
+            let shapes: Vec<_> = svg
+                .children
+                .iter()
+                .filter(|child| text_title(child).is_none())
+                .collect();
+            let markup: String =
+                shapes.iter().map(|&shape| markup(shape)).collect();
+            fields.push(format!("markup: Some({markup:?})"));
+            let shapes = shapes
+                .iter()
+                .map(|shape| match shape {
+                    SvgNode::Element(element) => xml_markup(
+                        element,
+                        |expr| expr.to_string(),
+                        |text| format!("{{{text:?}}}"),
+                    ),
+                    SvgNode::Text(text) => format!("{{{text:?}}}"),
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            (title, vec![], Some(shapes))
+        }
+    };
+
+    let title = match title {
+        Some(title) => format!("Some({title:?})"),
+        None => "None".to_string(),
+    };
+    if !attributes.is_empty() {
+        fields.push(format!("attributes: &[{}]", attributes.join(", ")));
+    }
+    if directional {
+        fields.push("directional: true".to_string());
+    }
+    // Filling in nothing would be a needless update.
+    let rest = match fields.len() < ICON_DATA_OPTIONAL_FIELDS.len() {
+        true => "\n    ..crate::IconData::EMPTY",
+        false => "",
+    };
+    let fields: String = fields
+        .iter()
+        .map(|field| format!("\n    {field},"))
+        .collect();
+    let paths = match paths.is_empty() {
+        true => "&[]".to_string(),
+        false => {
+            let paths: String = paths
+                .iter()
+                .map(|path| format!("\n        {path},"))
+                .collect();
+            format!("&[{paths}\n    ]")
+        }
+    };
+
+    let data = formatdoc! {r#"
+        crate::IconData {{
+            view_box: {view_box:?},
+            mode: crate::IconMode::{mode},{fields}
+            title: {title},
+            paths: {paths},{rest}
+        }}"#};
+    Ok(LeptosIcon { data, shapes })
+}
+
+/// The text of a `<title>` holding only text.
+fn text_title(node: &SvgNode) -> Option<&str> {
+    match node {
+        SvgNode::Element(element) if element.name == "title" => {
+            match element.children.as_slice() {
+                [SvgNode::Text(text)] => Some(text),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The `<title>` text of an icon and its shapes as `IconPath` expressions,
+/// failing if a shape can't be drawn as one.
+fn icon_paths(svg: &SvgElement) -> Result<(Option<&str>, Vec<String>)> {
+    let mut title = None;
+    let mut paths = vec![];
+    for child in &svg.children {
+        match child {
+            SvgNode::Element(element) if element.name == "title" => {
+                title = Some(
+                    text_title(child)
+                        .context("<title> must only contain text")?,
+                );
+            }
+            SvgNode::Element(element) => {
+                for shape in flatten_group(element)? {
+                    paths.push(icon_path(&shape)?);
+                }
+            }
+            SvgNode::Text(text) => bail!("unexpected text {text:?} in <svg>"),
+        }
+    }
+    Ok((title, paths))
+}
+
+/// The shapes of an element, replacing a `<g>` with its children and
+/// pushing its presentation attributes down to them.
+fn flatten_group(element: &SvgElement) -> Result<Vec<SvgElement>> {
+    if element.name != "g" {
+        return Ok(vec![element.clone()]);
+    }
+    if let Some(attribute) = element.attributes.iter().find(|attribute| {
+        !INHERITED_ATTRIBUTES.contains(&attribute.name.as_str())
+    }) {
+        bail!("the `{}` attribute of <g> isn't inherited", attribute.name);
+    }
+
+    let mut shapes = vec![];
+    for child in &element.children {
+        let SvgNode::Element(child) = child else {
+            bail!("unexpected text in <g>");
+        };
+        let mut child = child.clone();
+        for attribute in &element.attributes {
+            if child
+                .attributes
+                .iter()
+                .all(|own| own.name != attribute.name)
+            {
+                child.attributes.push(attribute.clone());
+            }
+        }
+        shapes.extend(flatten_group(&child)?);
+    }
+    Ok(shapes)
+}
+
+/// Writes a node as SVG markup, escaping attribute values and text.
+fn markup(node: &SvgNode) -> String {
+    fn escape(text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+
+    let element = match node {
+        SvgNode::Element(element) => element,
+        SvgNode::Text(text) => return escape(text),
+    };
+    let mut out = format!("<{}", element.name);
+    for Attribute { name, value } in &element.attributes {
+        if let AttributeValue::Literal(value) = value {
+            out.push_str(&format!(" {name}=\"{}\"", escape(value)));
+        }
+    }
+    match element.children.is_empty() {
+        true => out.push_str("/>"),
+        false => {
+            out.push('>');
+            out.extend(element.children.iter().map(markup));
+            out.push_str(&format!("</{}>", element.name));
+        }
+    }
+    out
+}
+
+/// Converts a `<path>`, or a shape that can be drawn as one, into an
+/// `IconPath` expression.
+fn icon_path(element: &SvgElement) -> Result<String> {
+    if !element.children.is_empty() {
+        bail!(
+            "<{}> elements with children are not supported",
+            element.name
+        );
+    }
+
+    let attribute = |name: &str| {
+        element.attributes.iter().find_map(|attribute| {
+            match (&attribute.value, attribute.name == name) {
+                (AttributeValue::Literal(value), true) => Some(value.as_str()),
+                _ => None,
+            }
+        })
+    };
+    let number = |name: &str| -> Result<Option<f64>> {
+        attribute(name)
+            .map(|value| {
+                value.parse::<f64>().with_context(|| {
+                    format!("unsupported <{}> {name} {value:?}", element.name)
+                })
+            })
+            .transpose()
+    };
+    let (d, geometry): (String, &[&str]) = match element.name.as_str() {
+        "path" => (
+            attribute("d").context("<path> has no `d`")?.to_string(),
+            &["d"],
+        ),
+        "rect" => {
+            let d = rect_path(
+                number("x")?.unwrap_or_default(),
+                number("y")?.unwrap_or_default(),
+                number("width")?.context("<rect> has no width")?,
+                number("height")?.context("<rect> has no height")?,
+                number("rx")?,
+                number("ry")?,
+            );
+            (d, &["x", "y", "width", "height", "rx", "ry"])
+        }
+        "circle" => {
+            let r = number("r")?.context("<circle> has no r")?;
+            let d = ellipse_path(
+                number("cx")?.unwrap_or_default(),
+                number("cy")?.unwrap_or_default(),
+                r,
+                r,
+            );
+            (d, &["cx", "cy", "r"])
+        }
+        "ellipse" => {
+            let (rx, ry) = (number("rx")?, number("ry")?);
+            // Per SVG 2, a missing radius takes the value of the other one.
+            let rx = rx.or(ry).context("<ellipse> has no rx or ry")?;
+            let d = ellipse_path(
+                number("cx")?.unwrap_or_default(),
+                number("cy")?.unwrap_or_default(),
+                rx,
+                ry.unwrap_or(rx),
+            );
+            (d, &["cx", "cy", "rx", "ry"])
+        }
+        "line" => {
+            let d = format!(
+                "M{} {}L{} {}",
+                number("x1")?.unwrap_or_default(),
+                number("y1")?.unwrap_or_default(),
+                number("x2")?.unwrap_or_default(),
+                number("y2")?.unwrap_or_default(),
+            );
+            (d, &["x1", "y1", "x2", "y2"])
+        }
+        "polyline" | "polygon" => {
+            let points = attribute("points").unwrap_or_default();
+            let mut d = points_path(points).with_context(|| {
+                format!("unsupported <{}> points {points:?}", element.name)
+            })?;
+            if element.name == "polygon" && !d.is_empty() {
+                d.push('Z');
+            }
+            (d, &["points"])
+        }
+        name => bail!("<{name}> can't be drawn as a path"),
+    };
+
+    let mut fields = vec![format!("d: {d:?}")];
+    for Attribute { name, value } in &element.attributes {
+        if geometry.contains(&name.as_str()) {
+            continue;
+        }
+        let AttributeValue::Literal(value) = value else {
+            bail!("unexpected expression in the `{name}` attribute");
+        };
+        match name.as_str() {
+            "fill-rule" | "clip-rule" | "stroke-linecap"
+            | "stroke-linejoin" | "fill" | "stroke" => {
+                let field = name.replace('-', "_");
+                fields.push(format!("{field}: Some({value:?})"))
+            }
+            _ => bail!(
+                "the `{name}` attribute on <{}> is not supported",
+                element.name
+            ),
+        }
+    }
+
+    if fields.len() < ICON_PATH_FIELDS.len() {
+        fields.push("..crate::IconPath::EMPTY".to_string());
+    }
+
+    Ok(format!("crate::IconPath {{ {} }}", fields.join(", ")))
+}
+
+/// Draws a (rounded) rectangle as path data.
+fn rect_path(
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    rx: Option<f64>,
+    ry: Option<f64>,
+) -> String {
+    // Per the SVG spec, a missing radius takes the value of the other one and
+    // neither can be more than half of the side it's on.
+    let rx = rx.or(ry).unwrap_or_default().min(width / 2.0);
+    let ry = ry.or(Some(rx)).unwrap_or_default().min(height / 2.0);
+    let (h, v) = (width - 2.0 * rx, height - 2.0 * ry);
+
+    if rx == 0.0 || ry == 0.0 {
+        return format!("M{x} {y}h{width}v{height}h{}Z", -width);
+    }
+
+    format!(
+        "M{} {y}h{h}a{rx} {ry} 0 0 1 {rx} {ry}v{v}a{rx} {ry} 0 0 1 {} {ry}\
+         h{}a{rx} {ry} 0 0 1 {} {}v{}a{rx} {ry} 0 0 1 {rx} {}Z",
+        x + rx,
+        -rx,
+        -h,
+        -rx,
+        -ry,
+        -v,
+        -ry,
+    )
+}
+
+/// Draws an ellipse, or a circle, as path data, starting on its right like
+/// the SVG spec's own.
+fn ellipse_path(cx: f64, cy: f64, rx: f64, ry: f64) -> String {
+    // Shapes without an area aren't rendered.
+    if rx <= 0.0 || ry <= 0.0 {
+        return String::new();
+    }
+
+    format!(
+        "M{} {cy}a{rx} {ry} 0 0 1 {} {ry}a{rx} {ry} 0 0 1 {} {}\
+         a{rx} {ry} 0 0 1 {rx} {}a{rx} {ry} 0 0 1 {rx} {ry}Z",
+        cx + rx,
+        -rx,
+        -rx,
+        -ry,
+        -ry,
+    )
+}
+
+/// Draws the `points` of a `<polyline>` or `<polygon>` as path data.
+fn points_path(points: &str) -> Result<String> {
+    let numbers = points
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|number| !number.is_empty())
+        .map(str::parse::<f64>)
+        .collect::<Result<Vec<_>, _>>()?;
+    if numbers.len() % 2 == 1 {
+        bail!("odd number of coordinates");
+    }
+
+    let mut d = String::new();
+    for (index, point) in numbers.chunks(2).enumerate() {
+        let command = match index {
+            0 => 'M',
+            _ => 'L',
+        };
+        d.push_str(&format!("{command}{} {}", point[0], point[1]));
+    }
+    Ok(d)
+}
+
+pub struct Yew;
+
+impl Template for Yew {
+    fn prelude(&self) -> &'static str {
+        "#[allow(unused_imports)]\nuse yew::prelude::*;"
+    }
+
+    fn header(&self) -> &'static str {
+        indoc::indoc! {r#"
+            /// The props shared by every icon component.
+            #[derive(Debug, Clone, PartialEq, Default, Properties)]
+            pub struct IconProps {
+                #[prop_or_default]
+                pub class: Classes,
+            }
+        "#}
+    }
+
+    fn dependency(&self) -> (&'static str, toml::Value) {
+        ("yew", toml::toml! { version = "0.21" }.into())
+    }
+
+    fn component(&self, icon: &Icon) -> Result<String> {
+        let Icon {
+            component_name,
+            cfg,
+            default_class,
+            ..
+        } = icon;
+        let class = match default_class {
+            Some(class) => formatdoc! {r#"
+                let class = match props.class.is_empty() {{
+                    true => classes!({class:?}),
+                    false => props.class.clone(),
+                }};"#},
+            None => "let class = props.class.clone();".to_string(),
+        };
+        let svg = with_class(icon.svg, "class");
+        let svg = xml_markup(
+            &svg,
+            |expr| format!("{{{expr}}}"),
+            |text| format!("{{{text:?}}}"),
+        );
+
+        Ok(formatdoc! {r#"
+            {cfg}
+            #[function_component]
+            pub fn {component_name}(props: &crate::IconProps) -> Html {{
+                {class}
+                html! {{
+                    {svg}
+                }}
+            }}
+        "#, class = indent_continuation(&class, 4), svg = indent_continuation(&svg, 8)})
+    }
+}
+
+pub struct Dioxus;
+
+impl Template for Dioxus {
+    fn prelude(&self) -> &'static str {
+        "#[allow(unused_imports)]\nuse dioxus::prelude::*;"
+    }
+
+    fn dependency(&self) -> (&'static str, toml::Value) {
+        (
+            "dioxus",
+            toml::toml! {
+                version = "0.6"
+                default-features = false
+                features = ["html", "macro", "signals"]
+            }
+            .into(),
+        )
+    }
+
+    fn component(&self, icon: &Icon) -> Result<String> {
+        let Icon {
+            component_name,
+            cfg,
+            default_class,
+            ..
+        } = icon;
+        let class_prop = match default_class {
+            Some(class) => {
+                format!("#[props(default = {class:?}.to_string(), into)]")
+            }
+            None => "#[props(default, into)]".to_string(),
+        };
+        let svg = with_class(icon.svg, "class");
+        let mut markup = String::new();
+        write_dioxus(&svg, &mut markup, 0);
+
+        Ok(formatdoc! {r#"
+            {cfg}
+            #[component]
+            pub fn {component_name}({class_prop} class: String) -> Element {{
+                rsx! {{
+                    {svg}
+                }}
+            }}
+        "#, svg = indent_continuation(markup.trim_end(), 8)})
+    }
+}
+
+pub struct Sycamore;
+
+impl Template for Sycamore {
+    fn prelude(&self) -> &'static str {
+        "#[allow(unused_imports)]\nuse sycamore::prelude::*;"
+    }
+
+    fn dependency(&self) -> (&'static str, toml::Value) {
+        ("sycamore", toml::toml! { version = "0.9" }.into())
+    }
+
+    fn component(&self, icon: &Icon) -> Result<String> {
+        let Icon {
+            component_name,
+            cfg,
+            default_class,
+            ..
+        } = icon;
+        let class_prop = match default_class {
+            Some(class) => {
+                format!(
+                    "#[prop(default = {class:?}.to_string(), setter(into))]"
+                )
+            }
+            None => "#[prop(default, setter(into))]".to_string(),
+        };
+        let svg = with_class(icon.svg, "class");
+        let mut markup = String::new();
+        write_sycamore(&svg, &mut markup, 0);
+
+        Ok(formatdoc! {r#"
+            {cfg}
+            #[component(inline_props)]
+            pub fn {component_name}({class_prop} class: String) -> View {{
+                view! {{
+                    {svg}
+                }}
+            }}
+        "#, svg = indent_continuation(markup.trim_end(), 8)})
+    }
+}
+
+/// Returns a copy of the icon with its class bound to `expr`.
+fn with_class(svg: &SvgElement, expr: &str) -> SvgElement {
+    let mut svg = svg.clone();
+    svg.attributes.insert(0, Attribute::expr("class", expr));
+    svg
+}
+
+/// Indents every line but the first, which the template already places.
+pub(crate) fn indent_continuation(text: &str, indent: usize) -> String {
+    text.replace('\n', &format!("\n{:indent$}", ""))
+}
+
+/// Renders HTML-like markup, as used by Leptos' `view!` and Yew's `html!`,
+/// nesting children by two spaces.
+fn xml_markup(
+    element: &SvgElement,
+    expr: impl Fn(&str) -> String + Copy,
+    text: impl Fn(&str) -> String + Copy,
+) -> String {
+    fn write(
+        element: &SvgElement,
+        out: &mut String,
+        indent: usize,
+        expr: impl Fn(&str) -> String + Copy,
+        text: impl Fn(&str) -> String + Copy,
+    ) {
+        out.push_str(&format!("{:indent$}<{}", "", element.name));
+        for Attribute { name, value } in &element.attributes {
+            match value {
+                AttributeValue::Literal(value) => {
+                    out.push_str(&format!(" {name}={value:?}"))
+                }
+                AttributeValue::Expr(value) => {
+                    out.push_str(&format!(" {name}={}", expr(value)))
+                }
+            }
+        }
+
+        if element.children.is_empty() {
+            out.push_str("/>\n");
+            return;
+        }
+
+        // Keep text-only elements such as `<title>` on a single line.
+        if let [SvgNode::Text(value)] = element.children.as_slice() {
+            out.push_str(&format!(">{}</{}>\n", text(value), element.name));
+            return;
+        }
+
+        out.push_str(">\n");
+        for child in &element.children {
+            match child {
+                SvgNode::Element(child) => {
+                    write(child, out, indent + 2, expr, text)
+                }
+                SvgNode::Text(value) => out.push_str(&format!(
+                    "{:indent$}{}\n",
+                    "",
+                    text(value),
+                    indent = indent + 2
+                )),
+            }
+        }
+        out.push_str(&format!("{:indent$}</{}>\n", "", element.name));
+    }
+
+    let mut out = String::new();
+    write(element, &mut out, 0, expr, text);
+    out.trim_end().to_string()
+}
+
+/// Renders `rsx!` markup. Attributes other than `class` use the quoted
+/// custom attribute syntax so they're set exactly as named in the SVG.
+fn write_dioxus(element: &SvgElement, out: &mut String, indent: usize) {
+    // rsx! string literals are format strings.
+    fn literal(value: &str) -> String {
+        format!("{:?}", value.replace('{', "{{").replace('}', "}}"))
+    }
+
+    out.push_str(&format!("{:indent$}{} {{\n", "", element.name));
+    for Attribute { name, value } in &element.attributes {
+        let name = match name.as_str() {
+            "class" => name.to_string(),
+            _ => format!("{name:?}"),
+        };
+        let value = match value {
+            AttributeValue::Literal(value) => literal(value),
+            AttributeValue::Expr(expr) => expr.to_string(),
+        };
+        out.push_str(&format!(
+            "{:indent$}{name}: {value},\n",
+            "",
+            indent = indent + 4
+        ));
+    }
+    for child in &element.children {
+        match child {
+            SvgNode::Element(child) => write_dioxus(child, out, indent + 4),
+            SvgNode::Text(text) => out.push_str(&format!(
+                "{:indent$}{}\n",
+                "",
+                literal(text),
+                indent = indent + 4
+            )),
+        }
+    }
+    out.push_str(&format!("{:indent$}}}\n", ""));
+}
+
+/// Renders Sycamore `view!` markup. Attributes other than `class` use the
+/// quoted syntax so they're set exactly as named in the SVG.
+fn write_sycamore(element: &SvgElement, out: &mut String, indent: usize) {
+    // Sycamore prefixes SVG elements that share a name with HTML ones.
+    let tag = match element.name.as_str() {
+        "a" | "script" | "style" | "title" => format!("svg_{}", element.name),
+        "use" => "r#use".to_string(),
+        name => name.to_string(),
+    };
+    let attributes = element
+        .attributes
+        .iter()
+        .map(|Attribute { name, value }| {
+            let name = match name.as_str() {
+                "class" => name.to_string(),
+                _ => format!("{name:?}"),
+            };
+            match value {
+                AttributeValue::Literal(value) => format!("{name}={value:?}"),
+                AttributeValue::Expr(expr) => format!("{name}={expr}"),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    out.push_str(&format!("{:indent$}{tag}({attributes})", ""));
+    if element.children.is_empty() {
+        out.push('\n');
+        return;
+    }
+
+    out.push_str(" {\n");
+    for child in &element.children {
+        match child {
+            SvgNode::Element(child) => write_sycamore(child, out, indent + 4),
+            SvgNode::Text(text) => out.push_str(&format!(
+                "{:indent$}{text:?}\n",
+                "",
+                indent = indent + 4
+            )),
+        }
+    }
+    out.push_str(&format!("{:indent$}}}\n", ""));
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::svg;
+    use crate::transform::Transform;
+
+    const ARROW_LEFT: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" stroke-width="1.5" stroke="currentColor" aria-hidden="true" data-slot="icon"><path stroke-linecap="round" stroke-linejoin="round" d="M10.5 19.5 3 12m0 0 7.5-7.5M3 12h18"/></svg>"#;
+
+    /// The fields of a struct of the Leptos header.
+    fn struct_fields(name: &str) -> Vec<&'static str> {
+        let header = include_str!("../templates/leptos_header.rs");
+        let start = header.find(&format!("pub struct {name} {{")).unwrap();
+        header[start..]
+            .lines()
+            .skip(1)
+            .take_while(|line| *line != "}")
+            .filter_map(|line| line.strip_prefix("    pub "))
+            .map(|line| line.split(':').next().unwrap())
+            .collect()
+    }
+
+    /// Asserts that an `IconData` expression sets every field, or fills the
+    /// missing ones in from `IconData::EMPTY`.
+    fn assert_complete(data: &str) {
+        if data.contains("..crate::IconData::EMPTY") {
+            return;
+        }
+        for field in struct_fields("IconData") {
+            let set = data.contains(&format!("\n    {field}: "));
+            assert!(set, "{field} is missing from {data}");
+        }
+    }
+
+    fn transformed(rules: &str) -> SvgElement {
+        #[derive(Deserialize)]
+        struct Rules {
+            transform: Vec<Transform>,
+        }
+        let rules: Rules = toml::from_str(rules).unwrap();
+        let mut svg = svg::parse(ARROW_LEFT).unwrap();
+        for rule in &rules.transform {
+            rule.apply(&mut svg);
+        }
+        svg
+    }
+
+    #[test]
+    fn field_lists_match_the_header() {
+        let mut data_fields = ICON_DATA_OPTIONAL_FIELDS.to_vec();
+        data_fields.extend(["view_box", "mode", "title", "paths"]);
+        data_fields.sort();
+        let mut declared = struct_fields("IconData");
+        declared.sort();
+        assert_eq!(data_fields, declared);
+        assert_eq!(ICON_PATH_FIELDS.to_vec(), struct_fields("IconPath"));
+    }
+
+    #[test]
+    fn readme_transforms_give_complete_icon_data() {
+        let readme = include_str!("../README.md");
+        let section = &readme[readme.find("### Transforming icons").unwrap()..];
+        let rules = &section[section.find("```toml\n").unwrap() + 8..];
+        let rules = &rules[..rules.find("```").unwrap()];
+
+        let svg = transformed(rules);
+        for directional in [false, true] {
+            let data = leptos_icon(&svg, directional).unwrap().data;
+            assert!(data.contains(r#"attributes: &[("focusable", "false")],"#));
+            assert!(data.contains(r#"stroke: Some("var(--icon-color)"),"#));
+            assert!(!data.contains("data_slot"));
+            assert_complete(&data);
+        }
+    }
+
+    #[test]
+    fn icon_data_with_every_field_is_complete() {
+        let svg = transformed(
+            r#"
+            [[transform]]
+            rule = "add"
+            attribute = "focusable"
+            value = "false"
+            "#,
+        );
+        let data = leptos_icon(&svg, true).unwrap().data;
+        assert!(data.contains("..crate::IconData::EMPTY"), "{data}");
+
+        // Only icons that keep their markup set it.
+        let mut svg = svg;
+        let Some(SvgNode::Element(path)) = svg.children.first_mut() else {
+            panic!("ARROW_LEFT starts with a path");
+        };
+        path.attributes
+            .push(Attribute::literal("transform", "rotate(90 12 12)"));
+        let data = leptos_icon(&svg, true).unwrap().data;
+        assert!(!data.contains("..crate::IconData::EMPTY"), "{data}");
+        assert_complete(&data);
+
+        let data = leptos_icon(&svg, false).unwrap().data;
+        assert!(data.contains("..crate::IconData::EMPTY"), "{data}");
+    }
+
+    #[test]
+    fn icon_paths_fill_in_missing_fields() {
+        let path = |attributes: &str| {
+            let source = format!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1 1"><path d="M0 0h1" {attributes}/></svg>"#
+            );
+            match &svg::parse(&source).unwrap().children[0] {
+                SvgNode::Element(element) => icon_path(element).unwrap(),
+                SvgNode::Text(_) => unreachable!(),
+            }
+        };
+        assert_eq!(
+            path(""),
+            r#"crate::IconPath { d: "M0 0h1", ..crate::IconPath::EMPTY }"#
+        );
+        let full = path(
+            r#"fill-rule="evenodd" clip-rule="evenodd" stroke-linecap="round" stroke-linejoin="round" fill="red" stroke="blue""#,
+        );
+        assert!(!full.contains("EMPTY"), "{full}");
+    }
+
+    /// The paths of an icon with the given shapes, or its error.
+    fn paths(shapes: &str) -> Result<Vec<String>> {
+        let source = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24">{shapes}</svg>"#
+        );
+        icon_paths(&svg::parse(&source).unwrap()).map(|(_, paths)| paths)
+    }
+
+    #[test]
+    fn draws_circles_and_ellipses() {
+        assert_eq!(
+            paths(r#"<circle cx="12" cy="8" r="5"/>"#).unwrap(),
+            [
+                r#"crate::IconPath { d: "M17 8a5 5 0 0 1 -5 5a5 5 0 0 1 -5 -5a5 5 0 0 1 5 -5a5 5 0 0 1 5 5Z", ..crate::IconPath::EMPTY }"#
+            ]
+        );
+        assert_eq!(
+            paths(r#"<ellipse cx="12" cy="12" rx="4" ry="2"/>"#).unwrap(),
+            [
+                r#"crate::IconPath { d: "M16 12a4 2 0 0 1 -4 2a4 2 0 0 1 -4 -2a4 2 0 0 1 4 -2a4 2 0 0 1 4 2Z", ..crate::IconPath::EMPTY }"#
+            ]
+        );
+        // A missing radius takes the value of the other one.
+        assert_eq!(
+            paths(r#"<ellipse rx="2"/>"#).unwrap(),
+            paths(r#"<circle r="2"/>"#).unwrap()
+        );
+        assert!(paths(r#"<circle cx="12" cy="12"/>"#).is_err());
+        assert!(paths(r#"<circle r="50%"/>"#).is_err());
+    }
+
+    #[test]
+    fn draws_lines_polylines_and_polygons() {
+        assert_eq!(
+            paths(r#"<line x1="1" y1="2" x2="3.5" y2="4" stroke-linecap="round"/>"#)
+                .unwrap(),
+            [r#"crate::IconPath { d: "M1 2L3.5 4", stroke_linecap: Some("round"), ..crate::IconPath::EMPTY }"#]
+        );
+        assert_eq!(
+            paths(r#"<polyline points="1,2 3,4  5 6"/><polygon points="0,0,4,0,2,3"/>"#)
+                .unwrap(),
+            [
+                r#"crate::IconPath { d: "M1 2L3 4L5 6", ..crate::IconPath::EMPTY }"#,
+                r#"crate::IconPath { d: "M0 0L4 0L2 3Z", ..crate::IconPath::EMPTY }"#,
+            ]
+        );
+        assert!(paths(r#"<polygon points="0,0 4"/>"#).is_err());
+    }
+
+    #[test]
+    fn draws_rects() {
+        assert_eq!(
+            paths(r#"<rect x="2" y="3" width="4" height="5"/>"#).unwrap(),
+            [
+                r#"crate::IconPath { d: "M2 3h4v5h-4Z", ..crate::IconPath::EMPTY }"#
+            ]
+        );
+    }
+
+    #[test]
+    fn flattens_groups_into_their_children() {
+        assert_eq!(
+            paths(
+                r#"<g fill="currentColor" stroke="red"><path d="M0 0h1"/><g stroke="blue"><circle r="1"/></g></g>"#
+            )
+            .unwrap(),
+            [
+                r#"crate::IconPath { d: "M0 0h1", fill: Some("currentColor"), stroke: Some("red"), ..crate::IconPath::EMPTY }"#,
+                r#"crate::IconPath { d: "M1 0a1 1 0 0 1 -1 1a1 1 0 0 1 -1 -1a1 1 0 0 1 1 -1a1 1 0 0 1 1 1Z", stroke: Some("blue"), fill: Some("currentColor"), ..crate::IconPath::EMPTY }"#,
+            ]
+        );
+        let error = paths(r#"<g transform="rotate(45)"><path d="M0 0"/></g>"#)
+            .unwrap_err();
+        assert!(error.to_string().contains("`transform` attribute of <g>"));
+    }
+
+    #[test]
+    fn icons_that_dont_fit_paths_keep_their_markup() {
+        let source = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="currentColor"><title>Dot</title><g transform="translate(1 1)"><circle r="5" opacity="0.5"/></g><path d="M0 0h1" stroke-width="2"/></svg>"#;
+        let icon = leptos_icon(&svg::parse(source).unwrap(), false).unwrap();
+        assert!(
+            icon.data.contains(r#"title: Some("Dot"),"#),
+            "{}",
+            icon.data
+        );
+        assert!(icon.data.contains("paths: &[],"), "{}", icon.data);
+        assert!(
+            icon.data.contains(
+                r#"markup: Some("<g transform=\"translate(1 1)\"><circle r=\"5\" opacity=\"0.5\"/></g><path d=\"M0 0h1\" stroke-width=\"2\"/>"),"#
+            ),
+            "{}",
+            icon.data
+        );
+        assert_complete(&icon.data);
+        assert_eq!(
+            icon.shapes.unwrap(),
+            indoc::indoc! {r#"
+                <g transform="translate(1 1)">
+                  <circle r="5" opacity="0.5"/>
+                </g>
+                <path d="M0 0h1" stroke-width="2"/>"#}
+        );
+    }
+
+    #[test]
+    fn icons_that_fit_paths_have_no_markup() {
+        let icon =
+            leptos_icon(&svg::parse(ARROW_LEFT).unwrap(), false).unwrap();
+        assert!(!icon.data.contains("markup"));
+        assert_eq!(icon.shapes, None);
+    }
+
+    #[test]
+    fn markup_escapes_attributes_and_text() {
+        let node = SvgNode::Element(SvgElement {
+            name: "text".into(),
+            attributes: vec![Attribute::literal("font-family", r#""A&B""#)],
+            children: vec![SvgNode::Text("1 < 2".into())],
+        });
+        assert_eq!(
+            markup(&node),
+            r#"<text font-family="&quot;A&amp;B&quot;">1 &lt; 2</text>"#
+        );
+    }
+
+    /// The props of a `#[slot]` struct of the Leptos header.
+    fn prop_fields(name: &str) -> Vec<&'static str> {
+        let header = include_str!("../templates/leptos_header.rs");
+        let start = header.find(&format!("pub struct {name} {{")).unwrap();
+        header[start..]
+            .lines()
+            .skip(1)
+            .take_while(|line| *line != "}")
+            .map(str::trim)
+            .filter(|line| !line.starts_with("///") && !line.starts_with("#["))
+            .map(|line| line.split(':').next().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn duotone_props_add_the_colors_to_the_others() {
+        for (props, duotone) in [
+            ("IconProps", "DuotoneIconProps"),
+            ("DualIconProps", "DualDuotoneIconProps"),
+        ] {
+            let mut expected = prop_fields(props);
+            expected.extend(["primary", "secondary"]);
+            expected.sort();
+            let mut fields = prop_fields(duotone);
+            fields.sort();
+            assert_eq!(fields, expected, "{duotone}");
+            assert!(!prop_fields(props).contains(&"primary"), "{props}");
+        }
+    }
+
+    fn leptos_component(svg: &SvgElement, duotone: bool) -> String {
+        Leptos
+            .component(&Icon {
+                component_name: "ArrowLeft",
+                cfg: "#[cfg(feature = \"24-outline\")]",
+                svg,
+                default_class: None,
+                directional: false,
+                duotone,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn only_duotone_icons_take_the_colors() {
+        let svg = svg::parse(ARROW_LEFT).unwrap();
+        let component = leptos_component(&svg, false);
+        assert!(
+            component.contains("(props: crate::IconProps)"),
+            "{component}"
+        );
+        assert!(
+            component.contains("crate::render_icon_with(&ARROW_LEFT, props)")
+        );
+
+        let svg = transformed(
+            r#"
+            [[transform]]
+            rule = "duotone"
+            "#,
+        );
+        let component = leptos_component(&svg, true);
+        assert!(
+            component.contains("(props: crate::DuotoneIconProps)"),
+            "{component}"
+        );
+        assert!(component
+            .contains("crate::render_duotone_icon(&ARROW_LEFT, props)"));
+
+        let mut svg = svg;
+        let Some(SvgNode::Element(path)) = svg.children.first_mut() else {
+            panic!("ARROW_LEFT starts with a path");
+        };
+        path.attributes
+            .push(Attribute::literal("transform", "rotate(90 12 12)"));
+        let component = leptos_component(&svg, true);
+        assert!(
+            component.contains("crate::render_duotone_icon_with_shapes("),
+            "{component}"
+        );
+    }
+
+    #[test]
+    fn only_duotone_dual_icons_take_the_colors() {
+        let component = |duotone| {
+            Leptos
+                .dual_component(&DualIcon {
+                    component_name: "ArrowLeft",
+                    cfg: "#[cfg(feature = \"24\")]",
+                    outline_module: "outline",
+                    solid_module: "solid",
+                    duotone,
+                })
+                .unwrap()
+        };
+        let plain = component(false);
+        assert!(plain.contains("(props: crate::DualIconProps)"), "{plain}");
+        assert!(plain.contains("crate::render_dual_icon("), "{plain}");
+        let duotone = component(true);
+        assert!(
+            duotone.contains("(props: crate::DualDuotoneIconProps)"),
+            "{duotone}"
+        );
+        assert!(
+            duotone.contains("crate::render_dual_duotone_icon("),
+            "{duotone}"
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use std::fs;
+use std::path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::svg::{Attribute, AttributeValue, SvgElement, SvgNode};
+
+/// A rule rewriting the attributes of every icon of a source before its
+/// component is generated.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "rule", rename_all = "lowercase", deny_unknown_fields)]
+pub enum Transform {
+    /// Removes an attribute from every element, e.g. `data-slot`.
+    Remove { attribute: String },
+    /// Sets an attribute of the `<svg>`, adding it if it's missing, e.g.
+    /// `focusable="false"`.
+    Add { attribute: String, value: String },
+    /// Replaces a value of an attribute of every element, or of every
+    /// attribute without one, e.g. `currentColor` with
+    /// `var(--icon-color)`.
+    Replace {
+        attribute: Option<String>,
+        from: String,
+        to: String,
+    },
+    /// Paints icons with the `--hi-primary` custom property, and every
+    /// other shape of icons with several of them with `--hi-secondary`,
+    /// both falling back to `currentColor`.
+    Duotone,
+}
+
+const PRIMARY: &str = "var(--hi-primary, currentColor)";
+const SECONDARY: &str = "var(--hi-secondary, currentColor)";
+
+/// The contents of a transforms file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Transforms {
+    #[serde(rename = "transform", default)]
+    rules: Vec<Transform>,
+}
+
+impl Transform {
+    /// Reads the `[[transform]]` rules of a transforms file, in order.
+    pub fn from_file(path: &path::Path) -> Result<Vec<Self>> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("reading {}", path.display()))?;
+        let transforms: Transforms = toml::from_str(&contents)
+            .with_context(|| format!("parsing {}", path.display()))?;
+        Ok(transforms.rules)
+    }
+
+    /// Rewrites a parsed icon.
+    pub fn apply(&self, svg: &mut SvgElement) {
+        match self {
+            Transform::Remove { attribute } => visit(svg, &mut |element| {
+                element.attributes.retain(|a| a.name != *attribute);
+            }),
+            Transform::Add { attribute, value } => {
+                match svg.attributes.iter_mut().find(|a| a.name == *attribute) {
+                    Some(existing) => {
+                        existing.value = AttributeValue::Literal(value.clone())
+                    }
+                    None => svg
+                        .attributes
+                        .push(Attribute::literal(attribute, value)),
+                }
+            }
+            Transform::Replace {
+                attribute,
+                from,
+                to,
+            } => visit(svg, &mut |element| {
+                for existing in &mut element.attributes {
+                    let matches = attribute
+                        .as_ref()
+                        .is_none_or(|attribute| existing.name == *attribute);
+                    if matches
+                        && existing.value
+                            == AttributeValue::Literal(from.clone())
+                    {
+                        existing.value = AttributeValue::Literal(to.clone());
+                    }
+                }
+            }),
+            Transform::Duotone => {
+                visit(svg, &mut |element| {
+                    for attribute in &mut element.attributes {
+                        if attribute.value
+                            == AttributeValue::Literal("currentColor".into())
+                        {
+                            attribute.value =
+                                AttributeValue::Literal(PRIMARY.to_string());
+                        }
+                    }
+                });
+
+                // Outline icons are stroked, solid ones filled.
+                let stroked = svg.attributes.iter().any(|attribute| {
+                    attribute.name == "stroke"
+                        && attribute.value
+                            != AttributeValue::Literal("none".into())
+                });
+                let paint = match stroked {
+                    true => "stroke",
+                    false => "fill",
+                };
+                let shapes =
+                    svg.children.iter_mut().filter_map(|child| match child {
+                        SvgNode::Element(element)
+                            if element.name != "title" =>
+                        {
+                            Some(element)
+                        }
+                        _ => None,
+                    });
+                for shape in shapes.skip(1).step_by(2) {
+                    Transform::Add {
+                        attribute: paint.to_string(),
+                        value: SECONDARY.to_string(),
+                    }
+                    .apply(shape);
+                }
+            }
+        }
+    }
+}
+
+/// Calls `f` on an element and all of its descendants.
+fn visit(element: &mut SvgElement, f: &mut impl FnMut(&mut SvgElement)) {
+    f(element);
+    for child in &mut element.children {
+        if let SvgNode::Element(child) = child {
+            visit(child, f);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::svg;
+
+    const ICON: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" data-slot="icon"><path stroke="currentColor" d="M0 0h24" data-slot="path"/><path fill="currentColor" d="M0 24h24"/></svg>"#;
+
+    fn transformed(rules: &str) -> SvgElement {
+        let transforms: Transforms = toml::from_str(rules).unwrap();
+        let mut svg = svg::parse(ICON).unwrap();
+        for transform in &transforms.rules {
+            transform.apply(&mut svg);
+        }
+        svg
+    }
+
+    fn paths(svg: &SvgElement) -> Vec<&SvgElement> {
+        svg.children
+            .iter()
+            .filter_map(|child| match child {
+                SvgNode::Element(element) => Some(element),
+                SvgNode::Text(_) => None,
+            })
+            .collect()
+    }
+
+    fn literal<'a>(element: &'a SvgElement, name: &str) -> Option<&'a str> {
+        element
+            .attributes
+            .iter()
+            .find(|attribute| attribute.name == name)
+            .map(|attribute| match &attribute.value {
+                AttributeValue::Literal(value) => value.as_str(),
+                AttributeValue::Expr(expr) => panic!("unexpected {expr}"),
+            })
+    }
+
+    #[test]
+    fn remove_drops_the_attribute_from_every_element() {
+        let svg = transformed(
+            r#"
+            [[transform]]
+            rule = "remove"
+            attribute = "data-slot"
+            "#,
+        );
+        assert_eq!(literal(&svg, "data-slot"), None);
+        assert_eq!(literal(paths(&svg)[0], "data-slot"), None);
+        assert_eq!(literal(&svg, "fill"), Some("none"));
+    }
+
+    #[test]
+    fn add_sets_or_appends_an_attribute_of_the_svg() {
+        let svg = transformed(
+            r#"
+            [[transform]]
+            rule = "add"
+            attribute = "focusable"
+            value = "false"
+
+            [[transform]]
+            rule = "add"
+            attribute = "fill"
+            value = "red"
+            "#,
+        );
+        let names: Vec<_> = svg.attributes.iter().map(|a| &a.name).collect();
+        assert_eq!(
+            names,
+            [
+                "xmlns",
+                "viewBox",
+                "fill",
+                "stroke",
+                "data-slot",
+                "focusable"
+            ]
+        );
+        assert_eq!(literal(&svg, "focusable"), Some("false"));
+        assert_eq!(literal(&svg, "fill"), Some("red"));
+        assert_eq!(literal(paths(&svg)[0], "focusable"), None);
+        assert_eq!(literal(paths(&svg)[1], "fill"), Some("currentColor"));
+    }
+
+    #[test]
+    fn replace_rewrites_exact_values_of_the_attribute() {
+        let svg = transformed(
+            r#"
+            [[transform]]
+            rule = "replace"
+            attribute = "stroke"
+            from = "currentColor"
+            to = "var(--icon-color)"
+
+            [[transform]]
+            rule = "replace"
+            attribute = "d"
+            from = "M0 0"
+            to = "M1 1"
+            "#,
+        );
+        assert_eq!(literal(&svg, "stroke"), Some("var(--icon-color)"));
+        assert_eq!(
+            literal(paths(&svg)[0], "stroke"),
+            Some("var(--icon-color)")
+        );
+        assert_eq!(literal(paths(&svg)[0], "d"), Some("M0 0h24"));
+        assert_eq!(literal(paths(&svg)[1], "fill"), Some("currentColor"));
+    }
+
+    #[test]
+    fn replace_without_an_attribute_rewrites_every_attribute() {
+        let svg = transformed(
+            r#"
+            [[transform]]
+            rule = "replace"
+            from = "currentColor"
+            to = "black"
+            "#,
+        );
+        assert_eq!(literal(&svg, "stroke"), Some("black"));
+        assert_eq!(literal(paths(&svg)[0], "stroke"), Some("black"));
+        assert_eq!(literal(paths(&svg)[1], "fill"), Some("black"));
+    }
+
+    #[test]
+    fn rejects_unknown_rules_and_fields() {
+        let unknown_rule = "[[transform]]\nrule = \"rename\"\n";
+        let unknown_field =
+            "[[transform]]\nrule = \"remove\"\nattribute = \"id\"\nvalue = \"\"\n";
+        assert!(toml::from_str::<Transforms>(unknown_rule).is_err());
+        assert!(toml::from_str::<Transforms>(unknown_field).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+
+/// Animates and transforms the icon it wraps, e.g. a spinning
+/// `<ArrowPath />` while loading.
+///
+/// The styles are inline and the component brings its own keyframes, so no
+/// CSS framework is needed, unless it's inside an [`IconAnimations`], which
+/// brings them once for all of its icons. The animations honor
+/// `prefers-reduced-motion`.
+#[cfg(feature = "animations")]
+#[component]
+pub fn AnimatedIcon(
+    /// Spins the icon clockwise, once a second.
+    #[prop(into, optional)]
+    spin: Signal<bool>,
+    /// Fades the icon in and out.
+    #[prop(into, optional)]
+    pulse: Signal<bool>,
+    /// Bounces the icon up and down.
+    #[prop(into, optional)]
+    bounce: Signal<bool>,
+    /// Rotates the icon clockwise by this many degrees.
+    #[prop(into, optional)]
+    rotate: Signal<f64>,
+    /// Mirrors the icon left to right.
+    #[prop(into, optional)]
+    flip_horizontal: Signal<bool>,
+    /// Mirrors the icon top to bottom.
+    #[prop(into, optional)]
+    flip_vertical: Signal<bool>,
+    children: Children,
+) -> impl IntoView {
+    let transform = move || {
+        let mut transforms = vec![];
+        if rotate.get() != 0.0 {
+            transforms.push(format!("rotate({}deg)", rotate.get()));
+        }
+        if flip_horizontal.get() {
+            transforms.push("scaleX(-1)".to_string());
+        }
+        if flip_vertical.get() {
+            transforms.push("scaleY(-1)".to_string());
+        }
+        match transforms.is_empty() {
+            true => "display: inline-flex".to_string(),
+            false => format!(
+                "display: inline-flex; transform: {}",
+                transforms.join(" ")
+            ),
+        }
+    };
+    let animation = move || {
+        let animations: Vec<&str> = [
+            (spin.get(), "leptos-heroicons-spin 1s linear infinite"),
+            (pulse.get(), "leptos-heroicons-pulse 2s ease-in-out infinite"),
+            (bounce.get(), "leptos-heroicons-bounce 1s infinite"),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, animation)| enabled.then_some(animation))
+        .collect();
+        match animations.is_empty() {
+            true => "display: inline-flex".to_string(),
+            false => format!(
+                "display: inline-flex; animation: {}",
+                animations.join(", ")
+            ),
+        }
+    };
+
+    let keyframes = use_context::<IconAnimationKeyframes>()
+        .is_none()
+        .then(|| view! { <style>{ANIMATION_KEYFRAMES}</style> });
+
+    // The animations use the `rotate` and `translate` properties, so they
+    // combine with each other and with the transforms.
+    view! {
+        {keyframes}
+        <span style=transform>
+            <span class="leptos-heroicons-animated" style=animation>
+                {children()}
+            </span>
+        </span>
+    }
+}
+
+/// Brings the keyframes of the [`AnimatedIcon`]s inside it once, instead of
+/// once per icon, e.g. around a list of icons or the whole app.
+#[cfg(feature = "animations")]
+#[component]
+pub fn IconAnimations(children: Children) -> impl IntoView {
+    provide_context(IconAnimationKeyframes);
+    view! {
+        <style>{ANIMATION_KEYFRAMES}</style>
+        {children()}
+    }
+}
+
+/// Provided by [`IconAnimations`], whose keyframes the icons inside it share.
+#[cfg(feature = "animations")]
+#[derive(Clone, Copy)]
+struct IconAnimationKeyframes;
+
+#[cfg(feature = "animations")]
+const ANIMATION_KEYFRAMES: &str = "\
+@keyframes leptos-heroicons-spin { to { rotate: 360deg } }
+@keyframes leptos-heroicons-pulse { 50% { opacity: 0.5 } }
+@keyframes leptos-heroicons-bounce {
+  0%, 100% { translate: 0 -25%; animation-timing-function: cubic-bezier(0.8, 0, 1, 1) }
+  50% { translate: 0 0; animation-timing-function: cubic-bezier(0, 0, 0.2, 1) }
+}
+@media (prefers-reduced-motion: reduce) {
+  .leptos-heroicons-animated { animation: none !important }
+}";
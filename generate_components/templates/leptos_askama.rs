@@ -0,0 +1,27 @@
+
+/// Askama filters rendering the enabled icons. Bring them into a template's
+/// scope with `mod filters { pub use leptos_heroicons::askama_filters::*; }`.
+#[cfg(feature = "askama")]
+pub mod askama_filters {
+    use askama::filters::Safe;
+    use askama::Values;
+
+    /// Renders the icon named by the value in the given size and style,
+    /// e.g. `{{ "arrow-down-tray"|heroicon("24-outline", "size-5") }}`.
+    ///
+    /// Fails if the icon isn't enabled.
+    #[askama::filter_fn]
+    pub fn heroicon(
+        name: impl std::fmt::Display,
+        _: &dyn Values,
+        style: &str,
+        class: &str,
+    ) -> askama::Result<Safe<String>> {
+        let feature = format!("{style}-{name}");
+        let icon = crate::find_icon(&feature).ok_or_else(|| {
+            askama::Error::custom(format!("the icon {feature} isn't enabled"))
+        })?;
+        let icon = crate::render::Icon::new(icon.data, class.to_string());
+        Ok(Safe(icon.to_string()))
+    }
+}
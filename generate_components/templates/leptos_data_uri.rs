@@ -0,0 +1,122 @@
+
+#[cfg(feature = "data-uri")]
+impl IconData {
+    /// The icon as a standalone SVG document, painted with `color` wherever
+    /// it would use `currentColor`.
+    ///
+    /// Both colors of duotone icons are painted with `color` too, as their
+    /// custom properties can't be set from outside an image.
+    pub fn to_svg(&self, color: &str) -> String {
+        let color = escape_svg(color);
+        // Includes the duotone colors, e.g. `var(--hi-primary, currentColor)`.
+        let paint = |value: &str| match value.contains("currentColor") {
+            true => color.clone(),
+            false => value.to_string(),
+        };
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{}\"",
+            self.view_box
+        );
+        let attributes = [
+            ("fill", self.fill.map(paint)),
+            ("stroke-width", self.stroke_width.map(str::to_string)),
+            ("stroke", self.stroke.map(paint)),
+        ];
+        for (name, value) in attributes {
+            if let Some(value) = value {
+                svg.push_str(&format!(" {name}=\"{value}\""));
+            }
+        }
+        // Added by transforms, so their values may need escaping.
+        for (name, value) in self.attributes {
+            svg.push_str(&format!(" {name}=\"{}\"", escape_svg(value)));
+        }
+        svg.push('>');
+
+        if let Some(title) = self.title {
+            svg.push_str(&format!("<title>{}</title>", escape_svg(title)));
+        }
+        // Painted like the attributes above, wherever it uses `currentColor`.
+        if let Some(markup) = self.markup {
+            svg.push_str(&markup.replace("currentColor", &color));
+        }
+        for path in self.paths {
+            svg.push_str(&format!("<path d=\"{}\"", path.d));
+            let attributes = [
+                ("fill-rule", path.fill_rule.map(str::to_string)),
+                ("clip-rule", path.clip_rule.map(str::to_string)),
+                ("stroke-linecap", path.stroke_linecap.map(str::to_string)),
+                ("stroke-linejoin", path.stroke_linejoin.map(str::to_string)),
+                ("fill", path.fill.map(paint)),
+                ("stroke", path.stroke.map(paint)),
+            ];
+            for (name, value) in attributes {
+                if let Some(value) = value {
+                    svg.push_str(&format!(" {name}=\"{value}\""));
+                }
+            }
+            svg.push_str("/>");
+        }
+        svg.push_str("</svg>");
+
+        svg
+    }
+
+    /// The icon as a URL-encoded `data:image/svg+xml` URI, painted with
+    /// `color`.
+    pub fn data_uri(&self, color: &str) -> String {
+        let mut uri = String::from("data:image/svg+xml,");
+        for byte in self.to_svg(color).bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => uri.push(byte as char),
+                b'-' | b'_' | b'.' | b'~' | b'!' | b'$' | b'\'' | b'(' | b')'
+                | b'*' | b'+' | b',' | b';' | b'=' | b':' | b'@' | b'/' => {
+                    uri.push(byte as char)
+                }
+                byte => uri.push_str(&format!("%{byte:02X}")),
+            }
+        }
+        uri
+    }
+
+    /// The icon as a base64 `data:image/svg+xml` URI, painted with `color`.
+    pub fn data_uri_base64(&self, color: &str) -> String {
+        const ALPHABET: &[u8; 64] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+        let mut uri = String::from("data:image/svg+xml;base64,");
+        for chunk in self.to_svg(color).as_bytes().chunks(3) {
+            let bytes = [
+                chunk[0],
+                chunk.get(1).copied().unwrap_or(0),
+                chunk.get(2).copied().unwrap_or(0),
+            ];
+            let bits = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+            for i in 0..4 {
+                match i <= chunk.len() {
+                    true => {
+                        let index = (bits >> (18 - 6 * i)) & 0b11_1111;
+                        uri.push(ALPHABET[index as usize] as char)
+                    }
+                    false => uri.push('='),
+                }
+            }
+        }
+        uri
+    }
+
+    /// The icon as a CSS `url("data:...")` value, painted with `color`, e.g.
+    /// for a `background-image` or a `::before` pseudo-element's `content`.
+    pub fn css_url(&self, color: &str) -> String {
+        format!("url(\"{}\")", self.data_uri(color))
+    }
+}
+
+#[cfg(feature = "data-uri")]
+fn escape_svg(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,584 @@
+/// The SVG data of an icon, rendered by [`render_icon`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IconData {
+    /// The `viewBox` of the `<svg>`.
+    pub view_box: &'static str,
+    /// Whether the icon's shapes are filled or stroked.
+    pub mode: IconMode,
+    /// Presentation attributes of the `<svg>`, rendered when present.
+    pub fill: Option<&'static str>,
+    pub stroke: Option<&'static str>,
+    pub stroke_width: Option<&'static str>,
+    pub aria_hidden: Option<&'static str>,
+    pub data_slot: Option<&'static str>,
+    /// Other attributes of the `<svg>`, e.g. `("focusable", "false")`.
+    pub attributes: &'static [(&'static str, &'static str)],
+    /// Whether the icon points left or right, and is mirrored in
+    /// right-to-left layouts, see [`RightToLeft`].
+    pub directional: bool,
+    /// The text of the icon's `<title>`, if it has one.
+    pub title: Option<&'static str>,
+    /// The shapes that make up the icon.
+    pub paths: &'static [IconPath],
+    /// The markup of the shapes of icons that can't be drawn as
+    /// [`IconPath`]s, e.g. because of a `transform`, rendered instead of
+    /// `paths`.
+    pub markup: Option<&'static str>,
+}
+
+impl IconData {
+    /// An icon without any attributes or shapes, to fill in with struct
+    /// update syntax.
+    pub const EMPTY: IconData = IconData {
+        view_box: "",
+        mode: IconMode::Fill,
+        fill: None,
+        stroke: None,
+        stroke_width: None,
+        aria_hidden: None,
+        data_slot: None,
+        attributes: &[],
+        directional: false,
+        title: None,
+        paths: &[],
+        markup: None,
+    };
+}
+
+/// How an icon is painted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IconMode {
+    /// The shapes are filled, like the solid heroicons.
+    Fill,
+    /// The shapes are outlined, like the outline heroicons.
+    Stroke,
+}
+
+/// A `<path>` of an icon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IconPath {
+    pub d: &'static str,
+    pub fill_rule: Option<&'static str>,
+    pub clip_rule: Option<&'static str>,
+    pub stroke_linecap: Option<&'static str>,
+    pub stroke_linejoin: Option<&'static str>,
+    /// The paint of the path, when it isn't the `<svg>`'s, e.g. the secondary
+    /// color of duotone icons.
+    pub fill: Option<&'static str>,
+    pub stroke: Option<&'static str>,
+}
+
+impl IconPath {
+    /// A path without any data, to fill in with struct update syntax.
+    pub const EMPTY: IconPath = IconPath {
+        d: "",
+        fill_rule: None,
+        clip_rule: None,
+        stroke_linecap: None,
+        stroke_linejoin: None,
+        fill: None,
+        stroke: None,
+    };
+}
+
+/// Renders an icon the way the generated components do.
+pub fn render_icon(data: &'static IconData, class: &'static str) -> impl IntoView {
+    render_icon_with(
+        data,
+        IconProps {
+            class,
+            style: None,
+            node_ref: None,
+            rtl: None,
+            stroke_width: None,
+        },
+    )
+}
+
+/// Renders an icon like [`render_icon`], with all the props of the generated
+/// components.
+pub fn render_icon_with(
+    data: &'static IconData,
+    props: IconProps,
+) -> impl IntoView {
+    render_icon_props(data, props, DuotoneColors::default(), None)
+}
+
+/// Renders an icon like [`render_icon_with`], with the colors of
+/// [`DuotoneIconProps`], the way the generated components of icons with the
+/// `duotone` rule do.
+pub fn render_duotone_icon(
+    data: &'static IconData,
+    props: DuotoneIconProps,
+) -> impl IntoView {
+    let (props, colors) = props.split();
+    render_icon_props(data, props, colors, None)
+}
+
+/// Renders an icon like [`render_icon_with`], drawing `shapes` instead of its
+/// [`IconData::markup`], the way the components of icons that can't be drawn
+/// as [`IconPath`]s do.
+pub fn render_icon_with_shapes(
+    data: &'static IconData,
+    props: IconProps,
+    shapes: impl IntoView + 'static,
+) -> impl IntoView {
+    let shapes = Some(shapes.into_view().into_any());
+    render_icon_props(data, props, DuotoneColors::default(), shapes)
+}
+
+/// Renders an icon like [`render_icon_with_shapes`], with the colors of
+/// [`DuotoneIconProps`].
+pub fn render_duotone_icon_with_shapes(
+    data: &'static IconData,
+    props: DuotoneIconProps,
+    shapes: impl IntoView + 'static,
+) -> impl IntoView {
+    let (props, colors) = props.split();
+    let shapes = Some(shapes.into_view().into_any());
+    render_icon_props(data, props, colors, shapes)
+}
+
+/// The colors of a duotone icon, set as its custom properties.
+#[derive(Clone, Copy, Default)]
+struct DuotoneColors {
+    primary: Option<Signal<String>>,
+    secondary: Option<Signal<String>>,
+}
+
+fn render_icon_props(
+    data: &'static IconData,
+    props: IconProps,
+    colors: DuotoneColors,
+    shapes: Option<AnyView>,
+) -> impl IntoView {
+    let IconProps {
+        class,
+        style,
+        node_ref,
+        rtl,
+        stroke_width,
+    } = props;
+    let DuotoneColors { primary, secondary } = colors;
+    let defaults = use_context::<IconDefaults>().unwrap_or_default();
+    let style = style.or(defaults.style.map(|style| style.into_owned().into()));
+    let rtl = match data.directional {
+        true => rtl.or_else(|| use_context::<RightToLeft>().map(|rtl| rtl.0)),
+        false => None,
+    };
+
+    // Duotone colors are custom properties and directional icons are
+    // mirrored with a transform, set with the other styles.
+    let style = match (primary, secondary, rtl) {
+        (None, None, None) => style,
+        _ => Some(Signal::derive(move || {
+            let colors =
+                [("--hi-primary", primary), ("--hi-secondary", secondary)];
+            let mirror = rtl
+                .is_some_and(|rtl| rtl.get())
+                .then(|| "transform: scaleX(-1)".to_string());
+            style
+                .map(|style| style.get())
+                .into_iter()
+                .chain(colors.into_iter().filter_map(|(name, color)| {
+                    color.map(|color| format!("{name}: {}", color.get()))
+                }))
+                .chain(mirror)
+                .collect::<Vec<_>>()
+                .join("; ")
+        })),
+    };
+
+    render::render_svg(
+        *data,
+        render::Options {
+            class: match class {
+                "" => defaults.class,
+                class => class.into(),
+            },
+            stroke_width: stroke_width
+                .map(Into::into)
+                .or(defaults.stroke_width),
+            ..render::Options::default()
+        },
+        style,
+        node_ref,
+        shapes,
+    )
+}
+
+/// Whether the layout is right-to-left, provided as a context to mirror the
+/// directional icons in it, e.g. `provide_context(RightToLeft(is_rtl))`.
+///
+/// Only icons whose [`IconData::directional`] is set are mirrored, such as
+/// `ArrowLeft`, `ChevronRight` or `Backspace`. The `rtl` prop of an icon
+/// overrides the context.
+#[derive(Debug, Clone, Copy)]
+pub struct RightToLeft(pub Signal<bool>);
+
+/// Defaults for the props of the icons in an [`IconProvider`], which their
+/// own props override.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IconDefaults {
+    /// Classes of icons without a `class`, e.g. `w-5 h-5 shrink-0`.
+    pub class: std::borrow::Cow<'static, str>,
+    /// Inline styles of icons without a `style`.
+    pub style: Option<std::borrow::Cow<'static, str>>,
+    /// The `stroke-width` of outline icons without a `stroke_width`.
+    pub stroke_width: Option<std::borrow::Cow<'static, str>>,
+}
+
+/// Provides [`IconDefaults`] to the icons in its children, e.g.
+/// `<IconProvider defaults=IconDefaults { class: "size-5".into(), .. }>`.
+///
+/// A nested provider replaces all the defaults of the outer one.
+#[component]
+pub fn IconProvider(
+    defaults: IconDefaults,
+    children: Children,
+) -> impl IntoView {
+    use leptos::context::Provider;
+
+    view! { <Provider value=defaults>{children()}</Provider> }
+}
+
+/// The props of the generated components, which share them to keep the crate
+/// quick to compile.
+///
+/// Attributes and event listeners spread with `{..}` also go on the `<svg>`.
+#[slot]
+pub struct IconProps {
+    /// Classes of the `<svg>`.
+    #[prop(optional)]
+    class: &'static str,
+    /// Inline styles of the `<svg>`.
+    #[prop(optional, into)]
+    style: Option<Signal<String>>,
+    /// Filled with the `<svg>` once it's mounted.
+    #[prop(optional)]
+    node_ref: Option<NodeRef<leptos::svg::Svg>>,
+    /// Whether the layout is right-to-left, which mirrors directional icons
+    /// such as arrows. Defaults to the [`RightToLeft`] context.
+    #[prop(optional, into)]
+    rtl: Option<Signal<bool>>,
+    /// The `stroke-width` of outline icons, replacing their own.
+    #[prop(optional)]
+    stroke_width: Option<&'static str>,
+}
+
+// `view!` builds a component's props through `Props`, which `#[component]`
+// implements for the props struct it generates. Leptos has no public way to
+// share one props struct between components, so `#[slot]` generates the
+// builder, `IconPropsBuilder`, and `Props` is implemented by hand. Both rely
+// on what the macros generate, which leptos_heroicons' tests/leptos_api.rs
+// pins.
+impl leptos::component::Props for IconProps {
+    type Builder = IconPropsBuilder;
+
+    fn builder() -> Self::Builder {
+        IconProps::builder()
+    }
+}
+
+/// The props of the generated components of icons with the `duotone` rule:
+/// those of [`IconProps`] and their two colors.
+///
+/// Attributes and event listeners spread with `{..}` also go on the `<svg>`.
+#[slot]
+pub struct DuotoneIconProps {
+    /// Classes of the `<svg>`.
+    #[prop(optional)]
+    class: &'static str,
+    /// Inline styles of the `<svg>`.
+    #[prop(optional, into)]
+    style: Option<Signal<String>>,
+    /// Filled with the `<svg>` once it's mounted.
+    #[prop(optional)]
+    node_ref: Option<NodeRef<leptos::svg::Svg>>,
+    /// The primary color of icons generated with the `duotone` rule, the
+    /// `--hi-primary` custom property.
+    #[prop(optional, into)]
+    primary: Option<Signal<String>>,
+    /// The secondary color of icons generated with the `duotone` rule, the
+    /// `--hi-secondary` custom property.
+    #[prop(optional, into)]
+    secondary: Option<Signal<String>>,
+    /// Whether the layout is right-to-left, which mirrors directional icons
+    /// such as arrows. Defaults to the [`RightToLeft`] context.
+    #[prop(optional, into)]
+    rtl: Option<Signal<bool>>,
+    /// The `stroke-width` of outline icons, replacing their own.
+    #[prop(optional)]
+    stroke_width: Option<&'static str>,
+}
+
+impl DuotoneIconProps {
+    fn split(self) -> (IconProps, DuotoneColors) {
+        let DuotoneIconProps {
+            class,
+            style,
+            node_ref,
+            primary,
+            secondary,
+            rtl,
+            stroke_width,
+        } = self;
+        let props = IconProps {
+            class,
+            style,
+            node_ref,
+            rtl,
+            stroke_width,
+        };
+        (props, DuotoneColors { primary, secondary })
+    }
+}
+
+// Implemented by hand like `IconProps`.
+impl leptos::component::Props for DuotoneIconProps {
+    type Builder = DuotoneIconPropsBuilder;
+
+    fn builder() -> Self::Builder {
+        DuotoneIconProps::builder()
+    }
+}
+
+/// Renders `solid` while `props.solid` is true and `outline` otherwise, the
+/// way the generated components switching between styles do.
+pub fn render_dual_icon(
+    outline: &'static IconData,
+    solid: &'static IconData,
+    props: DualIconProps,
+) -> impl IntoView {
+    let DualIconProps {
+        solid: is_solid,
+        class,
+        style,
+        node_ref,
+        rtl,
+        stroke_width,
+    } = props;
+
+    move || {
+        let data = match is_solid.get() {
+            true => solid,
+            false => outline,
+        };
+        render_icon_with(
+            data,
+            IconProps {
+                class,
+                style,
+                node_ref,
+                rtl,
+                stroke_width,
+            },
+        )
+    }
+}
+
+/// Renders a duotone icon like [`render_dual_icon`], with the colors of
+/// [`DualDuotoneIconProps`].
+pub fn render_dual_duotone_icon(
+    outline: &'static IconData,
+    solid: &'static IconData,
+    props: DualDuotoneIconProps,
+) -> impl IntoView {
+    let DualDuotoneIconProps {
+        solid: is_solid,
+        class,
+        style,
+        node_ref,
+        primary,
+        secondary,
+        rtl,
+        stroke_width,
+    } = props;
+
+    move || {
+        let data = match is_solid.get() {
+            true => solid,
+            false => outline,
+        };
+        render_duotone_icon(
+            data,
+            DuotoneIconProps {
+                class,
+                style,
+                node_ref,
+                primary,
+                secondary,
+                rtl,
+                stroke_width,
+            },
+        )
+    }
+}
+
+/// The props of the generated components switching between the solid and
+/// the outline style of an icon.
+///
+/// Attributes and event listeners spread with `{..}` also go on the `<svg>`.
+#[slot]
+pub struct DualIconProps {
+    /// Whether the solid style is shown rather than the outline one.
+    #[prop(into)]
+    solid: Signal<bool>,
+    /// Classes of the `<svg>`.
+    #[prop(optional)]
+    class: &'static str,
+    /// Inline styles of the `<svg>`.
+    #[prop(optional, into)]
+    style: Option<Signal<String>>,
+    /// Filled with the `<svg>` once it's mounted.
+    #[prop(optional)]
+    node_ref: Option<NodeRef<leptos::svg::Svg>>,
+    /// Whether the layout is right-to-left, which mirrors directional icons
+    /// such as arrows. Defaults to the [`RightToLeft`] context.
+    #[prop(optional, into)]
+    rtl: Option<Signal<bool>>,
+    /// The `stroke-width` of outline icons, replacing their own.
+    #[prop(optional)]
+    stroke_width: Option<&'static str>,
+}
+
+// Implemented by hand like `IconProps`.
+impl leptos::component::Props for DualIconProps {
+    type Builder = DualIconPropsBuilder;
+
+    fn builder() -> Self::Builder {
+        DualIconProps::builder()
+    }
+}
+
+/// The props of the generated components switching between the solid and
+/// the outline style of an icon with the `duotone` rule: those of
+/// [`DualIconProps`] and the two colors of [`DuotoneIconProps`].
+///
+/// Attributes and event listeners spread with `{..}` also go on the `<svg>`.
+#[slot]
+pub struct DualDuotoneIconProps {
+    /// Whether the solid style is shown rather than the outline one.
+    #[prop(into)]
+    solid: Signal<bool>,
+    /// Classes of the `<svg>`.
+    #[prop(optional)]
+    class: &'static str,
+    /// Inline styles of the `<svg>`.
+    #[prop(optional, into)]
+    style: Option<Signal<String>>,
+    /// Filled with the `<svg>` once it's mounted.
+    #[prop(optional)]
+    node_ref: Option<NodeRef<leptos::svg::Svg>>,
+    /// The primary color of icons generated with the `duotone` rule, the
+    /// `--hi-primary` custom property.
+    #[prop(optional, into)]
+    primary: Option<Signal<String>>,
+    /// The secondary color of icons generated with the `duotone` rule, the
+    /// `--hi-secondary` custom property.
+    #[prop(optional, into)]
+    secondary: Option<Signal<String>>,
+    /// Whether the layout is right-to-left, which mirrors directional icons
+    /// such as arrows. Defaults to the [`RightToLeft`] context.
+    #[prop(optional, into)]
+    rtl: Option<Signal<bool>>,
+    /// The `stroke-width` of outline icons, replacing their own.
+    #[prop(optional)]
+    stroke_width: Option<&'static str>,
+}
+
+// Implemented by hand like `IconProps`.
+impl leptos::component::Props for DualDuotoneIconProps {
+    type Builder = DualDuotoneIconPropsBuilder;
+
+    fn builder() -> Self::Builder {
+        DualDuotoneIconProps::builder()
+    }
+}
+
+/// An icon listed in [`ICONS`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IconMeta {
+    /// The icon's file name, e.g. `arrow-down`.
+    pub name: &'static str,
+    /// The path of the component's module, e.g. `size_24::solid`.
+    pub module: &'static str,
+    /// The component's name, e.g. `ArrowDown`.
+    pub component: &'static str,
+    /// The icon's size, e.g. `24`, if it's in a size directory.
+    pub size: Option<u32>,
+    /// The icon's style, e.g. `solid` or `outline`.
+    pub style: Option<&'static str>,
+    /// The `viewBox` of the `<svg>`.
+    pub view_box: &'static str,
+    /// The feature that enables just this icon, e.g. `24-solid-arrow-down`.
+    pub feature: &'static str,
+    /// Search keywords besides the icon's name, e.g. `delete` for `trash`.
+    pub tags: &'static [&'static str],
+    /// The icon itself, for rendering with [`render_icon`].
+    pub data: &'static IconData,
+}
+
+/// The enabled icon with the given feature, e.g. `24-solid-trash`.
+pub fn find_icon(feature: &str) -> Option<&'static IconMeta> {
+    ICONS.iter().find(|icon| icon.feature == feature)
+}
+
+/// The enabled icons of the given size.
+pub fn icons_by_size(size: u32) -> impl Iterator<Item = &'static IconMeta> {
+    ICONS.iter().filter(move |icon| icon.size == Some(size))
+}
+
+/// The enabled icons of the given style, e.g. `solid` or `outline`.
+pub fn icons_by_style(
+    style: &str,
+) -> impl Iterator<Item = &'static IconMeta> + '_ {
+    ICONS.iter().filter(move |icon| icon.style == Some(style))
+}
+
+/// The enabled icons matching every word of `query`, best matches first.
+///
+/// A word matches an icon if its name or one of its tags contains it. Whole
+/// names rank above whole words of a name, which rank above whole tags and
+/// then partial matches, and shorter names rank above longer ones. An empty
+/// query matches every icon.
+pub fn search(query: &str) -> Vec<&'static IconMeta> {
+    let query = query.to_lowercase();
+    let words: Vec<&str> = query.split_whitespace().collect();
+    if words.is_empty() {
+        return ICONS.iter().collect();
+    }
+
+    let mut matches: Vec<(u32, &'static IconMeta)> = ICONS
+        .iter()
+        .filter_map(|icon| {
+            let score = words
+                .iter()
+                .map(|word| search_score(icon, word))
+                .sum::<Option<u32>>()?;
+            Some((score, icon))
+        })
+        .collect();
+    // The sort is stable, so equally good matches stay in module order.
+    matches.sort_by_key(|(score, icon)| {
+        (std::cmp::Reverse(*score), icon.name.len())
+    });
+
+    matches.into_iter().map(|(_, icon)| icon).collect()
+}
+
+fn search_score(icon: &IconMeta, word: &str) -> Option<u32> {
+    if icon.name == word {
+        Some(4)
+    } else if icon.name.split('-').any(|part| part == word) {
+        Some(3)
+    } else if icon.tags.contains(&word) {
+        Some(2)
+    } else if icon.name.contains(word)
+        || icon.tags.iter().any(|tag| tag.contains(word))
+    {
+        Some(1)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,25 @@
+
+/// Renders the icon like its component without a class.
+#[cfg(feature = "maud")]
+impl maud::Render for IconData {
+    fn render(&self) -> maud::Markup {
+        maud::PreEscaped(render::to_html(self, render::Options::default()))
+    }
+}
+
+/// Renders the icon like its component without a class.
+#[cfg(feature = "maud")]
+impl maud::Render for IconMeta {
+    fn render(&self) -> maud::Markup {
+        self.data.render()
+    }
+}
+
+/// Renders the icon with its options, e.g.
+/// `render::Icon::new(&size_24::outline::BELL, "size-5")`.
+#[cfg(feature = "maud")]
+impl maud::Render for render::Icon {
+    fn render(&self) -> maud::Markup {
+        maud::PreEscaped(self.to_string())
+    }
+}
@@ -0,0 +1,317 @@
+
+/// A searchable grid of the enabled icons, grouped by size and style, for
+/// choosing one of them.
+///
+/// Icons are identified by their feature, e.g. `24-solid-trash`, which
+/// [`find_icon`] turns back into the icon. Left and Right move to the previous
+/// and next icon, Up and Down to the closest icon in the row above or below as
+/// laid out, Home and End to the first and last icon, and Enter or Space picks
+/// one.
+///
+/// The picker is unstyled: its parts have `icon-picker-*` classes and the
+/// selected icon has `aria-selected="true"`.
+#[cfg(feature = "icon-picker")]
+#[component]
+pub fn IconPicker(
+    /// The feature of the selected icon, if any.
+    #[prop(into)]
+    value: Signal<Option<String>>,
+    /// Called with the feature of the icon the user picks.
+    #[prop(into)]
+    on_change: Callback<String>,
+    /// Classes of the picker's root element.
+    #[prop(optional)]
+    class: &'static str,
+    /// Classes of every icon.
+    #[prop(default = "size-6")]
+    icon_class: &'static str,
+) -> impl IntoView {
+    let (query, set_query) = signal(String::new());
+    let icons = Memo::new(move |_| picker_groups(&query.read()));
+    // The icon that can be focused with Tab, as an index into `icons`.
+    let active = RwSignal::new(0);
+    let grid = NodeRef::<leptos::html::Div>::new();
+
+    let focus = move |index: usize| {
+        active.set(index);
+        let option = grid.get_untracked().and_then(|grid| {
+            grid.query_selector(&format!("[data-index=\"{index}\"]"))
+                .ok()
+                .flatten()
+        });
+        if let Some(option) = option {
+            use leptos::wasm_bindgen::JsCast;
+            let option = option.unchecked_into::<leptos::web_sys::HtmlElement>();
+            let _ = option.focus();
+        }
+    };
+    // The position of every icon, for moving between rows of any layout.
+    let positions = move || -> Vec<(i32, i32)> {
+        use leptos::wasm_bindgen::JsCast;
+        let Some(grid) = grid.get_untracked() else {
+            return vec![];
+        };
+        let options = grid.get_elements_by_class_name("icon-picker-option");
+        (0..options.length())
+            .filter_map(|index| options.item(index))
+            .map(|option| {
+                let option =
+                    option.unchecked_into::<leptos::web_sys::HtmlElement>();
+                (option.offset_left(), option.offset_top())
+            })
+            .collect()
+    };
+    let pick = move |index: usize| {
+        active.set(index);
+        let icon = icons.with_untracked(|icons| icons.get(index).copied());
+        if let Some(icon) = icon {
+            on_change.run(icon.feature.to_string());
+        }
+    };
+    let on_keydown = move |event: leptos::ev::KeyboardEvent| {
+        let Some(last) = icons.with_untracked(Vec::len).checked_sub(1) else {
+            return;
+        };
+        let index = active.get_untracked().min(last);
+        match event.key().as_str() {
+            "ArrowRight" => focus((index + 1).min(last)),
+            "ArrowLeft" => focus(index.saturating_sub(1)),
+            "ArrowDown" => focus(
+                picker_row_step(&positions(), index, true).unwrap_or(index),
+            ),
+            "ArrowUp" => focus(
+                picker_row_step(&positions(), index, false).unwrap_or(index),
+            ),
+            "Home" => focus(0),
+            "End" => focus(last),
+            "Enter" | " " => pick(index),
+            _ => return,
+        }
+        event.prevent_default();
+    };
+
+    let groups = move || {
+        let icons = icons.get();
+        let mut groups: Vec<(String, Vec<(usize, &IconMeta)>)> = vec![];
+        for (index, icon) in icons.into_iter().enumerate() {
+            let name = picker_group_name(icon);
+            match groups.last_mut() {
+                Some((group, icons)) if *group == name => {
+                    icons.push((index, icon))
+                }
+                _ => groups.push((name, vec![(index, icon)])),
+            }
+        }
+
+        groups
+            .into_iter()
+            .map(|(name, icons)| {
+                let options = icons
+                    .into_iter()
+                    .map(|(index, icon)| {
+                        view! {
+                            <button
+                                type="button"
+                                role="option"
+                                class="icon-picker-option"
+                                title=icon.name
+                                data-index=index
+                                tabindex=move || match active.get() == index {
+                                    true => "0",
+                                    false => "-1",
+                                }
+                                aria-selected=move || {
+                                    let value = value.read();
+                                    (value.as_deref() == Some(icon.feature)).to_string()
+                                }
+                                on:click=move |_| pick(index)
+                            >
+                                {render_icon(icon.data, icon_class)}
+                            </button>
+                        }
+                    })
+                    .collect_view();
+                let label = name.clone();
+
+                view! {
+                    <div role="group" class="icon-picker-group" aria-label=label>
+                        <div class="icon-picker-group-name">{name}</div>
+                        {options}
+                    </div>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <div class=format!("icon-picker {class}")>
+            <input
+                type="search"
+                class="icon-picker-search"
+                placeholder="Search icons"
+                prop:value=query
+                on:input=move |event| {
+                    set_query.set(event_target_value(&event));
+                    active.set(0);
+                }
+            />
+            <div
+                node_ref=grid
+                role="listbox"
+                class="icon-picker-icons"
+                aria-label="Icons"
+                on:keydown=on_keydown
+            >
+                {groups}
+            </div>
+        </div>
+    }
+}
+
+/// The icons matching `query`, best matches first, with the icons of each
+/// size and style kept together.
+#[cfg(feature = "icon-picker")]
+fn picker_groups(query: &str) -> Vec<&'static IconMeta> {
+    let icons = search(query);
+    let mut groups: Vec<String> = vec![];
+    for icon in &icons {
+        let name = picker_group_name(icon);
+        if !groups.contains(&name) {
+            groups.push(name);
+        }
+    }
+
+    let mut icons = icons;
+    // The sort is stable, so icons keep their rank within their group.
+    icons.sort_by_key(|icon| {
+        let name = picker_group_name(icon);
+        groups.iter().position(|group| *group == name)
+    });
+    icons
+}
+
+/// The icon in the row below (or above) the icon at `index` that's closest to
+/// its column, given the `(left, top)` position of every icon, or `None` in
+/// the last (or first) row.
+#[cfg(feature = "icon-picker")]
+fn picker_row_step(
+    positions: &[(i32, i32)],
+    index: usize,
+    down: bool,
+) -> Option<usize> {
+    let &(left, top) = positions.get(index)?;
+    let tops = positions.iter().map(|&(_, top)| top);
+    let row = match down {
+        true => tops.filter(|&other| other > top).min(),
+        false => tops.filter(|&other| other < top).max(),
+    }?;
+    positions
+        .iter()
+        .enumerate()
+        .filter(|(_, &(_, top))| top == row)
+        .min_by_key(|(_, &(other, _))| other.abs_diff(left))
+        .map(|(index, _)| index)
+}
+
+#[cfg(feature = "icon-picker")]
+fn picker_group_name(icon: &IconMeta) -> String {
+    match (icon.size, icon.style) {
+        (Some(size), Some(style)) => format!("{size} {style}"),
+        (Some(size), None) => size.to_string(),
+        (None, Some(style)) => style.to_string(),
+        (None, None) => String::new(),
+    }
+}
+
+#[cfg(all(test, feature = "icon-picker"))]
+mod picker_tests {
+    use super::*;
+
+    /// The group names of `icons`, in order, with consecutive repeats merged.
+    fn group_runs(icons: &[&IconMeta]) -> Vec<String> {
+        let mut runs: Vec<String> = vec![];
+        for icon in icons {
+            let name = picker_group_name(icon);
+            if runs.last() != Some(&name) {
+                runs.push(name);
+            }
+        }
+        runs
+    }
+
+    #[test]
+    fn groups_keep_their_icons_together_in_search_order() {
+        let queries = ICONS.iter().map(|icon| icon.name).take(3);
+        for query in ["", "a"].into_iter().chain(queries) {
+            let found = search(query);
+            let icons = picker_groups(query);
+            assert_eq!(icons.len(), found.len(), "{query:?}");
+
+            // Every group is one run, in the order of its best match.
+            let runs = group_runs(&icons);
+            let mut groups = group_runs(&found);
+            let mut seen = vec![];
+            groups.retain(|group| {
+                let new = !seen.contains(group);
+                seen.push(group.clone());
+                new
+            });
+            assert_eq!(runs, groups, "{query:?}");
+
+            // Icons keep their rank within their group.
+            for group in &groups {
+                let in_group = |icons: &[&'static IconMeta]| {
+                    icons
+                        .iter()
+                        .filter(|icon| picker_group_name(icon) == *group)
+                        .map(|icon| icon.feature)
+                        .collect::<Vec<_>>()
+                };
+                assert_eq!(in_group(&icons), in_group(&found), "{query:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn names_groups_by_size_and_style() {
+        let Some(&first) = ICONS.first() else {
+            return;
+        };
+        let icon = |size, style| IconMeta {
+            size,
+            style,
+            ..first
+        };
+        assert_eq!(
+            picker_group_name(&icon(Some(24), Some("solid"))),
+            "24 solid"
+        );
+        assert_eq!(picker_group_name(&icon(Some(24), None)), "24");
+        assert_eq!(picker_group_name(&icon(None, Some("solid"))), "solid");
+        assert_eq!(picker_group_name(&icon(None, None)), "");
+    }
+
+    #[test]
+    fn row_steps_move_to_the_closest_column() {
+        // Two rows of three icons, then a group with a row of two icons,
+        // offset by its label.
+        let positions = [
+            (0, 20),
+            (30, 20),
+            (60, 20),
+            (0, 50),
+            (30, 50),
+            (60, 50),
+            (10, 100),
+            (40, 100),
+        ];
+        assert_eq!(picker_row_step(&positions, 1, true), Some(4));
+        assert_eq!(picker_row_step(&positions, 4, false), Some(1));
+        assert_eq!(picker_row_step(&positions, 5, true), Some(7));
+        assert_eq!(picker_row_step(&positions, 3, true), Some(6));
+        assert_eq!(picker_row_step(&positions, 7, false), Some(4));
+        assert_eq!(picker_row_step(&positions, 0, false), None);
+        assert_eq!(picker_row_step(&positions, 6, true), None);
+        assert_eq!(picker_row_step(&[], 0, true), None);
+    }
+}
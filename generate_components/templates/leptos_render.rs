@@ -0,0 +1,158 @@
+
+/// Renders icons to HTML outside of a Leptos app, e.g. in HTTP handlers or
+/// emails.
+pub mod render {
+    use std::borrow::Cow;
+
+    use leptos::prelude::*;
+
+    use crate::IconData;
+
+    /// How [`to_html`] renders an icon. The defaults render it like its
+    /// component without a class.
+    #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+    pub struct Options {
+        /// Classes of the `<svg>`.
+        pub class: Cow<'static, str>,
+        /// The `width` and `height` of the `<svg>`, e.g. `24` or `1.5em`.
+        pub size: Option<Cow<'static, str>>,
+        /// The color painting the icon, through `currentColor`.
+        pub color: Option<Cow<'static, str>>,
+        /// The text of the icon's `<title>`, replacing its own.
+        pub title: Option<Cow<'static, str>>,
+        /// The `stroke-width` of outline icons, replacing their own.
+        pub stroke_width: Option<Cow<'static, str>>,
+    }
+
+    /// The HTML of an icon, e.g. `to_html(&size_24::solid::BELL, options)`.
+    ///
+    /// The icon is rendered with the components' own code, so it's the same
+    /// HTML they render, without the `<!>` markers Leptos leaves for
+    /// hydration.
+    #[cfg(feature = "ssr")]
+    pub fn to_html(data: &IconData, options: Options) -> String {
+        render_svg(*data, options, None, None, None)
+            .to_html()
+            .replace("<!>", "")
+    }
+
+    /// An icon with the [`Options`] to render it with, displayed as its
+    /// HTML.
+    #[cfg(feature = "ssr")]
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub struct Icon {
+        pub data: &'static IconData,
+        pub options: Options,
+    }
+
+    #[cfg(feature = "ssr")]
+    impl Icon {
+        /// The icon with the given classes.
+        pub fn new(
+            data: &'static IconData,
+            class: impl Into<Cow<'static, str>>,
+        ) -> Self {
+            Icon {
+                data,
+                options: Options {
+                    class: class.into(),
+                    ..Options::default()
+                },
+            }
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    impl std::fmt::Display for Icon {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(&to_html(self.data, self.options.clone()))
+        }
+    }
+
+    /// Renders an icon's `<svg>`, with `shapes` instead of the icon's own
+    /// when given.
+    pub(crate) fn render_svg(
+        data: IconData,
+        options: Options,
+        style: Option<Signal<String>>,
+        node_ref: Option<NodeRef<leptos::svg::Svg>>,
+        shapes: Option<AnyView>,
+    ) -> impl IntoView {
+        let title = options.title.or(data.title.map(Cow::Borrowed));
+        // Icons whose shapes don't fit `IconPath`s render their own markup,
+        // in a group since only elements without children can set it.
+        let markup = data.markup.filter(|_| shapes.is_none());
+        // Styles can be empty, e.g. when a directional icon isn't mirrored.
+        let style = style.map(|style| {
+            move || Some(style.get()).filter(|style| !style.is_empty())
+        });
+
+        view! {
+            <svg
+                class=options.class
+                xmlns="http://www.w3.org/2000/svg"
+                width=options.size.clone()
+                height=options.size
+                style=style
+                style:color=options.color.map(Cow::into_owned)
+                node_ref=SvgRef(node_ref)
+                fill=data.fill
+                viewBox=data.view_box
+                stroke-width=data
+                    .stroke_width
+                    .map(|own| options.stroke_width.unwrap_or(own.into()))
+                stroke=data.stroke
+                aria-hidden=data.aria_hidden
+                data-slot=data.data_slot
+                {..data
+                    .attributes
+                    .iter()
+                    .map(|&(name, value)| {
+                        leptos::attr::custom::custom_attribute(name, value)
+                            .into_any_attr()
+                    })
+                    .collect::<Vec<_>>()}
+            >
+                {title.map(|title| view! { <title>{title}</title> })}
+                {shapes}
+                {markup.map(|markup| view! { <g inner_html=markup></g> })}
+                {data
+                    .paths
+                    .iter()
+                    .map(|path| {
+                        view! {
+                            <path
+                                d=path.d
+                                fill-rule=path.fill_rule
+                                clip-rule=path.clip_rule
+                                stroke-linecap=path.stroke_linecap
+                                stroke-linejoin=path.stroke_linejoin
+                                fill=path.fill
+                                stroke=path.stroke
+                            />
+                        }
+                    })
+                    .collect_view()}
+            </svg>
+        }
+    }
+
+    /// The `node_ref` of a component, filled with its `<svg>` if given.
+    ///
+    /// Leptos only takes a `NodeRef` itself, and making one for every icon
+    /// that isn't given one would cost a signal each, so this implements
+    /// tachys' `NodeRefContainer`, whose `load` takes the element type of its
+    /// renderer. leptos_heroicons' tests/leptos_api.rs pins it.
+    #[derive(Clone, Copy)]
+    struct SvgRef(Option<NodeRef<leptos::svg::Svg>>);
+
+    impl leptos::tachys::html::node_ref::NodeRefContainer<leptos::svg::Svg>
+        for SvgRef
+    {
+        fn load(self, el: &leptos::tachys::renderer::types::Element) {
+            if let Some(node_ref) = self.0 {
+                node_ref.load(el);
+            }
+        }
+    }
+}
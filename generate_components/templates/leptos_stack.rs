@@ -0,0 +1,182 @@
+
+/// A corner of an [`IconStack`] or [`IconBadge`].
+#[cfg(feature = "stack")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Corner {
+    TopLeft,
+    #[default]
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+#[cfg(feature = "stack")]
+impl Corner {
+    /// Where a `width` by `height` box in this corner of a 100 by 100 box
+    /// starts.
+    fn origin(self, width: f64, height: f64) -> (f64, f64) {
+        match self {
+            Corner::TopLeft => (0.0, 0.0),
+            Corner::TopRight => (100.0 - width, 0.0),
+            Corner::BottomLeft => (0.0, 100.0 - height),
+            Corner::BottomRight => (100.0 - width, 100.0 - height),
+        }
+    }
+}
+
+/// Draws the `overlay` icon in a corner of the icon it wraps, e.g. an
+/// `<XMark />` over a `<Bell />`.
+///
+/// Icons are drawn as nested SVGs, so size the stack with `class` rather
+/// than the icons.
+#[cfg(feature = "stack")]
+#[component]
+pub fn IconStack(
+    /// The icon drawn over the wrapped one.
+    #[prop(into)]
+    overlay: ViewFnOnce,
+    /// The corner of the overlay.
+    #[prop(optional)]
+    corner: Corner,
+    /// The size of the overlay relative to the wrapped icon.
+    #[prop(default = 0.5)]
+    scale: f64,
+    /// Classes of the stack's `<svg>`.
+    #[prop(optional)]
+    class: &'static str,
+    children: Children,
+) -> impl IntoView {
+    let size = scale * 100.0;
+    let (x, y) = corner.origin(size, size);
+
+    view! {
+        <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 100 100" class=class>
+            {children()}
+            <svg x=x y=y width=size height=size>
+                {overlay.run()}
+            </svg>
+        </svg>
+    }
+}
+
+/// Draws a badge with `text`, e.g. an unread count, in a corner of the icon
+/// it wraps.
+///
+/// No badge is drawn while `text` is empty. The badge has the `icon-badge`
+/// class and its text `icon-badge-text`, to restyle them.
+#[cfg(feature = "stack")]
+#[component]
+pub fn IconBadge(
+    /// The text of the badge.
+    #[prop(into)]
+    text: Signal<String>,
+    /// The corner of the badge.
+    #[prop(optional)]
+    corner: Corner,
+    /// The height of the badge relative to the wrapped icon.
+    #[prop(default = 0.5)]
+    scale: f64,
+    /// The color of the badge.
+    #[prop(default = "#ef4444")]
+    color: &'static str,
+    /// The color of the badge's text.
+    #[prop(default = "white")]
+    text_color: &'static str,
+    /// Classes of the badge's `<svg>`.
+    #[prop(optional)]
+    class: &'static str,
+    children: Children,
+) -> impl IntoView {
+    let badge = move || {
+        let text = text.get();
+        let (width, height) = badge_size(&text, scale)?;
+        let font_size = height * BADGE_FONT_SIZE;
+        let (x, y) = corner.origin(width, height);
+
+        Some(view! {
+            <rect
+                class="icon-badge"
+                x=x
+                y=y
+                width=width
+                height=height
+                rx=height / 2.0
+                fill=color
+            />
+            <text
+                class="icon-badge-text"
+                x=x + width / 2.0
+                y=y + height / 2.0
+                font-size=font_size
+                font-weight="600"
+                text-anchor="middle"
+                dominant-baseline="central"
+                fill=text_color
+            >
+                {text}
+            </text>
+        })
+    };
+
+    view! {
+        <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 100 100" class=class>
+            {children()}
+            {badge}
+        </svg>
+    }
+}
+
+/// The font size of a badge's text relative to its height.
+#[cfg(feature = "stack")]
+const BADGE_FONT_SIZE: f64 = 0.65;
+
+/// The width and height of a badge with `text` and `scale` in a 100 by 100
+/// box, or `None` without text.
+#[cfg(feature = "stack")]
+fn badge_size(text: &str, scale: f64) -> Option<(f64, f64)> {
+    if text.is_empty() {
+        return None;
+    }
+    let height = scale * 100.0;
+    let font_size = height * BADGE_FONT_SIZE;
+    // Widens the badge for longer text, assuming digit-wide characters.
+    let width = height
+        .max(text.chars().count() as f64 * font_size * 0.6 + height * 0.4);
+    Some((width, height))
+}
+
+#[cfg(all(test, feature = "stack"))]
+mod stack_tests {
+    use super::*;
+
+    #[test]
+    fn corners_keep_boxes_inside() {
+        assert_eq!(Corner::TopLeft.origin(30.0, 20.0), (0.0, 0.0));
+        assert_eq!(Corner::TopRight.origin(30.0, 20.0), (70.0, 0.0));
+        assert_eq!(Corner::BottomLeft.origin(30.0, 20.0), (0.0, 80.0));
+        assert_eq!(Corner::BottomRight.origin(30.0, 20.0), (70.0, 80.0));
+        assert_eq!(Corner::default(), Corner::TopRight);
+    }
+
+    #[test]
+    fn badges_are_round_for_short_text() {
+        assert_eq!(badge_size("3", 0.5), Some((50.0, 50.0)));
+        assert_eq!(badge_size("9", 0.25), Some((25.0, 25.0)));
+    }
+
+    #[test]
+    fn badges_widen_with_their_text() {
+        let (one, height) = badge_size("9", 0.5).unwrap();
+        let (two, _) = badge_size("99", 0.5).unwrap();
+        let (three, _) = badge_size("99+", 0.5).unwrap();
+        assert!(one < two && two < three, "{one} {two} {three}");
+        assert_eq!(height, 50.0);
+        // Characters count once however many bytes they take.
+        assert_eq!(badge_size("é", 0.5), badge_size("e", 0.5));
+    }
+
+    #[test]
+    fn empty_text_has_no_badge() {
+        assert_eq!(badge_size("", 0.5), None);
+    }
+}
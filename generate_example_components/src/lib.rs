@@ -0,0 +1,166 @@
+//! Generates the example's gallery data from the components in
+//! `leptos_heroicons`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path;
+
+use anyhow::Result;
+use convert_case::Casing;
+use indoc::formatdoc;
+
+/// A component found in the library, along with what the gallery shows
+/// about it.
+struct GalleryIcon {
+    name: String,
+    component_name: String,
+    feature: String,
+}
+
+/// Writes the example's `components.rs` from the library at `lib_path`,
+/// listing every icon component by module.
+pub fn generate(
+    lib_path: &path::Path,
+    components_path: &path::Path,
+) -> Result<()> {
+    let library = syn::parse_file(&fs::read_to_string(lib_path)?)?;
+
+    let mut component_type_to_components: BTreeMap<String, Vec<GalleryIcon>> =
+        BTreeMap::new();
+    // Only components in a module are icons, the crate root also has helpers
+    // such as `IconPicker`.
+    for item in &library.items {
+        if let syn::Item::Mod(module) = item {
+            collect_icons(module, &[], &mut component_type_to_components)?;
+        }
+    }
+
+    let mut components_file = fs::File::create(components_path)?;
+    writeln!(&mut components_file, "use leptos::prelude::*;\n")?;
+    writeln!(&mut components_file, "use crate::GalleryIcon;\n")?;
+
+    for (component_type, components) in component_type_to_components {
+        writeln!(
+            &mut components_file,
+            "{}",
+            formatdoc! {r#"
+                pub fn {}() -> Vec<GalleryIcon> {{
+                    use leptos_heroicons::{component_type}::*;
+
+                    vec!["#,
+                component_type.replace("::", "_").to_case(convert_case::Case::Snake)
+            }
+        )?;
+
+        for GalleryIcon {
+            name,
+            component_name,
+            feature,
+        } in components
+        {
+            let component = formatdoc! {r#"
+                GalleryIcon {{
+                    name: "{name}",
+                    component: "{component_name}",
+                    module: "{component_type}",
+                    feature: "{feature}",
+                    view: || view! {{ <{component_name} /> }}.into_any(),
+                }},"#};
+            for line in component.lines() {
+                writeln!(&mut components_file, "        {line}")?;
+            }
+        }
+
+        writeln!(&mut components_file, "    ]\n}}\n")?;
+    }
+
+    Ok(())
+}
+
+/// Walks a `pub mod`, collecting its components by module path.
+fn collect_icons(
+    module: &syn::ItemMod,
+    parents: &[String],
+    component_type_to_components: &mut BTreeMap<String, Vec<GalleryIcon>>,
+) -> Result<()> {
+    let (syn::Visibility::Public(_), Some((_, items))) =
+        (&module.vis, &module.content)
+    else {
+        return Ok(());
+    };
+    let path = [parents, &[module.ident.to_string()]].concat();
+
+    for item in items {
+        match item {
+            syn::Item::Mod(module) => {
+                collect_icons(module, &path, component_type_to_components)?
+            }
+            syn::Item::Fn(function) if is_component(function) => {
+                // Icons are gated by their own feature and then by their
+                // module's, e.g.
+                // `any(feature = "24-solid-bolt", feature = "24-solid")`.
+                // Other components, such as the ones switching between
+                // styles, need more.
+                let features = cfg_features(&function.attrs)?;
+                let (feature, name) = match features.as_slice() {
+                    [feature, group] => (
+                        feature.clone(),
+                        feature
+                            .strip_prefix(&format!("{group}-"))
+                            .unwrap_or(feature)
+                            .to_string(),
+                    ),
+                    [feature] => (feature.clone(), feature.clone()),
+                    _ => continue,
+                };
+
+                component_type_to_components
+                    .entry(path.join("::"))
+                    .or_default()
+                    .push(GalleryIcon {
+                        name,
+                        component_name: function.sig.ident.to_string(),
+                        feature,
+                    });
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether the function is a component: icons take the shared `IconProps`
+/// rather than being `#[component]`s, but both are named in `PascalCase`.
+fn is_component(function: &syn::ItemFn) -> bool {
+    matches!(function.vis, syn::Visibility::Public(_))
+        && function
+            .sig
+            .ident
+            .to_string()
+            .starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+/// The features named by `#[cfg(...)]` attributes, in order.
+fn cfg_features(attrs: &[syn::Attribute]) -> Result<Vec<String>> {
+    fn visit(
+        meta: syn::meta::ParseNestedMeta,
+        features: &mut Vec<String>,
+    ) -> syn::Result<()> {
+        if meta.path.is_ident("feature") {
+            let feature: syn::LitStr = meta.value()?.parse()?;
+            features.push(feature.value());
+            Ok(())
+        } else {
+            // `any(...)`, `all(...)` or `not(...)`.
+            meta.parse_nested_meta(|meta| visit(meta, features))
+        }
+    }
+
+    let mut features = vec![];
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("cfg")) {
+        attr.parse_nested_meta(|meta| visit(meta, &mut features))?;
+    }
+    Ok(features)
+}
@@ -0,0 +1,98 @@
+//! Rasterizes SVG icons to PNG and ICO images with a pure-Rust renderer.
+
+use anyhow::{bail, Context, Result};
+use resvg::{tiny_skia, usvg};
+
+/// How an icon is painted when it's rasterized.
+#[derive(Debug, Clone)]
+pub struct Paint {
+    /// The color of the icon, in place of `currentColor`, as any SVG color.
+    pub color: String,
+    /// The color filling the image behind the icon, if any.
+    pub background: Option<String>,
+    /// The margin around the icon, as a fraction of the image's size.
+    pub padding: f32,
+}
+
+/// Renders the SVG `source` into a `size` by `size` PNG.
+pub fn render_png(source: &str, size: u32, paint: &Paint) -> Result<Vec<u8>> {
+    let svg = compose(source, size, paint);
+    let tree = usvg::Tree::from_str(&svg, &usvg::Options::default())
+        .context("parsing the icon")?;
+
+    let mut pixmap = tiny_skia::Pixmap::new(size, size)
+        .with_context(|| format!("invalid image size {size}"))?;
+    resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+
+    Ok(pixmap.encode_png()?)
+}
+
+/// The largest images ICO files can hold, in pixels.
+pub const ICO_MAX_SIZE: u32 = 256;
+
+/// Bundles PNGs, by their size, into an ICO file.
+pub fn encode_ico(images: &[(u32, Vec<u8>)]) -> Result<Vec<u8>> {
+    const HEADER_LEN: usize = 6;
+    const ENTRY_LEN: usize = 16;
+
+    let mut ico = vec![];
+    ico.extend(0u16.to_le_bytes());
+    // The file is an icon rather than a cursor.
+    ico.extend(1u16.to_le_bytes());
+    ico.extend(u16::try_from(images.len())?.to_le_bytes());
+
+    let mut offset = HEADER_LEN + ENTRY_LEN * images.len();
+    for (size, png) in images {
+        // Sizes are stored in a byte, with 0 meaning 256.
+        let stored_size = match *size {
+            1..=255 => *size as u8,
+            ICO_MAX_SIZE => 0,
+            size => bail!("ICO images can't be {size} pixels wide"),
+        };
+        ico.extend([stored_size, stored_size, 0, 0]);
+        // Color planes and bits per pixel.
+        ico.extend(1u16.to_le_bytes());
+        ico.extend(32u16.to_le_bytes());
+        ico.extend(u32::try_from(png.len())?.to_le_bytes());
+        ico.extend(u32::try_from(offset)?.to_le_bytes());
+        offset += png.len();
+    }
+    for (_, png) in images {
+        ico.extend(png);
+    }
+
+    Ok(ico)
+}
+
+/// Wraps the icon in a `size` by `size` SVG, painted and padded as asked.
+fn compose(source: &str, size: u32, paint: &Paint) -> String {
+    // `currentColor` is only ever inside attribute values.
+    let icon = source.replace("currentColor", &escape_attribute(&paint.color));
+    let inset = size as f32 * paint.padding;
+    let icon_size = size as f32 - 2.0 * inset;
+    let background = match &paint.background {
+        Some(color) => {
+            let color = escape_attribute(color);
+            format!("<rect width=\"100%\" height=\"100%\" fill=\"{color}\"/>")
+        }
+        None => String::new(),
+    };
+
+    // The icon's own `<svg>` fills the nested one, keeping its viewBox.
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{size}\" \
+         height=\"{size}\" viewBox=\"0 0 {size} {size}\">{background}<svg \
+         x=\"{inset}\" y=\"{inset}\" width=\"{icon_size}\" \
+         height=\"{icon_size}\">{icon}</svg></svg>"
+    )
+}
+
+/// Escapes `value` for an attribute value in either kind of quotes.
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
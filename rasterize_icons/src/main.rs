@@ -0,0 +1,173 @@
+use std::fs;
+use std::path;
+
+use anyhow::{bail, Context, Result};
+use clap::{Parser, ValueEnum};
+use generate_components::download_heroicons::*;
+use generate_components::OPTIMIZED_PATH;
+use rasterize_icons::*;
+
+/// Rasterizes heroicons to PNG, ICO and Apple touch icons, e.g. for favicons
+/// and PWA manifests.
+///
+/// Without `--input`, the latest heroicons are downloaded.
+#[derive(Debug, Parser)]
+struct Args {
+    /// The icons to rasterize, as paths in the icons' directory without the
+    /// extension, e.g. `24/solid/bell`.
+    #[arg(required = true)]
+    icons: Vec<String>,
+    /// Directory of SVG icons, e.g. heroicons' `optimized` directory.
+    #[arg(long)]
+    input: Option<path::PathBuf>,
+    /// Directory the images are written to.
+    #[arg(long, default_value = ".")]
+    output: path::PathBuf,
+    /// Sizes of the PNGs and of the images in the ICO file, in pixels. Sizes
+    /// over 256 are left out of the ICO file.
+    #[arg(long = "size", value_delimiter = ',', default_values_t = [16, 32, 48])]
+    sizes: Vec<u32>,
+    /// Color of the icons, as any SVG color.
+    #[arg(long, default_value = "black")]
+    color: String,
+    /// Color behind the icons. Transparent by default, except for Apple
+    /// touch icons which are white.
+    #[arg(long)]
+    background: Option<String>,
+    /// The images to write.
+    #[arg(
+        long = "format",
+        value_enum,
+        value_delimiter = ',',
+        default_values_t = [Format::Png, Format::Ico, Format::AppleTouch]
+    )]
+    formats: Vec<Format>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// `<icon>-<size>.png` for every size.
+    Png,
+    /// `<icon>.ico` with every size.
+    Ico,
+    /// `<icon>-apple-touch-icon.png`, 180 pixels wide on an opaque background.
+    AppleTouch,
+}
+
+/// The size of Apple touch icons, in pixels.
+const APPLE_TOUCH_SIZE: u32 = 180;
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    if args.sizes.contains(&0) {
+        bail!("sizes must be at least 1 pixel");
+    }
+    if args.formats.contains(&Format::Ico)
+        && args.sizes.iter().any(|&size| size > ICO_MAX_SIZE)
+    {
+        eprintln!(
+            "warning: ICO files can't hold images over {ICO_MAX_SIZE} pixels, \
+             leaving them out"
+        );
+    }
+
+    // Downloads go to a directory of their own, so concurrent runs don't
+    // share it, which is removed when dropped, even on errors.
+    let mut download_dir = None;
+    let input_dir = match &args.input {
+        Some(input_dir) => input_dir.clone(),
+        None => {
+            println!("Downloading heroicons");
+            let dir = download_dir.insert(
+                tempfile::Builder::new()
+                    .prefix("rasterize_icons-")
+                    .tempdir()
+                    .context("creating a download directory")?,
+            );
+            download_latest_icons(dir.path())?;
+            extract_latest_icons(dir.path())?;
+            dir.path().join(OPTIMIZED_PATH)
+        }
+    };
+
+    fs::create_dir_all(&args.output)?;
+    for icon in &args.icons {
+        rasterize(&args, &input_dir, icon)?;
+    }
+
+    if let Some(download_dir) = download_dir {
+        download_dir
+            .close()
+            .context("removing the download directory")?;
+    }
+
+    Ok(())
+}
+
+fn rasterize(args: &Args, input_dir: &path::Path, icon: &str) -> Result<()> {
+    let svg_path = input_dir.join(icon).with_extension("svg");
+    let source = fs::read_to_string(&svg_path)
+        .with_context(|| format!("reading {}", svg_path.display()))?;
+    // `24/solid/bell` is written as `24-solid-bell`, like its feature.
+    let name = icon.trim_matches('/').replace('/', "-");
+
+    let paint = Paint {
+        color: args.color.clone(),
+        background: args.background.clone(),
+        padding: 0.0,
+    };
+    let pngs = args
+        .sizes
+        .iter()
+        .map(|&size| Ok((size, render_png(&source, size, &paint)?)))
+        .collect::<Result<Vec<_>>>()
+        .with_context(|| format!("rendering {icon}"))?;
+
+    for format in &args.formats {
+        match format {
+            Format::Png => {
+                for (size, png) in &pngs {
+                    write(args, &format!("{name}-{size}.png"), png)?;
+                }
+            }
+            Format::Ico => {
+                let pngs: Vec<_> = pngs
+                    .iter()
+                    .filter(|(size, _)| *size <= ICO_MAX_SIZE)
+                    .cloned()
+                    .collect();
+                if pngs.is_empty() {
+                    eprintln!(
+                        "warning: no size fits in {name}.ico, skipping it"
+                    );
+                    continue;
+                }
+                let ico = encode_ico(&pngs)?;
+                write(args, &format!("{name}.ico"), &ico)?;
+            }
+            Format::AppleTouch => {
+                // iOS fills transparency with black and rounds the corners,
+                // so the icon gets a background and a margin.
+                let paint = Paint {
+                    background: Some(
+                        paint.background.clone().unwrap_or("white".to_string()),
+                    ),
+                    padding: 0.125,
+                    ..paint.clone()
+                };
+                let png = render_png(&source, APPLE_TOUCH_SIZE, &paint)?;
+                write(args, &format!("{name}-apple-touch-icon.png"), &png)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn write(args: &Args, file_name: &str, contents: &[u8]) -> Result<()> {
+    let path = args.output.join(file_name);
+    fs::write(&path, contents)
+        .with_context(|| format!("writing {}", path.display()))?;
+    println!("Wrote {}", path.display());
+    Ok(())
+}
@@ -0,0 +1,44 @@
+//! The layout of ICO files: a header, a directory entry per image and then
+//! the images.
+
+use rasterize_icons::encode_ico;
+
+fn u16_at(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes(bytes[offset..offset + 2].try_into().unwrap())
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+#[test]
+fn lays_out_the_header_directory_and_images() {
+    // The images are stored as they are, so they needn't be real PNGs.
+    let images = [(16, vec![1, 2, 3]), (256, vec![4, 5])];
+    let ico = encode_ico(&images).unwrap();
+
+    // Reserved, icon type and image count.
+    assert_eq!(u16_at(&ico, 0), 0);
+    assert_eq!(u16_at(&ico, 2), 1);
+    assert_eq!(u16_at(&ico, 4), 2);
+
+    let first_image = 6 + 2 * 16;
+    let entries = [(16, 3, first_image), (0, 2, first_image + 3)];
+    for (index, (stored_size, len, offset)) in entries.into_iter().enumerate() {
+        let entry = &ico[6 + 16 * index..6 + 16 * (index + 1)];
+        // Width and height, with 0 for 256, no palette and a reserved byte.
+        assert_eq!(entry[..4], [stored_size, stored_size, 0, 0]);
+        // Color planes and bits per pixel.
+        assert_eq!(u16_at(entry, 4), 1);
+        assert_eq!(u16_at(entry, 6), 32);
+        assert_eq!(u32_at(entry, 8), len);
+        assert_eq!(u32_at(entry, 12), offset as u32);
+    }
+
+    assert_eq!(ico[first_image..], [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn rejects_images_over_256_pixels() {
+    assert!(encode_ico(&[(512, vec![])]).is_err());
+}
@@ -0,0 +1,45 @@
+//! The colors icons are rasterized with.
+
+use rasterize_icons::{render_png, Paint};
+use resvg::tiny_skia::Pixmap;
+
+/// A square in the middle of the icon, leaving its corners empty.
+const SQUARE: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 4 4" fill="currentColor"><path d="M1 1h2v2H1z"/></svg>"#;
+
+/// The RGBA color of a pixel of a `size` by `size` PNG.
+fn pixel(png: &[u8], x: u32, y: u32) -> [u8; 4] {
+    let pixmap = Pixmap::decode_png(png).unwrap();
+    let pixel = pixmap.pixel(x, y).unwrap().demultiply();
+    [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()]
+}
+
+fn paint(color: &str, background: Option<&str>) -> Paint {
+    Paint {
+        color: color.into(),
+        background: background.map(Into::into),
+        padding: 0.0,
+    }
+}
+
+#[test]
+fn paints_the_icon_and_background() {
+    let png =
+        render_png(SQUARE, 16, &paint("#ff0000", Some("#00ff00"))).unwrap();
+    assert_eq!(pixel(&png, 8, 8), [255, 0, 0, 255]);
+    assert_eq!(pixel(&png, 0, 0), [0, 255, 0, 255]);
+
+    let png = render_png(SQUARE, 16, &paint("#ff0000", None)).unwrap();
+    assert_eq!(pixel(&png, 0, 0)[3], 0);
+}
+
+#[test]
+fn colors_cant_add_markup() {
+    // Unescaped, either color would close its attribute and add a blue
+    // square covering the whole image.
+    let injected = r#"red"/><rect width="100%" height="100%" fill="blue"#;
+    for paint in [paint(injected, None), paint("red", Some(injected))] {
+        if let Ok(png) = render_png(SQUARE, 16, &paint) {
+            assert_ne!(pixel(&png, 0, 0), [0, 0, 255, 255], "{paint:?}");
+        }
+    }
+}
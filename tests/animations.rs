@@ -0,0 +1,127 @@
+//! The styles `AnimatedIcon` renders on the server.
+
+use leptos::prelude::*;
+use leptos_heroicons::size_24::outline::ArrowPath;
+use leptos_heroicons::{AnimatedIcon, IconAnimations};
+
+fn render<V: IntoView + 'static>(view: impl FnOnce() -> V) -> String {
+    Owner::new().with(|| view().into_view().to_html())
+}
+
+/// The styles of the outer (transform) and inner (animation) spans, without
+/// the trailing `;` tachys adds.
+fn styles(html: &str) -> (&str, &str) {
+    let mut styles = html.split(r#"style=""#).skip(1).map(|style| {
+        let end = style.find('"').unwrap();
+        style[..end].trim_end_matches(';')
+    });
+    (styles.next().unwrap(), styles.next().unwrap())
+}
+
+#[test]
+fn renders_no_transform_or_animation_by_default() {
+    let html = render(|| {
+        view! { <AnimatedIcon><ArrowPath /></AnimatedIcon> }
+    });
+    assert_eq!(
+        styles(&html),
+        ("display: inline-flex", "display: inline-flex")
+    );
+    assert!(html.contains(r#"class="leptos-heroicons-animated""#));
+}
+
+#[test]
+fn rotates() {
+    let html = render(|| {
+        view! { <AnimatedIcon rotate=90.0><ArrowPath /></AnimatedIcon> }
+    });
+    assert_eq!(
+        styles(&html).0,
+        "display: inline-flex; transform: rotate(90deg)"
+    );
+}
+
+#[test]
+fn flips() {
+    let html = render(|| {
+        view! {
+            <AnimatedIcon flip_horizontal=true><ArrowPath /></AnimatedIcon>
+        }
+    });
+    assert_eq!(
+        styles(&html).0,
+        "display: inline-flex; transform: scaleX(-1)"
+    );
+
+    let html = render(|| {
+        view! {
+            <AnimatedIcon flip_vertical=true><ArrowPath /></AnimatedIcon>
+        }
+    });
+    assert_eq!(
+        styles(&html).0,
+        "display: inline-flex; transform: scaleY(-1)"
+    );
+
+    let html = render(|| {
+        view! {
+            <AnimatedIcon rotate=-45.0 flip_horizontal=true flip_vertical=true>
+                <ArrowPath />
+            </AnimatedIcon>
+        }
+    });
+    assert_eq!(
+        styles(&html).0,
+        "display: inline-flex; transform: rotate(-45deg) scaleX(-1) scaleY(-1)"
+    );
+}
+
+#[test]
+fn combines_animations() {
+    let html = render(|| {
+        view! { <AnimatedIcon spin=true><ArrowPath /></AnimatedIcon> }
+    });
+    assert_eq!(
+        styles(&html).1,
+        "display: inline-flex; animation: leptos-heroicons-spin 1s linear infinite"
+    );
+
+    let html = render(|| {
+        view! {
+            <AnimatedIcon spin=true pulse=true bounce=true rotate=180.0>
+                <ArrowPath />
+            </AnimatedIcon>
+        }
+    });
+    assert_eq!(
+        styles(&html),
+        (
+            "display: inline-flex; transform: rotate(180deg)",
+            "display: inline-flex; animation: \
+             leptos-heroicons-spin 1s linear infinite, \
+             leptos-heroicons-pulse 2s ease-in-out infinite, \
+             leptos-heroicons-bounce 1s infinite"
+        )
+    );
+}
+
+#[test]
+fn renders_the_keyframes_once_inside_icon_animations() {
+    let icons = || {
+        (0..3)
+            .map(|_| {
+                view! { <AnimatedIcon spin=true><ArrowPath /></AnimatedIcon> }
+            })
+            .collect_view()
+    };
+
+    let html = render(icons);
+    assert_eq!(html.matches("<style>").count(), 3);
+
+    let html =
+        render(move || view! { <IconAnimations>{icons()}</IconAnimations> });
+    assert_eq!(html.matches("<style>").count(), 1);
+    assert_eq!(html.matches("@keyframes leptos-heroicons-spin").count(), 1);
+    let animated = r#"class="leptos-heroicons-animated""#;
+    assert_eq!(html.matches(animated).count(), 3);
+}
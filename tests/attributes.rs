@@ -0,0 +1,196 @@
+//! The props and attributes components forward to their `<svg>`.
+
+use leptos::prelude::*;
+use leptos_heroicons::size_24;
+use leptos_heroicons::size_24::solid::{ArrowLeft, Bell};
+use leptos_heroicons::{
+    DuotoneIconProps, IconData, IconDefaults, IconMode, IconPath, IconProvider,
+    RightToLeft,
+};
+
+/// The opening `<svg>` tag of a rendered icon.
+fn svg_tag(view: impl IntoView) -> String {
+    let html = view.to_html();
+    html[..=html.find('>').unwrap()].to_string()
+}
+
+#[test]
+fn renders_without_extra_attributes_by_default() {
+    Owner::new().with(|| {
+        let tag = svg_tag(view! { <Bell class="size-6" /> });
+        assert!(tag.contains(r#"class="size-6""#));
+        assert!(!tag.contains("style="));
+        assert!(!tag.contains("id="));
+    });
+}
+
+#[test]
+fn forwards_style() {
+    Owner::new().with(|| {
+        let tag = svg_tag(view! { <Bell style="width: 2em" /> });
+        assert!(tag.contains(r#"style="width: 2em;""#), "{tag}");
+    });
+}
+
+#[test]
+fn forwards_reactive_style() {
+    Owner::new().with(|| {
+        let (style, set_style) = signal("color: red".to_string());
+        let icon = move || view! { <Bell style=style /> };
+        assert!(svg_tag(icon()).contains(r#"style="color: red;""#));
+
+        set_style.set("color: blue".to_string());
+        assert!(svg_tag(icon()).contains(r#"style="color: blue;""#));
+    });
+}
+
+#[test]
+fn forwards_spread_attributes() {
+    Owner::new().with(|| {
+        let tag = svg_tag(view! {
+            <Bell {..} id="notifications" data-count="3" aria-label="Bell" />
+        });
+        assert!(tag.contains(r#"id="notifications""#), "{tag}");
+        assert!(tag.contains(r#"data-count="3""#), "{tag}");
+        assert!(tag.contains(r#"aria-label="Bell""#), "{tag}");
+    });
+}
+
+/// A two-shape icon the way the `duotone` rule paints it.
+static DUOTONE: IconData = IconData {
+    view_box: "0 0 24 24",
+    mode: IconMode::Stroke,
+    fill: Some("none"),
+    stroke: Some("var(--hi-primary, currentColor)"),
+    stroke_width: Some("1.5"),
+    paths: &[
+        IconPath {
+            d: "M3 3h18v18H3z",
+            ..IconPath::EMPTY
+        },
+        IconPath {
+            d: "M9 9h6v6H9z",
+            stroke: Some("var(--hi-secondary, currentColor)"),
+            ..IconPath::EMPTY
+        },
+    ],
+    ..IconData::EMPTY
+};
+
+/// A component of [`DUOTONE`], the way the generator writes them.
+#[allow(non_snake_case)]
+fn Duotone(props: DuotoneIconProps) -> impl IntoView {
+    leptos_heroicons::render_duotone_icon(&DUOTONE, props)
+}
+
+#[test]
+fn sets_duotone_colors_with_the_style() {
+    Owner::new().with(|| {
+        let html = view! {
+            <Duotone style="width: 2em" primary="red" secondary="blue" />
+        }
+        .to_html();
+        let tag = &html[..=html.find('>').unwrap()];
+        assert!(
+            tag.contains(
+                r#"style="width: 2em; --hi-primary: red; --hi-secondary: blue;""#
+            ),
+            "{tag}"
+        );
+        assert!(
+            tag.contains(r#"stroke="var(--hi-primary, currentColor)""#),
+            "{tag}"
+        );
+        assert!(
+            html.contains(r#"stroke="var(--hi-secondary, currentColor)""#),
+            "{html}"
+        );
+
+        let tag = svg_tag(view! { <Duotone primary="red" /> });
+        assert!(tag.contains(r#"style="--hi-primary: red;""#), "{tag}");
+        assert!(!svg_tag(view! { <Duotone /> }).contains("style="));
+    });
+}
+
+#[test]
+fn mirrors_directional_icons_in_right_to_left_layouts() {
+    Owner::new().with(|| {
+        let mirrored = r#"style="transform: scaleX(-1);""#;
+        assert!(svg_tag(view! { <ArrowLeft rtl=true /> }).contains(mirrored));
+        assert!(!svg_tag(view! { <ArrowLeft /> }).contains("style="));
+        assert!(!svg_tag(view! { <Bell rtl=true /> }).contains("style="));
+
+        provide_context(RightToLeft(Signal::stored(true)));
+        assert!(svg_tag(view! { <ArrowLeft /> }).contains(mirrored));
+        assert!(!svg_tag(view! { <ArrowLeft rtl=false /> }).contains("style="));
+    });
+}
+
+#[test]
+fn applies_provided_defaults_under_own_props() {
+    Owner::new().with(|| {
+        let defaults = IconDefaults {
+            class: "w-5 h-5 shrink-0".into(),
+            stroke_width: Some("2".into()),
+            ..Default::default()
+        };
+        let tag = svg_tag(view! {
+            <IconProvider defaults=defaults.clone()>
+                <size_24::outline::Bell />
+            </IconProvider>
+        });
+        assert!(tag.contains(r#"class="w-5 h-5 shrink-0""#), "{tag}");
+        assert!(tag.contains(r#"stroke-width="2""#), "{tag}");
+
+        let tag = svg_tag(view! {
+            <IconProvider defaults=defaults>
+                <size_24::outline::Bell class="size-6" stroke_width="1" />
+            </IconProvider>
+        });
+        assert!(tag.contains(r#"class="size-6""#), "{tag}");
+        assert!(tag.contains(r#"stroke-width="1""#), "{tag}");
+
+        let tag = svg_tag(view! { <size_24::outline::Bell /> });
+        assert!(tag.contains(r#"stroke-width="1.5""#), "{tag}");
+    });
+}
+
+#[test]
+fn accepts_event_listeners() {
+    Owner::new().with(|| {
+        // Listeners only exist in the browser, so the HTML is unchanged.
+        let tag = svg_tag(view! { <Bell on:click=|_| () {..} id="bell" /> });
+        assert!(tag.contains(r#"id="bell""#), "{tag}");
+        assert!(!tag.contains("click"), "{tag}");
+    });
+}
+
+#[test]
+fn accepts_node_ref() {
+    Owner::new().with(|| {
+        let node_ref = NodeRef::new();
+        let tag = svg_tag(view! { <Bell node_ref=node_ref /> });
+        assert!(tag.starts_with("<svg"));
+        // It's only filled when the `<svg>` is mounted in the browser.
+        assert!(node_ref.get_untracked().is_none());
+    });
+}
+
+#[test]
+fn dual_components_forward_props_and_attributes() {
+    Owner::new().with(|| {
+        let node_ref = NodeRef::new();
+        let tag = svg_tag(view! {
+            <size_24::Bell
+                solid=true
+                node_ref=node_ref
+                style="opacity: 0.5"
+                {..}
+                id="dual"
+            />
+        });
+        assert!(tag.contains(r#"fill="currentColor""#), "{tag}");
+        assert!(tag.contains(r#"style="opacity: 0.5;""#), "{tag}");
+        assert!(tag.contains(r#"id="dual""#), "{tag}");
+    });
+}
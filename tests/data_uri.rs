@@ -0,0 +1,92 @@
+//! The data URIs of icons, against encodings made independently of the
+//! crate.
+
+use leptos_heroicons::{IconData, IconPath};
+
+/// A square, short enough to compare its URIs in full.
+static SQUARE: IconData = IconData {
+    view_box: "0 0 1 1",
+    fill: Some("currentColor"),
+    paths: &[IconPath {
+        d: "M0 0h1v1H0Z",
+        ..IconPath::EMPTY
+    }],
+    ..IconData::EMPTY
+};
+
+const BASE64_PREFIX: &str = "data:image/svg+xml;base64,";
+
+#[test]
+fn paints_the_svg() {
+    assert_eq!(
+        SQUARE.to_svg("#000"),
+        r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1 1" fill="#000"><path d="M0 0h1v1H0Z"/></svg>"##
+    );
+}
+
+#[test]
+fn percent_encodes_the_data_uri() {
+    assert_eq!(
+        SQUARE.data_uri("#000"),
+        "data:image/svg+xml,%3Csvg%20xmlns=%22http://www.w3.org/2000/svg%22\
+         %20viewBox=%220%200%201%201%22%20fill=%22%23000%22%3E%3Cpath%20d=\
+         %22M0%200h1v1H0Z%22/%3E%3C/svg%3E"
+    );
+}
+
+#[test]
+fn wraps_the_data_uri_in_a_css_url() {
+    assert_eq!(
+        SQUARE.css_url("#000"),
+        format!("url(\"{}\")", SQUARE.data_uri("#000"))
+    );
+}
+
+#[test]
+fn base64_encodes_without_padding() {
+    // The SVG is a multiple of 3 bytes long.
+    assert_eq!(
+        SQUARE.data_uri_base64("#000"),
+        format!(
+            "{BASE64_PREFIX}PHN2ZyB4bWxucz0iaHR0cDovL3d3dy53My5vcmcvMjAwMC9zd\
+             mciIHZpZXdCb3g9IjAgMCAxIDEiIGZpbGw9IiMwMDAiPjxwYXRoIGQ9Ik0wIDBoM\
+             XYxSDBaIi8+PC9zdmc+"
+        )
+    );
+}
+
+#[test]
+fn base64_pads_one_remaining_byte() {
+    assert_eq!(
+        SQUARE.data_uri_base64("#0000"),
+        format!(
+            "{BASE64_PREFIX}PHN2ZyB4bWxucz0iaHR0cDovL3d3dy53My5vcmcvMjAwMC9zd\
+             mciIHZpZXdCb3g9IjAgMCAxIDEiIGZpbGw9IiMwMDAwIj48cGF0aCBkPSJNMCAwa\
+             DF2MUgwWiIvPjwvc3ZnPg=="
+        )
+    );
+}
+
+#[test]
+fn base64_pads_two_remaining_bytes() {
+    assert_eq!(
+        SQUARE.data_uri_base64("red"),
+        format!(
+            "{BASE64_PREFIX}PHN2ZyB4bWxucz0iaHR0cDovL3d3dy53My5vcmcvMjAwMC9zd\
+             mciIHZpZXdCb3g9IjAgMCAxIDEiIGZpbGw9InJlZCI+PHBhdGggZD0iTTAgMGgxd\
+             jFIMFoiLz48L3N2Zz4="
+        )
+    );
+}
+
+#[test]
+fn escapes_extra_attributes() {
+    static LABELLED: IconData = IconData {
+        attributes: &[("focusable", "false"), ("aria-label", "<\"Q&A\">")],
+        ..SQUARE
+    };
+    assert_eq!(
+        LABELLED.to_svg("#000"),
+        r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1 1" fill="#000" focusable="false" aria-label="&lt;&quot;Q&amp;A&quot;&gt;"><path d="M0 0h1v1H0Z"/></svg>"##
+    );
+}
@@ -0,0 +1,164 @@
+//! The features `Cargo.toml` declares and the ones `src/lib.rs` is gated by,
+//! which the generator writes separately.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use syn::visit::Visit;
+
+/// The features declared in `Cargo.toml`, besides `default`, with the ones
+/// they enable.
+fn declared_features() -> BTreeMap<String, Vec<String>> {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    let manifest: toml::Table =
+        toml::from_str(&fs::read_to_string(manifest).unwrap()).unwrap();
+    manifest["features"]
+        .as_table()
+        .unwrap()
+        .iter()
+        .filter(|(feature, _)| *feature != "default")
+        .map(|(feature, enables)| {
+            let enables = enables.as_array().unwrap().iter();
+            let enables = enables.map(|enable| enable.as_str().unwrap().into());
+            (feature.clone(), enables.collect())
+        })
+        .collect()
+}
+
+/// The features named by the `#[cfg(...)]` attributes of `src/lib.rs`.
+fn cfg_features() -> BTreeSet<String> {
+    let lib = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("lib.rs");
+    let lib = syn::parse_file(&fs::read_to_string(lib).unwrap()).unwrap();
+    let mut visitor = CfgFeatures::default();
+    visitor.visit_file(&lib);
+    visitor.0
+}
+
+#[derive(Default)]
+struct CfgFeatures(BTreeSet<String>);
+
+impl CfgFeatures {
+    fn visit_predicate(
+        &mut self,
+        meta: syn::meta::ParseNestedMeta,
+    ) -> syn::Result<()> {
+        if meta.path.is_ident("feature") {
+            let feature: syn::LitStr = meta.value()?.parse()?;
+            self.0.insert(feature.value());
+        } else if meta.input.peek(syn::token::Paren) {
+            // `any(...)`, `all(...)` or `not(...)`.
+            meta.parse_nested_meta(|meta| self.visit_predicate(meta))?;
+        } else if meta.input.peek(syn::Token![=]) {
+            // Other options, e.g. `target_os = "linux"`.
+            meta.value()?.parse::<syn::LitStr>()?;
+        }
+        Ok(())
+    }
+}
+
+impl<'ast> Visit<'ast> for CfgFeatures {
+    fn visit_attribute(&mut self, attr: &'ast syn::Attribute) {
+        if attr.path().is_ident("cfg") {
+            attr.parse_nested_meta(|meta| self.visit_predicate(meta))
+                .unwrap();
+        }
+    }
+}
+
+/// Whether a feature is a category bundle, such as `24-outline-arrows`, which
+/// only enables single-icon features of its own group, e.g.
+/// `24-outline-arrow-up`.
+fn is_bundle(
+    feature: &str,
+    enables: &[String],
+    declared: &BTreeMap<String, Vec<String>>,
+    used: &BTreeSet<String>,
+) -> bool {
+    let in_group = |group: &str| {
+        let prefix = format!("{group}-");
+        enables.iter().all(|icon| {
+            icon.starts_with(&prefix)
+                && declared.get(icon).is_some_and(Vec::is_empty)
+                && used.contains(icon)
+        })
+    };
+    !enables.is_empty()
+        && declared.keys().any(|group| {
+            feature.starts_with(&format!("{group}-")) && in_group(group)
+        })
+}
+
+#[test]
+fn every_feature_gates_code_and_every_cfg_is_declared() {
+    let declared = declared_features();
+    let used = cfg_features();
+
+    let unused: Vec<_> = declared
+        .iter()
+        .filter(|(feature, enables)| {
+            !used.contains(*feature)
+                && !is_bundle(feature, enables, &declared, &used)
+        })
+        .map(|(feature, _)| feature)
+        .collect();
+    let undeclared: Vec<_> = used
+        .iter()
+        .filter(|feature| !declared.contains_key(*feature))
+        .collect();
+    assert!(unused.is_empty(), "features gating nothing: {unused:?}");
+    assert!(undeclared.is_empty(), "undeclared features: {undeclared:?}");
+}
+
+/// Single-icon features to build the crate with: the first and the last icon
+/// of every group, such as `24-outline`, an icon switching styles and a
+/// category.
+fn sampled_features(declared: &BTreeSet<String>) -> Vec<Vec<String>> {
+    let children = |group: &str| -> Vec<&String> {
+        let prefix = format!("{group}-");
+        declared
+            .iter()
+            .filter(|feature| feature.starts_with(&prefix))
+            .collect()
+    };
+    // Icons can prefix others too, e.g. `arrow-down` and `arrow-down-left`.
+    let groups: Vec<_> = declared
+        .iter()
+        .filter(|feature| !children(feature).is_empty())
+        .collect();
+    let mut samples = vec![vec![]];
+    for group in &groups {
+        if groups
+            .iter()
+            .any(|outer| group.starts_with(&format!("{outer}-")))
+        {
+            continue;
+        }
+        let icons = children(group);
+        samples.push(vec![icons[0].clone()]);
+        samples.push(vec![icons[icons.len() - 1].clone()]);
+    }
+    samples.push(vec!["24-outline-bell".into(), "24-solid-bell".into()]);
+    samples.push(vec!["24-outline-chevrons".into()]);
+    samples
+}
+
+#[test]
+fn builds_with_sampled_single_icon_features() {
+    let declared = declared_features();
+    for features in sampled_features(&declared.into_keys().collect()) {
+        let status = Command::new(env!("CARGO"))
+            .args(["check", "--lib", "--quiet", "--no-default-features"])
+            .args(["--features", &features.join(",")])
+            .arg("--manifest-path")
+            .arg(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"))
+            .arg("--target-dir")
+            .arg(Path::new(env!("CARGO_TARGET_TMPDIR")).join("features"))
+            .status()
+            .unwrap();
+        assert!(status.success(), "failed to build with {features:?}");
+    }
+}
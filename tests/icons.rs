@@ -0,0 +1,96 @@
+//! The `ICONS` table and its lookups, with the `24-outline` and `24-solid`
+//! icons the tests enable.
+
+use std::collections::BTreeSet;
+
+use leptos_heroicons::{
+    find_icon, icons_by_size, icons_by_style, size_24, ICONS,
+};
+
+/// The number of icons of each group of heroicons.
+const OUTLINE_24: usize = 324;
+const SOLID_24: usize = 324;
+const SOLID_20: usize = 324;
+const SOLID_16: usize = 316;
+
+/// The number of icons of a group enabled by `enabled`.
+fn count(enabled: bool, icons: usize) -> usize {
+    match enabled {
+        true => icons,
+        false => 0,
+    }
+}
+
+// `cargo test --workspace` unifies the features of every member, and the
+// example enables `20-solid` and `16-solid` too, so those groups are only
+// expected to be missing when they're disabled, as with
+// `cargo test -p leptos_heroicons`.
+const SOLID_20_ENABLED: bool = cfg!(feature = "20-solid");
+const SOLID_16_ENABLED: bool = cfg!(feature = "16-solid");
+
+#[test]
+fn lists_every_enabled_icon_once() {
+    let total = OUTLINE_24
+        + SOLID_24
+        + count(SOLID_20_ENABLED, SOLID_20)
+        + count(SOLID_16_ENABLED, SOLID_16);
+    assert_eq!(ICONS.len(), total);
+
+    let features: BTreeSet<_> = ICONS.iter().map(|icon| icon.feature).collect();
+    assert_eq!(features.len(), ICONS.len());
+}
+
+#[test]
+fn groups_icons_by_size_and_style() {
+    assert_eq!(icons_by_size(24).count(), OUTLINE_24 + SOLID_24);
+    assert_eq!(icons_by_size(20).count(), count(SOLID_20_ENABLED, SOLID_20));
+    assert_eq!(icons_by_size(16).count(), count(SOLID_16_ENABLED, SOLID_16));
+    assert_eq!(icons_by_size(32).count(), 0);
+
+    assert_eq!(icons_by_style("outline").count(), OUTLINE_24);
+    assert_eq!(
+        icons_by_style("solid").count(),
+        SOLID_24
+            + count(SOLID_20_ENABLED, SOLID_20)
+            + count(SOLID_16_ENABLED, SOLID_16)
+    );
+    assert_eq!(icons_by_style("mini").count(), 0);
+
+    for icon in icons_by_size(24) {
+        let style = icon.style.unwrap();
+        assert_eq!(icon.module, format!("size_24::{style}"));
+        assert_eq!(icon.view_box, "0 0 24 24");
+        assert!(icon.feature.starts_with(&format!("24-{style}-")));
+    }
+}
+
+#[test]
+fn has_no_disabled_groups() {
+    if !SOLID_20_ENABLED {
+        assert!(ICONS.iter().all(|icon| icon.size != Some(20)));
+        assert_eq!(find_icon("20-solid-trash"), None);
+    }
+    if !SOLID_16_ENABLED {
+        assert!(ICONS.iter().all(|icon| icon.size != Some(16)));
+        assert_eq!(find_icon("16-solid-trash"), None);
+    }
+}
+
+#[test]
+fn finds_icons_by_feature() {
+    let trash = find_icon("24-solid-trash").unwrap();
+    assert_eq!(trash.name, "trash");
+    assert_eq!(trash.module, "size_24::solid");
+    assert_eq!(trash.component, "Trash");
+    assert_eq!(trash.size, Some(24));
+    assert_eq!(trash.style, Some("solid"));
+    assert_eq!(trash.view_box, "0 0 24 24");
+    assert!(std::ptr::eq(trash.data, &size_24::solid::TRASH));
+
+    let outline = find_icon("24-outline-trash").unwrap();
+    assert!(std::ptr::eq(outline.data, &size_24::outline::TRASH));
+
+    assert_eq!(find_icon("24-solid"), None);
+    assert_eq!(find_icon("24-solid-no-such-icon"), None);
+    assert_eq!(find_icon("trash"), None);
+}
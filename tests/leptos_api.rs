@@ -0,0 +1,69 @@
+//! The parts of Leptos the shared props and node refs rely on beyond its
+//! documented API: the builders `#[slot]` generates, implementing
+//! `leptos::component::Props` by hand, and tachys' `NodeRefContainer`. These
+//! tests fail to build or pass if a Leptos update changes them.
+
+use leptos::component::Props;
+use leptos::prelude::*;
+use leptos::tachys::html::node_ref::NodeRefContainer;
+use leptos_heroicons::size_24;
+use leptos_heroicons::size_24::solid::Bell;
+use leptos_heroicons::{
+    DualDuotoneIconProps, DualIconProps, DuotoneIconProps, IconProps,
+    IconPropsBuilder,
+};
+
+/// The props builder `view!` uses for a component taking `P`.
+fn view_builder<P: Props>() -> P::Builder {
+    P::builder()
+}
+
+#[test]
+fn view_builds_the_props_slot_generates() {
+    let builder: IconPropsBuilder = view_builder::<IconProps>();
+    let props = builder.class("size-6").stroke_width("2").build();
+    let html = Owner::new().with(|| Bell(props).to_html());
+    assert!(html.contains(r#"class="size-6""#), "{html}");
+
+    // Every prop is optional, as `<Bell />` builds the props without any.
+    let html = Owner::new()
+        .with(|| Bell(view_builder::<IconProps>().build()).to_html());
+    assert!(html.starts_with("<svg"), "{html}");
+
+    // The duotone props are built the same way.
+    view_builder::<DuotoneIconProps>().primary("red").build();
+    view_builder::<DualDuotoneIconProps>().solid(true).build();
+
+    let props = view_builder::<DualIconProps>().solid(true).build();
+    let html = Owner::new().with(|| size_24::Bell(props).to_html());
+    let solid = Owner::new().with(|| view! { <Bell /> }.to_html());
+    assert_eq!(html, solid);
+}
+
+/// Mirrors the crate's own container, which forwards to an optional
+/// `NodeRef`.
+#[derive(Clone, Copy)]
+struct OptionalRef(Option<NodeRef<leptos::svg::Svg>>);
+
+impl NodeRefContainer<leptos::svg::Svg> for OptionalRef {
+    fn load(self, el: &leptos::tachys::renderer::types::Element) {
+        if let Some(node_ref) = self.0 {
+            node_ref.load(el);
+        }
+    }
+}
+
+#[test]
+fn node_ref_containers_load_renderer_elements() {
+    let node_ref = NodeRef::<leptos::svg::Svg>::new();
+    Owner::new().with(|| {
+        let html = view! {
+            <svg node_ref=OptionalRef(Some(node_ref))></svg>
+            <Bell node_ref=node_ref />
+        }
+        .to_html();
+        assert!(html.contains("<svg"), "{html}");
+    });
+    // Only browsers fill node refs.
+    assert!(node_ref.get_untracked().is_none());
+}
@@ -0,0 +1,50 @@
+//! The `IconPicker` as rendered on the server.
+
+use leptos::prelude::*;
+use leptos_heroicons::IconPicker;
+
+/// The opening tags of the picker's options.
+fn options(html: &str) -> Vec<&str> {
+    html.split("<button")
+        .skip(1)
+        .map(|option| &option[..option.find('>').unwrap()])
+        .collect()
+}
+
+fn render(value: Option<&str>) -> String {
+    let value = value.map(str::to_string);
+    Owner::new().with(|| {
+        view! { <IconPicker value=Signal::stored(value) on_change=|_| () /> }
+            .to_html()
+    })
+}
+
+#[test]
+fn only_the_first_icon_is_tabbable() {
+    let html = render(None);
+    let options = options(&html);
+    assert_eq!(options.len(), leptos_heroicons::ICONS.len());
+    assert!(options[0].contains(r#"tabindex="0""#), "{}", options[0]);
+    for option in &options[1..] {
+        assert!(option.contains(r#"tabindex="-1""#), "{option}");
+    }
+}
+
+#[test]
+fn selects_the_value() {
+    let html = render(Some("24-solid-trash"));
+    let selected: Vec<_> = options(&html)
+        .into_iter()
+        .filter(|option| option.contains(r#"aria-selected="true""#))
+        .collect();
+    assert_eq!(selected.len(), 1, "{selected:?}");
+    assert!(selected[0].contains(r#"title="trash""#), "{}", selected[0]);
+    assert!(selected[0].contains("data-index"), "{}", selected[0]);
+
+    let html = render(None);
+    assert!(!html.contains(r#"aria-selected="true""#));
+    assert_eq!(
+        options(&html).len(),
+        html.matches(r#"aria-selected="false""#).count()
+    );
+}
@@ -0,0 +1,64 @@
+//! The HTML `render::to_html` renders outside of a Leptos app.
+
+use leptos_heroicons::render::{self, Options};
+use leptos_heroicons::{IconData, IconPath};
+
+/// A square, short enough to compare its HTML in full.
+static SQUARE: IconData = IconData {
+    view_box: "0 0 1 1",
+    fill: Some("currentColor"),
+    title: Some("Square"),
+    paths: &[IconPath {
+        d: "M0 0h1v1H0Z",
+        ..IconPath::EMPTY
+    }],
+    ..IconData::EMPTY
+};
+
+#[test]
+fn renders_the_options() {
+    let html = render::to_html(
+        &SQUARE,
+        Options {
+            class: "size-6".into(),
+            size: Some("24".into()),
+            color: Some("#6b7280".into()),
+            title: Some("Notifications".into()),
+            ..Options::default()
+        },
+    );
+    assert_eq!(
+        html,
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" fill="currentColor" viewBox="0 0 1 1" class="size-6" style="color:#6b7280;"><title>Notifications</title><path d="M0 0h1v1H0Z"></path></svg>"##
+    );
+}
+
+#[test]
+fn renders_the_icon_like_its_component_by_default() {
+    let html = render::to_html(&SQUARE, Options::default());
+    assert_eq!(
+        html,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" fill="currentColor" viewBox="0 0 1 1" class=""><title>Square</title><path d="M0 0h1v1H0Z"></path></svg>"#
+    );
+}
+
+#[test]
+fn leaves_out_hydration_markers() {
+    let html = render::Icon::new(&SQUARE, "size-5").to_string();
+    assert!(!html.contains("<!"), "{html}");
+}
+
+#[test]
+fn renders_the_markup_of_icons_that_dont_fit_paths() {
+    static ROTATED: IconData = IconData {
+        view_box: "0 0 1 1",
+        fill: Some("currentColor"),
+        markup: Some(r#"<rect width="1" height="1" transform="rotate(45)"/>"#),
+        ..IconData::EMPTY
+    };
+    let html = render::to_html(&ROTATED, Options::default());
+    assert_eq!(
+        html,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" fill="currentColor" viewBox="0 0 1 1" class=""><g><rect width="1" height="1" transform="rotate(45)"/></g></svg>"#
+    );
+}
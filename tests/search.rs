@@ -0,0 +1,60 @@
+//! `search` over the icons the tests enable.
+
+use leptos_heroicons::{search, IconMeta, ICONS};
+
+fn names(icons: &[&IconMeta]) -> Vec<&'static str> {
+    icons.iter().map(|icon| icon.name).collect()
+}
+
+fn position(icons: &[&IconMeta], name: &str) -> usize {
+    icons
+        .iter()
+        .position(|icon| icon.name == name)
+        .unwrap_or_else(|| panic!("{name} is missing from {:?}", names(icons)))
+}
+
+#[test]
+fn ranks_whole_names_before_tags() {
+    let icons = search("trash");
+    assert_eq!(icons[0].name, "trash");
+    assert!(position(&icons, "trash") < position(&icons, "archive-box-x-mark"));
+}
+
+#[test]
+fn matches_tags() {
+    let icons = search("garbage");
+    assert!(!icons.is_empty());
+    assert!(icons.iter().all(|icon| icon.name == "trash"), "{icons:?}");
+}
+
+#[test]
+fn requires_every_word() {
+    let icons = search("arrow down");
+    assert!(!icons.is_empty());
+    for icon in &icons {
+        for word in ["arrow", "down"] {
+            let matches = icon.name.contains(word)
+                || icon.tags.iter().any(|tag| tag.contains(word));
+            assert!(matches, "{} doesn't match {word}", icon.name);
+        }
+    }
+    assert!(!names(&icons).contains(&"arrow-up"));
+    assert!(search("trash bell").is_empty());
+}
+
+#[test]
+fn ignores_case() {
+    assert_eq!(search("TRASH"), search("trash"));
+    assert_eq!(search("Arrow Down"), search("arrow down"));
+    assert!(ICONS
+        .iter()
+        .flat_map(|icon| icon.tags)
+        .all(|tag| *tag == tag.to_lowercase()));
+}
+
+#[test]
+fn an_empty_query_returns_every_icon() {
+    let every: Vec<_> = ICONS.iter().collect();
+    assert_eq!(search(""), every);
+    assert_eq!(search("  "), every);
+}
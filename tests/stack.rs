@@ -0,0 +1,111 @@
+//! The geometry of `IconStack` overlays and `IconBadge` badges as rendered on
+//! the server.
+
+use leptos::prelude::*;
+use leptos_heroicons::size_24::outline::Bell;
+use leptos_heroicons::size_24::solid::XMark;
+use leptos_heroicons::{Corner, IconBadge, IconStack};
+
+fn render<V: IntoView + 'static>(view: impl FnOnce() -> V) -> String {
+    Owner::new().with(|| view().into_view().to_html())
+}
+
+/// The opening tag of the first `name` element after the wrapped icon.
+fn tag<'a>(html: &'a str, name: &str) -> &'a str {
+    let icon = html.find("</svg>").unwrap();
+    let start = icon + html[icon..].find(&format!("<{name} ")).unwrap();
+    &html[start..start + html[start..].find('>').unwrap()]
+}
+
+#[test]
+fn draws_the_overlay_in_the_top_right_by_default() {
+    let html = render(|| {
+        view! {
+            <IconStack class="size-6" overlay=|| view! { <XMark /> }>
+                <Bell />
+            </IconStack>
+        }
+    });
+    assert!(html.starts_with(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 100 100" class="size-6">"#
+    ));
+    assert_eq!(
+        tag(&html, "svg"),
+        r#"<svg x="50" y="0" width="50" height="50""#
+    );
+    assert!(html.contains(r#"<svg x="50" y="0" width="50" height="50"><svg"#));
+}
+
+#[test]
+fn draws_the_overlay_in_its_corner_and_scale() {
+    let html = render(|| {
+        view! {
+            <IconStack
+                corner=Corner::BottomLeft
+                scale=0.25
+                overlay=|| view! { <XMark /> }
+            >
+                <Bell />
+            </IconStack>
+        }
+    });
+    assert_eq!(
+        tag(&html, "svg"),
+        r#"<svg x="0" y="75" width="25" height="25""#
+    );
+}
+
+#[test]
+fn draws_a_round_badge_for_short_text() {
+    let html = render(|| {
+        view! { <IconBadge text="3"><Bell /></IconBadge> }
+    });
+    assert_eq!(
+        tag(&html, "rect"),
+        r##"<rect x="50" y="0" width="50" height="50" rx="25" fill="#ef4444" class="icon-badge""##
+    );
+    let text = tag(&html, "text");
+    assert!(
+        text.contains(r#" x="75" y="25" font-size="32.5""#),
+        "{text}"
+    );
+    assert!(html.contains(">3</text>"), "{html}");
+}
+
+#[test]
+fn widens_the_badge_for_longer_text_within_the_icon() {
+    let width = |text: &'static str| {
+        let html = render(move || {
+            view! {
+                <IconBadge text=text corner=Corner::BottomRight scale=0.4>
+                    <Bell />
+                </IconBadge>
+            }
+        });
+        let rect = tag(&html, "rect").to_string();
+        let attribute = |name: &str| -> f64 {
+            let start =
+                rect.find(&format!(" {name}=\"")).unwrap() + name.len() + 3;
+            let end = start + rect[start..].find('"').unwrap();
+            rect[start..end].parse().unwrap()
+        };
+        assert_eq!(attribute("height"), 40.0);
+        assert_eq!(attribute("y"), 60.0);
+        // The badge ends at the right edge whatever its width.
+        assert_eq!(attribute("x") + attribute("width"), 100.0);
+        attribute("width")
+    };
+    assert_eq!(width("1"), 40.0);
+    assert!(width("1") < width("12"));
+    assert!(width("12") < width("123"));
+}
+
+#[test]
+fn draws_no_badge_without_text() {
+    let html = render(|| {
+        view! { <IconBadge text=""><Bell /></IconBadge> }
+    });
+    assert!(!html.contains("<rect"), "{html}");
+    assert!(!html.contains("<text"), "{html}");
+    assert!(html.contains("<path"), "{html}");
+}
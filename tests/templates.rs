@@ -0,0 +1,66 @@
+//! Icons rendered in Maud and Askama templates.
+
+use askama::Template;
+use leptos_heroicons::render::{self, Options};
+use leptos_heroicons::size_24::outline::BELL;
+use maud::html;
+
+mod filters {
+    pub use leptos_heroicons::askama_filters::*;
+}
+
+#[test]
+fn renders_icons_in_maud() {
+    let markup = html! {
+        button { (render::Icon::new(&BELL, "size-5")) "Notifications" }
+    };
+    let icon = render::to_html(
+        &BELL,
+        Options {
+            class: "size-5".into(),
+            ..Options::default()
+        },
+    );
+    assert_eq!(
+        markup.into_string(),
+        format!("<button>{icon}Notifications</button>")
+    );
+}
+
+#[test]
+fn renders_icon_data_in_maud() {
+    let markup = html! { (BELL) };
+    assert_eq!(
+        markup.into_string(),
+        render::to_html(&BELL, Options::default())
+    );
+}
+
+#[derive(Template)]
+#[template(
+    source = r#"<a>{{ "bell"|heroicon("24-outline", "size-5") }}</a>"#,
+    ext = "html"
+)]
+struct Bell;
+
+#[test]
+fn renders_icons_with_the_askama_filter() {
+    let icon = render::Icon::new(&BELL, "size-5");
+    assert_eq!(Bell.render().unwrap(), format!("<a>{icon}</a>"));
+}
+
+#[derive(Template)]
+#[template(
+    source = r#"{{ "no-such-icon"|heroicon("24-outline", "size-5") }}"#,
+    ext = "html"
+)]
+struct Missing;
+
+#[test]
+fn fails_to_render_icons_that_are_not_enabled() {
+    let error = Missing.render().unwrap_err();
+    assert!(
+        error.to_string().contains("24-outline-no-such-icon"),
+        "{error}"
+    );
+}
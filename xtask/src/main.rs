@@ -0,0 +1,174 @@
+//! Maintenance tasks for the workspace, run with `cargo xtask <task>`.
+
+use std::fs;
+use std::path;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+use generate_components::download_heroicons::*;
+use generate_components::*;
+
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(subcommand)]
+    task: Task,
+}
+
+#[derive(Debug, Subcommand)]
+enum Task {
+    /// Regenerates the libraries and then the example's gallery.
+    Regen(LibArgs),
+    /// Regenerates `leptos_heroicons` and the other frameworks' crates,
+    /// including their features.
+    Lib(LibArgs),
+    /// Regenerates the example's gallery from `src/lib.rs`.
+    Example,
+}
+
+#[derive(Debug, clap::Args)]
+struct LibArgs {
+    /// Directory of heroicons' optimized SVGs, instead of downloading the
+    /// latest ones.
+    #[arg(long)]
+    input: Option<path::PathBuf>,
+}
+
+/// The crates generated from heroicons, besides `leptos_heroicons` at the
+/// root of the workspace.
+const FRAMEWORK_CRATES: [(Backend, &str); 3] = [
+    (Backend::Yew, "yew_heroicons"),
+    (Backend::Dioxus, "dioxus_heroicons"),
+    (Backend::Sycamore, "sycamore_heroicons"),
+];
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let root = workspace_root();
+
+    let before = Snapshot::take(&root);
+    match args.task {
+        Task::Regen(lib_args) => {
+            regen_lib(&root, lib_args)?;
+            regen_example(&root)?;
+        }
+        Task::Lib(lib_args) => regen_lib(&root, lib_args)?,
+        Task::Example => regen_example(&root)?,
+    }
+    Snapshot::take(&root).report_changes(&before);
+
+    Ok(())
+}
+
+fn workspace_root() -> path::PathBuf {
+    path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+fn regen_lib(root: &path::Path, args: LibArgs) -> Result<()> {
+    let download_dir = root.join("target").join("heroicons");
+    let input_dir = match args.input {
+        Some(input_dir) => input_dir,
+        None => {
+            println!("Downloading heroicons");
+            fs::create_dir_all(&download_dir)?;
+            download_latest_icons(&download_dir)?;
+            extract_latest_icons(&download_dir)?;
+            download_dir.join(OPTIMIZED_PATH)
+        }
+    };
+
+    let source = Source {
+        input_dir: Some(input_dir),
+        module: None,
+        feature_prefix: String::new(),
+        tags: Some(root.join("generate_components").join(HEROICONS_TAGS_PATH)),
+        categories: Some(
+            root.join("generate_components")
+                .join(HEROICONS_CATEGORIES_PATH),
+        ),
+        transforms: None,
+        rtl: Some(root.join("generate_components").join(HEROICONS_RTL_PATH)),
+    };
+    let crates = [(Backend::Leptos, HEROICONS_CRATE_NAME, root.to_path_buf())]
+        .into_iter()
+        .chain(FRAMEWORK_CRATES.map(|(backend, crate_name)| {
+            (backend, crate_name, root.join(crate_name))
+        }));
+    for (backend, crate_name, output_dir) in crates {
+        println!("Generating {crate_name}");
+        generate(&Config {
+            output_dir,
+            crate_name: crate_name.to_string(),
+            backend,
+            sources: vec![source.clone()],
+        })?;
+    }
+
+    if download_dir.exists() {
+        fs::remove_dir_all(download_dir)?;
+    }
+
+    Ok(())
+}
+
+fn regen_example(root: &path::Path) -> Result<()> {
+    println!("Generating the example's gallery");
+    generate_example_components::generate(
+        &root.join("src").join("lib.rs"),
+        &root.join("example").join("src").join("components.rs"),
+    )
+}
+
+/// The contents of every generated file, to report what a task changed.
+struct Snapshot(Vec<(path::PathBuf, Option<String>)>);
+
+impl Snapshot {
+    fn take(root: &path::Path) -> Self {
+        let crate_dirs = [root.to_path_buf()].into_iter().chain(
+            FRAMEWORK_CRATES.map(|(_, crate_name)| root.join(crate_name)),
+        );
+        let mut paths: Vec<path::PathBuf> = crate_dirs
+            .flat_map(|dir| [dir.join("Cargo.toml"), dir.join("src/lib.rs")])
+            .collect();
+        paths.push(root.join("example/src/components.rs"));
+
+        Self(
+            paths
+                .into_iter()
+                .map(|path| {
+                    let contents = fs::read_to_string(&path).ok();
+                    let path = path.strip_prefix(root).unwrap().to_path_buf();
+                    (path, contents)
+                })
+                .collect(),
+        )
+    }
+
+    fn report_changes(&self, before: &Snapshot) {
+        let mut changed = false;
+        for ((path, after), (_, before)) in self.0.iter().zip(&before.0) {
+            if after == before {
+                continue;
+            }
+            changed = true;
+
+            let lines = |contents: &Option<String>| {
+                contents
+                    .as_deref()
+                    .map_or(0, |contents| contents.lines().count())
+            };
+            println!(
+                "changed {} ({} -> {} lines)",
+                path.display(),
+                lines(before),
+                lines(after)
+            );
+        }
+
+        if !changed {
+            println!("nothing changed");
+        }
+    }
+}